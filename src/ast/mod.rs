@@ -11,6 +11,7 @@ use crate::diagnostics::{Diagnostic, Error};
 use crate::grammar::attributes::*;
 use crate::grammar::{Element, NamedSymbol, Primitive, Symbol};
 use crate::utils::ptr_util::{OwnedPtr, WeakPtr};
+use crate::utils::string_util::edit_distance;
 use std::collections::HashMap;
 
 /// Since Slice definitions can be split across multiple files, and defined in any order, it is impossible for some
@@ -20,14 +21,18 @@ use std::collections::HashMap;
 /// computed, in the following order:
 /// 1. References to other Slice types are verified and resolved.
 /// 2. Compute and store the Slice encodings that each element can be used with.
+/// 3. Links in doc comments are verified and resolved.
+/// 4. Imports that are unresolvable, or weren't used by any type reference or link, are reported.
 ///
 /// This function fails fast, so if any phase of patching fails, we skip any remaining phases.
 pub(crate) unsafe fn patch_ast(compilation_state: &mut CompilationState) {
-    let attribute_patcher = crate::patch_attributes!("", Allow, Compress, Deprecated, Oneway, SlicedFormat);
+    let attribute_patcher =
+        crate::patch_attributes!("", Allow, Compress, Deny, Deprecated, Flags, Oneway, SlicedFormat, Warn,);
     compilation_state.apply_unsafe(attribute_patcher);
     compilation_state.apply_unsafe(patchers::type_ref_patcher::patch_ast);
     compilation_state.apply_unsafe(patchers::encoding_patcher::patch_ast);
     compilation_state.apply_unsafe(patchers::comment_link_patcher::patch_ast);
+    compilation_state.apply(patchers::import_checker::report_import_diagnostics);
 }
 
 /// The AST (Abstract Syntax Tree) is the heart of the compiler, containing all the slice elements defined and used by
@@ -309,6 +314,55 @@ impl Ast {
         self.find_node_with_scope(identifier, scope).and_then(|x| x.try_into())
     }
 
+    /// Returns the AST [nodes](Node) with identifiers similar to the provided one, for suggesting alternatives when a
+    /// lookup fails. Only nodes that satisfy the provided filter are considered.
+    ///
+    /// A node is similar if the last segment of its identifier is within a small edit distance of the last segment of
+    /// the provided identifier. This includes nodes with the exact same (unscoped) identifier, but in other modules.
+    ///
+    /// # Returns
+    ///
+    /// A vector of at most 3 `(identifier, node)` pairs, where each identifier is fully scoped (without a leading
+    /// '::'). The vector is sorted from most to least similar, with ties broken alphabetically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use slicec::ast::Ast;
+    /// let ast = Ast::create();
+    ///
+    /// let similar_nodes = ast.find_similar_nodes("Int32", |_| true);
+    /// assert_eq!(similar_nodes[0].0, "int32");
+    /// ```
+    pub fn find_similar_nodes<'a>(
+        &'a self,
+        identifier: &str,
+        filter: impl Fn(&'a Node) -> bool,
+    ) -> Vec<(&'a str, &'a Node)> {
+        const MAX_SUGGESTIONS: usize = 3;
+
+        let last_segment = |s: &'a str| s.rsplit("::").next().unwrap();
+        let unscoped_identifier = identifier.rsplit("::").next().unwrap();
+        let max_distance = (unscoped_identifier.chars().count() / 3).max(1);
+
+        let mut candidates = self
+            .lookup_table
+            .iter()
+            .filter_map(|(key, i)| {
+                let distance = edit_distance(unscoped_identifier, last_segment(key));
+                (distance <= max_distance).then_some((distance, key.as_str(), &self.elements[*i]))
+            })
+            .filter(|(_, _, node)| filter(node))
+            .collect::<Vec<_>>();
+
+        candidates.sort_by(|(d1, k1, _), (d2, k2, _)| d1.cmp(d2).then(k1.cmp(k2)));
+        candidates
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, key, node)| (key, node))
+            .collect()
+    }
+
     /// Returns an immutable slice of all the [nodes](Node) contained in this AST.
     ///
     /// # Examples
@@ -83,7 +83,7 @@ macro_rules! generate_node_enum {
 // generate the `Node` enum with variants for every type allowed to be in the AST.
 generate_node_enum! {
     Module, Struct, Class, Exception, Field, Interface, Operation, Parameter, Enum,
-    Enumerator, CustomType, TypeAlias, Sequence, Dictionary, Primitive, Attribute
+    Enumerator, CustomType, TypeAlias, Constant, Sequence, Dictionary, Primitive, Attribute
 }
 
 impl<'a> TryFrom<&'a Node> for WeakPtr<dyn Type> {
@@ -163,6 +163,7 @@ impl<'a> TryFrom<&'a Node> for &'a dyn NamedSymbol {
             Node::Enumerator(enumerator_ptr) => Ok(enumerator_ptr.borrow()),
             Node::CustomType(custom_type_ptr) => Ok(custom_type_ptr.borrow()),
             Node::TypeAlias(type_alias_ptr) => Ok(type_alias_ptr.borrow()),
+            Node::Constant(constant_ptr) => Ok(constant_ptr.borrow()),
             _ => Err(LookupError::TypeMismatch {
                 expected: "NamedSymbol".to_owned(),
                 actual: node.to_string().to_case(Case::Lower),
@@ -192,6 +193,7 @@ impl<'a> TryFrom<&'a Node> for WeakPtr<dyn Entity> {
             Node::Enumerator(enumerator_ptr) => Ok(downgrade_as!(enumerator_ptr, dyn Entity)),
             Node::CustomType(custom_type_ptr) => Ok(downgrade_as!(custom_type_ptr, dyn Entity)),
             Node::TypeAlias(type_alias_ptr) => Ok(downgrade_as!(type_alias_ptr, dyn Entity)),
+            Node::Constant(constant_ptr) => Ok(downgrade_as!(constant_ptr, dyn Entity)),
             _ => Err(LookupError::TypeMismatch {
                 expected: "Entity".to_owned(),
                 actual: node.to_string().to_case(Case::Lower),
@@ -221,6 +223,7 @@ impl<'a> TryFrom<&'a Node> for &'a dyn Entity {
             Node::Enumerator(enumerator_ptr) => Ok(enumerator_ptr.borrow()),
             Node::CustomType(custom_type_ptr) => Ok(custom_type_ptr.borrow()),
             Node::TypeAlias(type_alias_ptr) => Ok(type_alias_ptr.borrow()),
+            Node::Constant(constant_ptr) => Ok(constant_ptr.borrow()),
             _ => Err(LookupError::TypeMismatch {
                 expected: "Entity".to_owned(),
                 actual: node.to_string().to_case(Case::Lower),
@@ -257,6 +260,7 @@ impl_into_node_for!(Enum);
 impl_into_node_for!(Enumerator);
 impl_into_node_for!(CustomType);
 impl_into_node_for!(TypeAlias);
+impl_into_node_for!(Constant);
 impl_into_node_for!(Sequence);
 impl_into_node_for!(Dictionary);
 // We don't implement it on `Primitive`, because primitive types are baked into the compiler, so we don't need
@@ -1,11 +1,12 @@
 // Copyright (c) ZeroC, Inc.
 
+use super::import_checker::ImportChecker;
 use crate::ast::{Ast, LookupError, Node};
 use crate::compilation_state::CompilationState;
 use crate::diagnostics::{Diagnostic, DiagnosticReporter, Lint};
 use crate::grammar::*;
 use crate::utils::ptr_util::WeakPtr;
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
 
 macro_rules! patch_link {
     ($self:ident, $tag:expr) => {
@@ -26,22 +27,31 @@ macro_rules! patch_element {
 pub unsafe fn patch_ast(compilation_state: &mut CompilationState) {
     let mut patcher = CommentLinkPatcher {
         link_patches: VecDeque::new(),
+        import_checker: ImportChecker::new(
+            &compilation_state.files,
+            compilation_state.strict_imports,
+            &mut compilation_state.used_imports,
+        ),
         diagnostic_reporter: &mut compilation_state.diagnostic_reporter,
+        referenced_definitions: &mut compilation_state.referenced_definitions,
     };
 
     // Immutably iterate through the AST and compute patches for all the doc comments stored in it.
     for node in compilation_state.ast.as_slice() {
         match node {
+            Node::Module(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
             Node::Struct(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
             Node::Class(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
             Node::Exception(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
             Node::Field(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
             Node::Interface(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
             Node::Operation(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
+            Node::Parameter(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
             Node::Enum(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
             Node::Enumerator(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
             Node::CustomType(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
             Node::TypeAlias(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
+            Node::Constant(ptr) => patcher.compute_patches_for(ptr.borrow(), &compilation_state.ast),
             _ => {} // Skip any elements that don't implement `Commentable`.
         }
     }
@@ -49,16 +59,19 @@ pub unsafe fn patch_ast(compilation_state: &mut CompilationState) {
     // Mutably iterate through the AST and apply all the patches in the same oder they were computed.
     for node in compilation_state.ast.as_mut_slice() {
         match node {
+            Node::Module(ptr) => patch_element!(ptr, patcher),
             Node::Struct(ptr) => patch_element!(ptr, patcher),
             Node::Class(ptr) => patch_element!(ptr, patcher),
             Node::Exception(ptr) => patch_element!(ptr, patcher),
             Node::Field(ptr) => patch_element!(ptr, patcher),
             Node::Interface(ptr) => patch_element!(ptr, patcher),
             Node::Operation(ptr) => patch_element!(ptr, patcher),
+            Node::Parameter(ptr) => patch_element!(ptr, patcher),
             Node::Enum(ptr) => patch_element!(ptr, patcher),
             Node::Enumerator(ptr) => patch_element!(ptr, patcher),
             Node::CustomType(ptr) => patch_element!(ptr, patcher),
             Node::TypeAlias(ptr) => patch_element!(ptr, patcher),
+            Node::Constant(ptr) => patch_element!(ptr, patcher),
             _ => {} // Skip any elements that don't implement `Commentable`.
         }
     }
@@ -67,7 +80,9 @@ pub unsafe fn patch_ast(compilation_state: &mut CompilationState) {
 
 struct CommentLinkPatcher<'a> {
     link_patches: VecDeque<Option<WeakPtr<dyn Entity>>>,
+    import_checker: ImportChecker<'a>,
     diagnostic_reporter: &'a mut DiagnosticReporter,
+    referenced_definitions: &'a mut HashSet<String>,
 }
 
 impl CommentLinkPatcher<'_> {
@@ -91,13 +106,25 @@ impl CommentLinkPatcher<'_> {
             for see_tag in &comment.see {
                 self.resolve_link(&see_tag.link, commentable, ast);
             }
+            for remarks_tag in &comment.remarks {
+                self.resolve_links_in(&remarks_tag.message, commentable, ast);
+            }
+            for deprecated_tag in &comment.deprecated {
+                self.resolve_links_in(&deprecated_tag.message, commentable, ast);
+            }
         }
     }
 
     fn resolve_links_in(&mut self, message: &Message, commentable: &impl Commentable, ast: &Ast) {
         for component in message {
-            if let MessageComponent::Link(link_tag) = component {
-                self.resolve_link(&link_tag.link, commentable, ast);
+            match component {
+                MessageComponent::Link(link_tag) => self.resolve_link(&link_tag.link, commentable, ast),
+                MessageComponent::List(list) => {
+                    for item in &list.items {
+                        self.resolve_links_in(item, commentable, ast);
+                    }
+                }
+                _ => {}
             }
         }
     }
@@ -114,23 +141,39 @@ impl CommentLinkPatcher<'_> {
         // Look up the linked-to entity in the AST.
         let result = ast
             .find_node_with_scope(&identifier.value, &commentable.parser_scoped_identifier())
-            .and_then(<WeakPtr<dyn Entity>>::try_from);
+            .and_then(|node| {
+                self.import_checker
+                    .check_usage(identifier.span(), node, self.diagnostic_reporter);
+                <WeakPtr<dyn Entity>>::try_from(node)
+            });
 
         // If the lookup succeeded, store the result, otherwise report a lint violation and store `None` as a dummy.
         self.link_patches.push_back(match result {
-            Ok(ptr) => Some(ptr),
+            Ok(ptr) => {
+                let identifier = ptr.borrow().parser_scoped_identifier();
+                let scope = commentable.parser_scoped_identifier();
+                super::record_reference(self.referenced_definitions, identifier, &scope);
+                Some(ptr)
+            }
             Err(error) => {
-                let message = match error {
-                    LookupError::DoesNotExist { identifier } => {
-                        format!("no element named '{identifier}' exists in scope")
+                let diagnostic = match error {
+                    LookupError::DoesNotExist { identifier: id } => {
+                        let message = format!("no element named '{id}' exists in scope");
+                        let diagnostic = Diagnostic::new(Lint::BrokenDocLink { message });
+
+                        // Suggest similarly named elements, but only ones that can be linked to.
+                        let is_linkable = |node: &Node| <&dyn Entity>::try_from(node).is_ok();
+                        let scope = commentable.parser_scoped_identifier();
+                        super::add_similar_identifier_notes(diagnostic, &identifier.value, &scope, ast, is_linkable)
                     }
                     LookupError::TypeMismatch { actual, .. } => {
                         // Only primitives and modules have names but cannot be linked to.
                         debug_assert!(actual == "primitive" || actual == "module");
-                        format!("{actual}s cannot be linked to")
+                        let message = format!("{actual}s cannot be linked to");
+                        Diagnostic::new(Lint::BrokenDocLink { message })
                     }
                 };
-                Diagnostic::new(Lint::BrokenDocLink { message })
+                diagnostic
                     .set_span(identifier.span())
                     .set_scope(commentable.parser_scoped_identifier())
                     .report(self.diagnostic_reporter);
@@ -159,13 +202,25 @@ impl CommentLinkPatcher<'_> {
             for see_tag in &mut comment.see {
                 patch_link!(self, see_tag);
             }
+            for remarks_tag in &mut comment.remarks {
+                self.patch_links_in(&mut remarks_tag.message);
+            }
+            for deprecated_tag in &mut comment.deprecated {
+                self.patch_links_in(&mut deprecated_tag.message);
+            }
         }
     }
 
     fn patch_links_in(&mut self, message: &mut Message) {
         for component in message {
-            if let MessageComponent::Link(link_tag) = component {
-                patch_link!(self, link_tag);
+            match component {
+                MessageComponent::Link(link_tag) => patch_link!(self, link_tag),
+                MessageComponent::List(list) => {
+                    for item in &mut list.items {
+                        self.patch_links_in(item);
+                    }
+                }
+                _ => {}
             }
         }
     }
@@ -244,9 +244,7 @@ fn disallowed_optional_suggestion(
     type_ref: &TypeRef<impl Type + ?Sized>,
     container: Option<&dyn Entity>,
 ) -> Option<Note> {
-    let Some(container) = container else {
-        return None;
-    };
+    let container = container?;
 
     let identifier = match container.concrete_entity() {
         Entities::Field(field) => match field.parent().concrete_entity() {
@@ -449,7 +447,13 @@ impl ComputeSupportedEncodings for Enum {
         supported_encodings: &mut SupportedEncodings,
         compilation_mode: &CompilationMode,
     ) -> Option<&'static str> {
-        // TODO: rework all of this when we add enums with associated types.
+        // Insert a dummy entry for the enum into the cache to prevent infinite lookup cycles.
+        // Enumerators' fields can reference their own enum, but it's an error for this to be cyclic,
+        // so it's fine if the supported encodings are bogus.
+        patcher
+            .supported_encodings_cache
+            .insert(self.parser_scoped_identifier(), SupportedEncodings::dummy());
+
         if let Some(underlying_type) = &self.underlying {
             // Enums only support encodings that its underlying type also supports.
             supported_encodings.intersect_with(&patcher.get_supported_encodings_for_type_ref(
@@ -464,10 +468,9 @@ impl ComputeSupportedEncodings for Enum {
             if *compilation_mode == CompilationMode::Slice1 {
                 return Some("enums defined in Slice1 mode cannot have underlying types");
             }
-        } else {
-            // Enums defined in a file using Slice2 must have an explicit underlying type.
+        } else if !self.has_enumerators_with_fields() {
+            // Enums defined in a file using Slice2 must have an explicit underlying type or enumerators with fields.
             if *compilation_mode == CompilationMode::Slice2 {
-                // TODO: this isn't the correct error to emit, remove this when we add enums with associated values.
                 Diagnostic::new(Error::EnumUnderlyingTypeNotSupported {
                     enum_identifier: self.identifier().to_owned(),
                     kind: None,
@@ -483,6 +486,24 @@ impl ComputeSupportedEncodings for Enum {
                 .report(patcher.diagnostic_reporter)
             }
         }
+
+        if self.has_enumerators_with_fields() {
+            // Enums only support encodings that all of their enumerators' fields also support.
+            for field in self.enumerators().into_iter().flat_map(Enumerator::fields) {
+                supported_encodings.intersect_with(&patcher.get_supported_encodings_for_type_ref(
+                    field.data_type(),
+                    compilation_mode,
+                    field.is_tagged(),
+                    Some(field),
+                ));
+            }
+
+            // Enumerators with fields are not allowed in Slice1 mode.
+            supported_encodings.disable(Encoding::Slice1);
+            if *compilation_mode == CompilationMode::Slice1 {
+                return Some("enumerators with fields can only be used in Slice2 mode");
+            }
+        }
         None
     }
 }
@@ -0,0 +1,153 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::ast::{Ast, Node};
+use crate::compilation_state::CompilationState;
+use crate::diagnostics::*;
+use crate::grammar::*;
+use crate::slice_file::{SliceFile, Span};
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+
+/// Tracks which imports are used to resolve type references and doc comment links, and (when strict imports are
+/// enabled) checks that files only use definitions from themselves and from the files and definitions they import.
+pub struct ImportChecker<'a> {
+    files: &'a HashMap<String, SliceFile>,
+    is_strict: bool,
+
+    /// The imports that have been used so far, stored as `(file path, import index)` pairs.
+    used_imports: &'a mut HashSet<(String, usize)>,
+}
+
+impl<'a> ImportChecker<'a> {
+    pub fn new(
+        files: &'a HashMap<String, SliceFile>,
+        is_strict: bool,
+        used_imports: &'a mut HashSet<(String, usize)>,
+    ) -> Self {
+        ImportChecker {
+            files,
+            is_strict,
+            used_imports,
+        }
+    }
+
+    /// Checks whether the provided node can be used at the provided location, marking any imports which make it
+    /// visible as used. If strict imports are enabled and none of the file's imports make the node visible, this
+    /// reports an error.
+    pub fn check_usage(&mut self, span: &Span, node: &Node, diagnostic_reporter: &mut DiagnosticReporter) {
+        // Only entities are defined in files; primitives and anonymous types are usable everywhere.
+        let Ok(entity) = <&dyn Entity>::try_from(node) else { return };
+
+        // Definitions can always be used within the file they're defined in.
+        let using_file = &span.file;
+        let defining_file = &entity.span().file;
+        if using_file == defining_file {
+            return;
+        }
+
+        let identifier = entity.parser_scoped_identifier();
+        let Some(file) = self.files.get(using_file) else { return };
+
+        let mut is_imported = false;
+        for (i, import) in file.imports.iter().enumerate() {
+            if is_match(import, using_file, defining_file, &identifier) {
+                self.used_imports.insert((using_file.clone(), i));
+                is_imported = true;
+            }
+        }
+
+        if self.is_strict && !is_imported {
+            Diagnostic::new(Error::DefinitionNotImported {
+                identifier: identifier.clone(),
+                file: defining_file.clone(),
+            })
+            .set_span(span)
+            .add_note(format!("consider adding 'import {identifier}' to this file"), None)
+            .report(diagnostic_reporter);
+        }
+    }
+}
+
+/// Reports an error for each import that doesn't refer to a known Slice file or definition, and a lint for each import
+/// that was never used to resolve a type reference or doc comment link.
+///
+/// This must run after every type reference and doc comment link has been resolved.
+pub fn report_import_diagnostics(compilation_state: &mut CompilationState) {
+    let files = &compilation_state.files;
+    let used_imports = &compilation_state.used_imports;
+    let diagnostic_reporter = &mut compilation_state.diagnostic_reporter;
+
+    // Sort the files by path, so diagnostics are reported in a consistent order.
+    let mut sorted_files = files.values().collect::<Vec<_>>();
+    sorted_files.sort_by_key(|file| &file.relative_path);
+
+    for file in sorted_files {
+        for (i, import) in file.imports.iter().enumerate() {
+            let (import_string, note) = match &import.kind {
+                ImportKind::File(path) => (path, "no Slice file with this path was passed to the compiler"),
+                ImportKind::Definition(identifier) => (&identifier.value, "no definition with this identifier exists"),
+            };
+
+            if !is_resolvable(import, &file.relative_path, files, &compilation_state.ast) {
+                Diagnostic::new(Error::UnresolvedImport {
+                    import: import_string.clone(),
+                })
+                .set_span(import.span())
+                .add_note(note, None)
+                .report(diagnostic_reporter);
+            } else if !used_imports.contains(&(file.relative_path.clone(), i)) {
+                Diagnostic::new(Lint::UnusedImport {
+                    import: import_string.clone(),
+                })
+                .set_span(import.span())
+                .add_suggestion("remove the unused import", import.span(), "")
+                .report(diagnostic_reporter);
+            }
+        }
+    }
+}
+
+fn is_resolvable(import: &Import, importing_file: &str, files: &HashMap<String, SliceFile>, ast: &Ast) -> bool {
+    match &import.kind {
+        ImportKind::File(path) => {
+            let resolved_path = resolve_import_path(importing_file, path);
+            files
+                .keys()
+                .any(|file| normalize_path(Path::new(file)) == resolved_path)
+        }
+        ImportKind::Definition(identifier) => ast.find_node(identifier.value.trim_start_matches("::")).is_ok(),
+    }
+}
+
+/// Returns true if the provided import makes the definition with the provided identifier (and file) visible.
+fn is_match(import: &Import, importing_file: &str, defining_file: &str, identifier: &str) -> bool {
+    match &import.kind {
+        ImportKind::File(path) => resolve_import_path(importing_file, path) == normalize_path(Path::new(defining_file)),
+        ImportKind::Definition(imported) => {
+            // Importing a definition also imports everything nested inside of it.
+            let imported = imported.value.trim_start_matches("::");
+            identifier == imported || identifier.starts_with(&format!("{imported}::"))
+        }
+    }
+}
+
+/// Resolves an imported path against the directory of the file that imported it.
+fn resolve_import_path(importing_file: &str, path: &str) -> PathBuf {
+    let directory = Path::new(importing_file).parent().unwrap_or_else(|| Path::new(""));
+    normalize_path(&directory.join(path))
+}
+
+/// Lexically normalizes a path, removing any `.` components, and collapsing any `..` components where possible.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if matches!(normalized.components().next_back(), Some(Component::Normal(_))) => {
+                normalized.pop();
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
@@ -4,8 +4,56 @@
 
 pub mod comment_link_patcher;
 pub mod encoding_patcher;
+pub mod import_checker;
 pub mod type_ref_patcher;
 
+use crate::ast::{Ast, Node};
+use crate::diagnostics::Diagnostic;
+use crate::grammar::NamedSymbol;
+use std::collections::HashSet;
+
+/// Adds a "did you mean" note to the provided diagnostic for each node with an identifier similar to the provided
+/// (unresolvable) one. Only nodes that satisfy the provided filter are suggested, so callers can limit the suggestions
+/// to elements of a kind they could actually use.
+///
+/// Suggested identifiers are written relative to the provided scope when possible, so they can be used as-is.
+fn add_similar_identifier_notes<'a>(
+    mut diagnostic: Diagnostic,
+    identifier: &str,
+    scope: &str,
+    ast: &'a Ast,
+    filter: impl Fn(&'a Node) -> bool,
+) -> Diagnostic {
+    for (candidate, node) in ast.find_similar_nodes(identifier, filter) {
+        let span = <&dyn NamedSymbol>::try_from(node).ok().map(|symbol| symbol.span());
+        let message = format!("did you mean '{}'?", relativize_identifier(candidate, scope));
+        diagnostic = diagnostic.add_note(message, span);
+    }
+    diagnostic
+}
+
+/// Records that the definition with the provided (parser scoped) identifier was referenced from the provided scope, unless the reference is from within
+/// the definition itself (ex: a recursive field, or a doc comment that links to its own element).
+fn record_reference(referenced_definitions: &mut HashSet<String>, identifier: String, scope: &str) {
+    let is_self_reference = scope == identifier || scope.starts_with(&format!("{identifier}::"));
+    if !is_self_reference {
+        referenced_definitions.insert(identifier);
+    }
+}
+
+/// Strips the longest enclosing scope of the provided scope off of a fully scoped identifier.
+fn relativize_identifier<'a>(identifier: &'a str, scope: &str) -> &'a str {
+    let mut scopes = scope.split("::").collect::<Vec<_>>();
+    while !scopes.is_empty() {
+        let prefix = scopes.join("::") + "::";
+        if let Some(relative_identifier) = identifier.strip_prefix(&prefix) {
+            return relative_identifier;
+        }
+        scopes.pop();
+    }
+    identifier
+}
+
 #[macro_export]
 macro_rules! patch_attributes {
     ($prefix:literal, $($attribute_type:ty),* $(,)?) => {{
@@ -1,16 +1,24 @@
 // Copyright (c) ZeroC, Inc.
 
+use super::import_checker::ImportChecker;
 use crate::ast::{Ast, LookupError, Node};
 use crate::compilation_state::CompilationState;
 use crate::diagnostics::*;
 use crate::grammar::attributes::Deprecated;
 use crate::grammar::*;
 use crate::utils::ptr_util::{OwnedPtr, WeakPtr};
+use std::collections::HashSet;
 
 pub unsafe fn patch_ast(compilation_state: &mut CompilationState) {
     let mut patcher = TypeRefPatcher {
         type_ref_patches: Vec::new(),
+        import_checker: ImportChecker::new(
+            &compilation_state.files,
+            compilation_state.strict_imports,
+            &mut compilation_state.used_imports,
+        ),
         diagnostic_reporter: &mut compilation_state.diagnostic_reporter,
+        referenced_definitions: &mut compilation_state.referenced_definitions,
     };
 
     // TODO why explain we split this logic so that we can for sure have an immutable AST.
@@ -20,7 +28,9 @@ pub unsafe fn patch_ast(compilation_state: &mut CompilationState) {
 
 struct TypeRefPatcher<'a> {
     type_ref_patches: Vec<PatchKind>,
+    import_checker: ImportChecker<'a>,
     diagnostic_reporter: &'a mut DiagnosticReporter,
+    referenced_definitions: &'a mut HashSet<String>,
 }
 
 impl TypeRefPatcher<'_> {
@@ -71,6 +81,10 @@ impl TypeRefPatcher<'_> {
                     self.resolve_definition(type_ref, ast)
                         .map(PatchKind::TypeAliasUnderlyingType)
                 }
+                Node::Constant(constant_ptr) => {
+                    let type_ref = &constant_ptr.borrow().data_type;
+                    self.resolve_definition(type_ref, ast).map(PatchKind::ConstantType)
+                }
                 Node::Sequence(sequence_ptr) => {
                     let type_ref = &sequence_ptr.borrow().element_type;
                     self.resolve_definition(type_ref, ast).map(PatchKind::SequenceType)
@@ -149,6 +163,11 @@ impl TypeRefPatcher<'_> {
                     let type_alias_underlying_type_ref = &mut type_alias_ptr.borrow_mut().underlying;
                     type_alias_underlying_type_ref.patch(type_alias_underlying_type_ptr, attributes);
                 }
+                PatchKind::ConstantType((constant_type_ptr, attributes)) => {
+                    let constant_ptr: &mut OwnedPtr<Constant> = element.try_into().unwrap();
+                    let constant_type_ref = &mut constant_ptr.borrow_mut().data_type;
+                    constant_type_ref.patch(constant_type_ptr, attributes);
+                }
                 PatchKind::SequenceType((element_type_ptr, attributes)) => {
                     let sequence_ptr: &mut OwnedPtr<Sequence> = element.try_into().unwrap();
                     let element_type_ref = &mut sequence_ptr.borrow_mut().element_type;
@@ -190,6 +209,12 @@ impl TypeRefPatcher<'_> {
                 // We perform the deprecation check here instead of the validators since we need to check type-aliases
                 // which are resolved and erased after TypeRef patching is completed.
                 self.check_for_deprecated_type(type_ref, node);
+                self.import_checker
+                    .check_usage(type_ref.span(), node, self.diagnostic_reporter);
+                if let Ok(definition) = <&dyn NamedSymbol>::try_from(node) {
+                    let identifier = definition.parser_scoped_identifier();
+                    super::record_reference(self.referenced_definitions, identifier, type_ref.parser_scope());
+                }
 
                 if let Node::TypeAlias(type_alias) = node {
                     self.resolve_type_alias(type_alias.borrow(), ast)
@@ -202,21 +227,25 @@ impl TypeRefPatcher<'_> {
         match lookup_result {
             Ok(definition) => Some(definition),
             Err(err) => {
-                let mapped_error = match err {
-                    LookupError::DoesNotExist { identifier } => Error::DoesNotExist { identifier },
+                let diagnostic = match err {
+                    LookupError::DoesNotExist { identifier: id } => {
+                        // Suggest similarly named definitions, but only ones that this type reference could use.
+                        let is_compatible = |node: &'a Node| TryInto::<WeakPtr<T>>::try_into(node).is_ok();
+                        let diagnostic = Diagnostic::new(Error::DoesNotExist { identifier: id });
+                        let scope = type_ref.module_scope();
+                        super::add_similar_identifier_notes(diagnostic, &identifier.value, scope, ast, is_compatible)
+                    }
                     LookupError::TypeMismatch {
                         expected,
                         actual,
                         is_concrete,
-                    } => Error::TypeMismatch {
+                    } => Diagnostic::new(Error::TypeMismatch {
                         expected,
                         actual,
                         is_concrete,
-                    },
+                    }),
                 };
-                Diagnostic::new(mapped_error)
-                    .set_span(identifier.span())
-                    .report(self.diagnostic_reporter);
+                diagnostic.set_span(identifier.span()).report(self.diagnostic_reporter);
                 None
             }
         }
@@ -327,6 +356,7 @@ enum PatchKind {
     ThrowsType(Patch<Exception>),
     EnumUnderlyingType(Patch<Primitive>),
     TypeAliasUnderlyingType(Patch<dyn Type>),
+    ConstantType(Patch<dyn Type>),
     SequenceType(Patch<dyn Type>),
     DictionaryTypes(Option<Patch<dyn Type>>, Option<Patch<dyn Type>>),
 }
@@ -0,0 +1,446 @@
+// Copyright (c) ZeroC, Inc.
+
+//! Serializes the definitions of a compilation to JSON, so they can be consumed by tools that can't link against this
+//! crate, such as code generators written in other languages.
+//!
+//! The JSON model is described by a JSON Schema, which is published in this repository at `schema/ast.schema.json`,
+//! and is also available as [`JSON_SCHEMA`]. Every document starts with a `format_version` field. Whenever the model
+//! changes, [`FORMAT_VERSION`] is incremented, and the schema is updated to match.
+//!
+//! A document holds one object per Slice file, each of which holds the modules and definitions declared in that file.
+//! Definitions hold their members (fields, operations, enumerators, etc.) inline. Everything else is referenced by
+//! scoped identifier: type references, doc comment links, and thrown exceptions.
+
+use crate::compilation_state::CompilationState;
+use crate::diagnostics::{Diagnostic, Error};
+use crate::grammar::*;
+use crate::slice_file::SliceFile;
+use crate::supported_encodings::SupportedEncodings;
+use serde_json::{json, Map, Value};
+use std::fs;
+
+/// The version of the JSON model produced by this module.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// The JSON Schema which describes the model produced by this module.
+pub const JSON_SCHEMA: &str = include_str!("../schema/ast.schema.json");
+
+/// Serializes every Slice file in the provided compilation, and all the definitions they contain, to JSON.
+///
+/// The compilation must have completed without errors, since only fully patched ASTs can be serialized.
+pub fn ast_to_json(compilation_state: &CompilationState) -> Value {
+    // Files are sorted by path, so that the output is deterministic.
+    let mut files = compilation_state.files.values().collect::<Vec<_>>();
+    files.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+
+    json!({
+        "format_version": FORMAT_VERSION,
+        "slicec_version": env!("CARGO_PKG_VERSION"),
+        "files": files.into_iter().map(file_to_json).collect::<Vec<_>>(),
+    })
+}
+
+/// Serializes the provided compilation to JSON (see [ast_to_json]) and writes it to the file at the provided path.
+/// If the file can't be written, an error is reported.
+pub(crate) fn emit_ast_json(compilation_state: &mut CompilationState, path: &str) {
+    let json = ast_to_json(compilation_state).to_string();
+    if let Err(error) = fs::write(path, json) {
+        Diagnostic::new(Error::IO {
+            action: "write",
+            path: path.to_owned(),
+            error,
+        })
+        .report(&mut compilation_state.diagnostic_reporter);
+    }
+}
+
+fn file_to_json(slice_file: &SliceFile) -> Value {
+    let imports = slice_file.imports.iter().map(|import| match &import.kind {
+        ImportKind::File(path) => json!({ "kind": "file", "path": path, "span": import.span }),
+        ImportKind::Definition(identifier) => {
+            json!({ "kind": "definition", "identifier": identifier.value, "span": import.span })
+        }
+    });
+
+    json!({
+        "path": slice_file.relative_path,
+        "is_source": slice_file.is_source,
+        "mode": slice_file.compilation_mode().to_string(),
+        "attributes": attributes_to_json(slice_file.attributes()),
+        "imports": imports.collect::<Vec<_>>(),
+        "modules": slice_file.modules.iter().map(|module_ptr| module_to_json(module_ptr.borrow())).collect::<Vec<_>>(),
+        "definitions": slice_file.contents.iter().map(definition_to_json).collect::<Vec<_>>(),
+    })
+}
+
+fn module_to_json(module_def: &Module) -> Value {
+    json!({
+        "kind": "module",
+        "identifier": module_def.nested_module_identifier(),
+        "attributes": attributes_to_json(module_def.attributes()),
+        "doc_comment": module_def.comment().map(doc_comment_to_json),
+        "span": module_def.span(),
+    })
+}
+
+fn definition_to_json(definition: &Definition) -> Value {
+    match definition {
+        Definition::Struct(struct_ptr) => struct_to_json(struct_ptr.borrow()),
+        Definition::Class(class_ptr) => class_to_json(class_ptr.borrow()),
+        Definition::Exception(exception_ptr) => exception_to_json(exception_ptr.borrow()),
+        Definition::Interface(interface_ptr) => interface_to_json(interface_ptr.borrow()),
+        Definition::Enum(enum_ptr) => enum_to_json(enum_ptr.borrow()),
+        Definition::CustomType(custom_type_ptr) => custom_type_to_json(custom_type_ptr.borrow()),
+        Definition::TypeAlias(type_alias_ptr) => type_alias_to_json(type_alias_ptr.borrow()),
+        Definition::Constant(constant_ptr) => constant_to_json(constant_ptr.borrow()),
+    }
+}
+
+fn struct_to_json(struct_def: &Struct) -> Value {
+    entity_to_json(
+        struct_def,
+        "struct",
+        json!({
+            "is_compact": struct_def.is_compact,
+            "fields": fields_to_json(struct_def.fields()),
+            "supported_encodings": encodings_to_json(&struct_def.supported_encodings()),
+        }),
+    )
+}
+
+fn class_to_json(class_def: &Class) -> Value {
+    entity_to_json(
+        class_def,
+        "class",
+        json!({
+            "compact_id": class_def.compact_id.as_ref().map(|compact_id| compact_id.value),
+            "base": class_def.base.as_ref().map(type_ref_to_json),
+            "fields": fields_to_json(class_def.fields()),
+            "supported_encodings": encodings_to_json(&class_def.supported_encodings()),
+        }),
+    )
+}
+
+fn exception_to_json(exception_def: &Exception) -> Value {
+    entity_to_json(
+        exception_def,
+        "exception",
+        json!({
+            "base": exception_def.base.as_ref().map(type_ref_to_json),
+            "fields": fields_to_json(exception_def.fields()),
+            "supported_encodings": encodings_to_json(&exception_def.supported_encodings()),
+        }),
+    )
+}
+
+fn interface_to_json(interface_def: &Interface) -> Value {
+    entity_to_json(
+        interface_def,
+        "interface",
+        json!({
+            "bases": interface_def.bases.iter().map(type_ref_to_json).collect::<Vec<_>>(),
+            "operations": interface_def.operations().into_iter().map(operation_to_json).collect::<Vec<_>>(),
+            "supported_encodings": encodings_to_json(&interface_def.supported_encodings()),
+        }),
+    )
+}
+
+fn operation_to_json(operation: &Operation) -> Value {
+    let throws = match &operation.throws {
+        Throws::None => json!({ "kind": "none" }),
+        Throws::Specific(exception) => json!({ "kind": "specific", "exception": type_ref_to_json(exception) }),
+        Throws::AnyException => json!({ "kind": "any_exception" }),
+    };
+
+    entity_to_json(
+        operation,
+        "operation",
+        json!({
+            "is_idempotent": operation.is_idempotent,
+            "encoding": operation.encoding.to_string(),
+            "parameters": operation.parameters().into_iter().map(parameter_to_json).collect::<Vec<_>>(),
+            "return_members": operation.return_members().into_iter().map(parameter_to_json).collect::<Vec<_>>(),
+            "throws": throws,
+        }),
+    )
+}
+
+fn parameter_to_json(parameter: &Parameter) -> Value {
+    entity_to_json(
+        parameter,
+        "parameter",
+        json!({
+            "data_type": type_ref_to_json(&parameter.data_type),
+            "tag": parameter.tag(),
+            "is_streamed": parameter.is_streamed,
+        }),
+    )
+}
+
+fn enum_to_json(enum_def: &Enum) -> Value {
+    let enumerators = enum_def.enumerators().into_iter().map(|enumerator| {
+        entity_to_json(
+            enumerator,
+            "enumerator",
+            json!({
+                "value": integer_to_json(enumerator.value()),
+                "fields": fields_to_json(enumerator.fields()),
+            }),
+        )
+    });
+
+    entity_to_json(
+        enum_def,
+        "enum",
+        json!({
+            "underlying": enum_def.underlying.as_ref().map(type_ref_to_json),
+            "is_unchecked": enum_def.is_unchecked,
+            "enumerators": enumerators.collect::<Vec<_>>(),
+            "supported_encodings": encodings_to_json(&enum_def.supported_encodings()),
+        }),
+    )
+}
+
+fn custom_type_to_json(custom_type: &CustomType) -> Value {
+    entity_to_json(
+        custom_type,
+        "custom_type",
+        json!({
+            "supported_encodings": encodings_to_json(&custom_type.supported_encodings()),
+        }),
+    )
+}
+
+fn type_alias_to_json(type_alias: &TypeAlias) -> Value {
+    entity_to_json(
+        type_alias,
+        "type_alias",
+        json!({
+            "underlying": type_ref_to_json(&type_alias.underlying),
+            "supported_encodings": encodings_to_json(&type_alias.supported_encodings()),
+        }),
+    )
+}
+
+fn constant_to_json(constant: &Constant) -> Value {
+    entity_to_json(
+        constant,
+        "constant",
+        json!({
+            "data_type": type_ref_to_json(&constant.data_type),
+            "value": literal_to_json(&constant.value),
+        }),
+    )
+}
+
+fn fields_to_json(fields: Vec<&Field>) -> Vec<Value> {
+    let fields = fields.into_iter().map(|field| {
+        entity_to_json(
+            field,
+            "field",
+            json!({
+                "data_type": type_ref_to_json(&field.data_type),
+                "tag": field.tag(),
+                "default_value": field.default_value.as_ref().map(literal_to_json),
+            }),
+        )
+    });
+    fields.collect()
+}
+
+/// Creates an object holding the properties shared by all entities, followed by the provided entity-specific ones.
+fn entity_to_json<T: Entity + Commentable>(entity: &T, kind: &str, properties: Value) -> Value {
+    let mut object = Map::new();
+    object.insert("kind".to_owned(), json!(kind));
+    object.insert("identifier".to_owned(), json!(entity.identifier()));
+    object.insert("scoped_identifier".to_owned(), json!(entity.parser_scoped_identifier()));
+    object.insert("module".to_owned(), json!(entity.module_scope()));
+    object.insert("attributes".to_owned(), attributes_to_json(entity.attributes()));
+    object.insert(
+        "doc_comment".to_owned(),
+        json!(entity.comment().map(doc_comment_to_json)),
+    );
+    object.insert("span".to_owned(), json!(entity.span()));
+
+    let Value::Object(properties) = properties else {
+        unreachable!("entity properties must be an object");
+    };
+    object.extend(properties);
+    Value::Object(object)
+}
+
+fn type_ref_to_json<T: Type + ?Sized>(type_ref: &TypeRef<T>) -> Value {
+    let reference =
+        |kind: &str, entity: &dyn Entity| json!({ "kind": kind, "identifier": entity.parser_scoped_identifier() });
+
+    let mut object = match type_ref.concrete_type() {
+        Types::Struct(struct_def) => reference("struct", struct_def),
+        Types::Class(class_def) => reference("class", class_def),
+        Types::Exception(exception_def) => reference("exception", exception_def),
+        Types::Interface(interface_def) => reference("interface", interface_def),
+        Types::Enum(enum_def) => reference("enum", enum_def),
+        Types::CustomType(custom_type) => reference("custom_type", custom_type),
+        Types::Sequence(sequence) => json!({
+            "kind": "sequence",
+            "element_type": type_ref_to_json(&sequence.element_type),
+        }),
+        Types::Dictionary(dictionary) => json!({
+            "kind": "dictionary",
+            "key_type": type_ref_to_json(&dictionary.key_type),
+            "value_type": type_ref_to_json(&dictionary.value_type),
+        }),
+        Types::Primitive(primitive) => json!({ "kind": "primitive", "identifier": primitive.type_string() }),
+    };
+
+    object["is_optional"] = json!(type_ref.is_optional);
+    object["attributes"] = attributes_to_json(type_ref.attributes());
+    object["span"] = json!(type_ref.span());
+    object
+}
+
+fn attributes_to_json(attributes: Vec<&Attribute>) -> Value {
+    let attributes = attributes.into_iter().map(|attribute| {
+        json!({
+            "directive": attribute.kind.directive(),
+            "args": attribute.args.iter().map(attribute_argument_to_json).collect::<Vec<_>>(),
+            "span": attribute.span,
+        })
+    });
+    Value::Array(attributes.collect())
+}
+
+fn attribute_argument_to_json(argument: &AttributeArgument) -> Value {
+    let (kind, value) = match &argument.value {
+        ArgumentValue::Identifier(identifier) => ("identifier", json!(identifier)),
+        ArgumentValue::String(string) => ("string", json!(string)),
+        ArgumentValue::Integer(integer) => ("integer", integer_to_json(*integer)),
+        ArgumentValue::Bool(boolean) => ("bool", json!(boolean)),
+        ArgumentValue::List(elements) => ("list", elements.iter().map(attribute_argument_to_json).collect()),
+        ArgumentValue::KeyValue(key, value) => (
+            "key_value",
+            json!({ "key": key.value, "value": attribute_argument_to_json(value) }),
+        ),
+    };
+    json!({ "kind": kind, "value": value, "span": argument.span })
+}
+
+fn literal_to_json(literal: &Literal) -> Value {
+    let (kind, value) = match &literal.value {
+        LiteralValue::Bool(boolean) => ("bool", json!(boolean)),
+        LiteralValue::Integer(integer) => ("integer", integer_to_json(*integer)),
+        LiteralValue::Float(float) => ("float", json!(float)),
+        LiteralValue::String(string) => ("string", json!(string)),
+        LiteralValue::Identifier(identifier) => ("identifier", json!(identifier)),
+    };
+    json!({ "kind": kind, "value": value, "span": literal.span })
+}
+
+fn doc_comment_to_json(comment: &DocComment) -> Value {
+    let params = comment.params.iter().map(|param| {
+        json!({
+            "identifier": param.identifier.value,
+            "message": message_to_json(&param.message),
+            "span": param.span,
+        })
+    });
+    let returns = comment.returns.iter().map(|returns| {
+        json!({
+            "identifier": returns.identifier.as_ref().map(|identifier| &identifier.value),
+            "message": message_to_json(&returns.message),
+            "span": returns.span,
+        })
+    });
+    let throws = comment.throws.iter().map(|throws| {
+        json!({
+            "exception": throws.thrown_type().map(|thrown_type| link_to_json(thrown_type.map(|e| e as &dyn Entity))),
+            "message": message_to_json(&throws.message),
+            "span": throws.span,
+        })
+    });
+    let see = comment
+        .see
+        .iter()
+        .map(|see| json!({ "link": link_to_json(see.linked_entity()), "span": see.span }));
+    let remarks = comment
+        .remarks
+        .iter()
+        .map(|remarks| json!({ "message": message_to_json(&remarks.message), "span": remarks.span }));
+    let examples = comment
+        .examples
+        .iter()
+        .map(|example| json!({ "code": example.code, "span": example.span }));
+    let since = comment
+        .since
+        .iter()
+        .map(|since| json!({ "version": since.version, "span": since.span }));
+    let deprecated = comment
+        .deprecated
+        .iter()
+        .map(|deprecated| json!({ "message": message_to_json(&deprecated.message), "span": deprecated.span }));
+
+    json!({
+        "overview": comment.overview.as_ref().map(|overview| message_to_json(&overview.message)),
+        "params": params.collect::<Vec<_>>(),
+        "returns": returns.collect::<Vec<_>>(),
+        "throws": throws.collect::<Vec<_>>(),
+        "see": see.collect::<Vec<_>>(),
+        "remarks": remarks.collect::<Vec<_>>(),
+        "examples": examples.collect::<Vec<_>>(),
+        "since": since.collect::<Vec<_>>(),
+        "deprecated": deprecated.collect::<Vec<_>>(),
+        "span": comment.span,
+    })
+}
+
+fn message_to_json(message: &Message) -> Value {
+    let components = message.iter().map(|component| match component {
+        MessageComponent::Text(text) => json!({ "kind": "text", "text": text }),
+        MessageComponent::Link(link) => {
+            json!({ "kind": "link", "link": link_to_json(link.linked_entity()), "span": link.span })
+        }
+        MessageComponent::Code(code) => json!({ "kind": "code", "code": code.code, "span": code.span }),
+        MessageComponent::Emphasis(emphasis) => json!({
+            "kind": "emphasis",
+            "text": emphasis.text,
+            "is_strong": emphasis.is_strong,
+            "span": emphasis.span,
+        }),
+        MessageComponent::List(list) => json!({
+            "kind": "list",
+            "items": list.items.iter().map(message_to_json).collect::<Vec<_>>(),
+            "span": list.span,
+        }),
+        MessageComponent::CodeBlock(code_block) => json!({
+            "kind": "code_block",
+            "language": code_block.language,
+            "code": code_block.code,
+            "span": code_block.span,
+        }),
+    });
+    Value::Array(components.collect())
+}
+
+/// Serializes the target of a doc comment link. Resolved links hold the scoped identifier of the entity they point to,
+/// and unresolved links hold the identifier that was written in the comment.
+fn link_to_json(link: Result<&dyn Entity, &Identifier>) -> Value {
+    match link {
+        Ok(entity) => json!({ "is_resolved": true, "identifier": entity.parser_scoped_identifier() }),
+        Err(identifier) => json!({ "is_resolved": false, "identifier": identifier.value }),
+    }
+}
+
+fn encodings_to_json(supported_encodings: &SupportedEncodings) -> Value {
+    let encodings = supported_encodings[..].iter().map(|encoding| encoding.to_string());
+    Value::Array(encodings.map(Value::String).collect())
+}
+
+/// JSON numbers can't hold every `i128`, so integers which don't fit in 64 bits are written as strings instead.
+fn integer_to_json(integer: i128) -> Value {
+    if let Ok(integer) = i64::try_from(integer) {
+        json!(integer)
+    } else if let Ok(integer) = u64::try_from(integer) {
+        json!(integer)
+    } else {
+        json!(integer.to_string())
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (c) ZeroC, Inc.
+
+//! `slicec-compat` checks whether a new version of a Slice API is wire-compatible with an old version of it
+//! (see [`slicec::compatibility`]).
+//!
+//! The new version is compiled from the usual Slice compiler options, and the old version from the files passed with
+//! `--old`. Both versions share the same references and preprocessor symbols. Breaking changes are reported as errors,
+//! and compatible changes are allowed (not reported); like any other lints, their levels can be configured with
+//! `--allow`, `--warn`, and `--deny`, or with attributes in the new version of the API.
+
+use clap::ArgAction::Append;
+use clap::Parser;
+use slicec::compatibility::check_compatibility;
+use slicec::compilation_state::CompilationState;
+use slicec::compile_from_options;
+use slicec::slice_file::SliceFile;
+use slicec::slice_options::SliceOptions;
+use std::process::exit;
+
+/// Checks whether a new version of a Slice API is wire-compatible with an old version of it.
+#[derive(Debug, Parser)]
+#[command(name = "slicec-compat", version, rename_all = "kebab-case")]
+struct CompatibilityOptions {
+    /// Add a Slice file to the sources of the old version of the API.
+    #[arg(long, value_name = "OLD_SOURCE", required = true, num_args = 1, action = Append)]
+    old: Vec<String>,
+
+    /// The options used to compile the new version of the API.
+    #[command(flatten)]
+    slice_options: SliceOptions,
+}
+
+pub fn main() {
+    let options = CompatibilityOptions::parse();
+    let new_options = options.slice_options;
+    let old_options = SliceOptions {
+        sources: options.old,
+        references: new_options.references.clone(),
+        defined_symbols: new_options.defined_symbols.clone(),
+        diagnostic_format: new_options.diagnostic_format,
+        disable_color: new_options.disable_color,
+        ..Default::default()
+    };
+
+    // If either version fails to compile, report its errors instead of comparing them.
+    let old_state = compile_from_options(&old_options, |_| {}, |_| {});
+    if old_state.diagnostic_reporter.has_errors() {
+        exit(old_state.into_exit_code());
+    }
+    let new_state = compile_from_options(&new_options, |_| {}, |_| {});
+    if new_state.diagnostic_reporter.has_errors() {
+        exit(new_state.into_exit_code());
+    }
+
+    exit(compare(old_state, new_state).into_exit_code());
+}
+
+/// Compares the two versions of the API, and returns a `CompilationState` that holds the resulting diagnostics, in place
+/// of the diagnostics reported while compiling the new version.
+fn compare(old_state: CompilationState, mut new_state: CompilationState) -> CompilationState {
+    new_state.diagnostic_reporter.diagnostics = check_compatibility(&old_state, &new_state);
+
+    // Diagnostics about removed elements point into the old files, so their contents are needed to emit snippets.
+    // Only the text is copied, since the rest of the old files refers into the old AST, which is dropped after this.
+    for (path, old_file) in &old_state.files {
+        let copy = || SliceFile::new(path.clone(), old_file.raw_text.clone(), old_file.is_source);
+        new_state.files.entry(path.clone()).or_insert_with(copy);
+    }
+    new_state
+}
@@ -0,0 +1,65 @@
+// Copyright (c) ZeroC, Inc.
+
+//! `slicec-fmt` formats Slice files in the canonical style (see [`slicec::formatter`]).
+//!
+//! By default, files are formatted in place. With `--check`, files are left untouched, and the command fails if any of
+//! them aren't already formatted.
+
+use clap::Parser;
+use slicec::formatter::format_slice;
+use std::fs;
+use std::process::exit;
+
+/// Formats Slice files in the canonical style.
+#[derive(Debug, Parser)]
+#[command(name = "slicec-fmt", version, rename_all = "kebab-case")]
+struct FormatOptions {
+    /// List of Slice files to format.
+    #[arg(required = true)]
+    files: Vec<String>,
+
+    /// Check whether the files are formatted, without modifying them. Files which aren't formatted are listed, and the
+    /// command exits with a non-zero exit code.
+    #[arg(long)]
+    check: bool,
+}
+
+pub fn main() {
+    let options = FormatOptions::parse();
+
+    let mut succeeded = true;
+    for file in &options.files {
+        if let Err(message) = format_file(file, options.check) {
+            eprintln!("{message}");
+            succeeded = false;
+        }
+    }
+    exit(i32::from(!succeeded));
+}
+
+/// Formats a single file, returning a message describing why it failed if it couldn't be formatted, or (in check mode)
+/// if it isn't already formatted.
+fn format_file(path: &str, check: bool) -> Result<(), String> {
+    let raw_text = fs::read_to_string(path).map_err(|error| format!("error: unable to read '{path}': {error}"))?;
+
+    let formatted = format_slice(path, &raw_text).map_err(|diagnostics| {
+        let messages = diagnostics.iter().map(|diagnostic| match diagnostic.span() {
+            Some(span) => format!(
+                "error: {path}:{}:{}: {}",
+                span.start.row,
+                span.start.col,
+                diagnostic.message()
+            ),
+            None => format!("error: {path}: {}", diagnostic.message()),
+        });
+        messages.collect::<Vec<_>>().join("\n")
+    })?;
+
+    if formatted == raw_text {
+        Ok(())
+    } else if check {
+        Err(format!("{path} is not formatted"))
+    } else {
+        fs::write(path, formatted).map_err(|error| format!("error: unable to write '{path}': {error}"))
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright (c) ZeroC, Inc.
+
+//! Functions for converting between the compiler's types, and their equivalent representations in LSP messages.
+
+use serde_json::{json, Value};
+use slicec::diagnostics::{Diagnostic, DiagnosticLevel};
+use slicec::grammar::*;
+use slicec::slice_file::{Location, Span};
+use std::path::{Path, PathBuf};
+
+/// Converts a `file` URI into a path, or returns `None` if the URI uses a different scheme.
+pub fn path_from_uri(uri: &str) -> Option<PathBuf> {
+    let encoded = uri.strip_prefix("file://")?.as_bytes();
+    let mut decoded = Vec::with_capacity(encoded.len());
+    let mut i = 0;
+    while i < encoded.len() {
+        let escaped = encoded.get(i + 1..i + 3).and_then(|hex| std::str::from_utf8(hex).ok());
+        match (encoded[i], escaped.and_then(|hex| u8::from_str_radix(hex, 16).ok())) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    Some(PathBuf::from(String::from_utf8(decoded).ok()?))
+}
+
+/// Converts an absolute path into a `file` URI, percent-encoding any characters which aren't allowed in URI paths.
+pub fn uri_from_path(path: &Path) -> String {
+    let mut uri = "file://".to_owned();
+    for byte in path.to_string_lossy().bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => uri.push(byte as char),
+            _ => uri += &format!("%{byte:02X}"),
+        }
+    }
+    uri
+}
+
+/// Converts a JSON-RPC position (a zero-based line and character) into a one-based location.
+///
+/// LSP counts characters in UTF-16 code units, whereas locations count Unicode characters, so the column is computed
+/// from the text of the line the position is on. Positions past the end of their line are clamped to it.
+pub fn location_from_position(position: &Value, text: &str) -> Option<Location> {
+    let line = position["line"].as_u64()? as usize;
+    let character = position["character"].as_u64()? as usize;
+
+    let mut units = 0;
+    let preceding_chars = line_text(text, line).chars().take_while(|c| {
+        units += c.len_utf16();
+        units <= character
+    });
+    Some(Location {
+        row: line + 1,
+        col: preceding_chars.count() + 1,
+    })
+}
+
+/// Converts a span into an LSP range, using the text of the file the span is in.
+/// Spans use one-based locations which count Unicode characters, whereas LSP uses zero-based positions which count
+/// UTF-16 code units.
+pub fn range_from_span(span: &Span, text: &str) -> Value {
+    let position = |location: &Location| {
+        let line = location.row - 1;
+        let preceding_chars = location.col - 1;
+        let line_text = line_text(text, line);
+        let units = line_text
+            .chars()
+            .take(preceding_chars)
+            .map(char::len_utf16)
+            .sum::<usize>();
+        // Spans can end past the last character of a line, so any remaining characters are counted as single units.
+        let past_end = preceding_chars.saturating_sub(line_text.chars().count());
+        json!({ "line": line, "character": units + past_end })
+    };
+    json!({
+        "start": position(&span.start),
+        "end": position(&span.end),
+    })
+}
+
+/// Returns the provided (zero-based) line of the provided text, or an empty string if the text has no such line.
+fn line_text(text: &str, line: usize) -> &str {
+    text.split('\n').nth(line).unwrap_or_default()
+}
+
+/// Returns true if the provided location falls within the provided span (the end of a span is exclusive).
+pub fn span_contains(span: &Span, location: &Location) -> bool {
+    let location = (location.row, location.col);
+    (span.start.row, span.start.col) <= location && location < (span.end.row, span.end.col)
+}
+
+/// Converts a diagnostic into an LSP diagnostic. The `file_info` function returns the URI and text of the file with the
+/// provided name, and is used to convert the spans of the diagnostic and its notes into LSP locations.
+///
+/// Notes with spans are converted into related information. Notes without spans have nowhere to be shown, so they're
+/// appended to the diagnostic's message instead.
+pub fn convert_diagnostic<'a>(
+    diagnostic: &Diagnostic,
+    span: &Span,
+    file_info: impl Fn(&str) -> Option<(String, &'a str)>,
+) -> Value {
+    let severity = match diagnostic.level() {
+        DiagnosticLevel::Error => 1,
+        DiagnosticLevel::Warning | DiagnosticLevel::Allowed => 2,
+    };
+
+    let mut message = diagnostic.message();
+    let mut related_information = Vec::new();
+    for note in diagnostic.notes() {
+        let location = note.span.as_ref().and_then(|s| Some((file_info(&s.file)?, s)));
+        match location {
+            Some(((uri, text), note_span)) => related_information.push(json!({
+                "location": { "uri": uri, "range": range_from_span(note_span, text) },
+                "message": note.message,
+            })),
+            None => message += &format!("\nnote: {}", note.message),
+        }
+    }
+
+    json!({
+        "range": range_from_span(span, file_info(&span.file).map_or("", |(_, text)| text)),
+        "severity": severity,
+        "code": diagnostic.code(),
+        "source": "slicec",
+        "message": message,
+        "relatedInformation": related_information,
+    })
+}
+
+/// Renders the overview of an entity's doc comment as markdown, or returns `None` if it doesn't have one.
+pub fn render_overview(entity: &dyn Entity) -> Option<String> {
+    let comment = match entity.concrete_entity() {
+        Entities::Struct(struct_def) => struct_def.comment(),
+        Entities::Class(class_def) => class_def.comment(),
+        Entities::Exception(exception_def) => exception_def.comment(),
+        Entities::Field(field) => field.comment(),
+        Entities::Interface(interface_def) => interface_def.comment(),
+        Entities::Operation(operation) => operation.comment(),
+        Entities::Parameter(parameter) => parameter.comment(),
+        Entities::Enum(enum_def) => enum_def.comment(),
+        Entities::Enumerator(enumerator) => enumerator.comment(),
+        Entities::CustomType(custom_type) => custom_type.comment(),
+        Entities::TypeAlias(type_alias) => type_alias.comment(),
+        Entities::Constant(constant) => constant.comment(),
+    };
+    let overview = comment?.overview.as_ref()?;
+    Some(render_message(&overview.message))
+}
+
+fn render_message(message: &Message) -> String {
+    let mut rendered = String::new();
+    for component in message {
+        match component {
+            MessageComponent::Text(text) => rendered += text,
+            MessageComponent::Link(link_tag) => match link_tag.linked_entity() {
+                Ok(entity) => rendered += &format!("`{}`", entity.identifier()),
+                Err(identifier) => rendered += &format!("`{}`", identifier.value),
+            },
+            MessageComponent::Code(code) => rendered += &format!("`{}`", code.code),
+            MessageComponent::Emphasis(emphasis) => {
+                let delimiter = if emphasis.is_strong { "**" } else { "*" };
+                rendered += &format!("{delimiter}{}{delimiter}", emphasis.text);
+            }
+            MessageComponent::List(list) => {
+                for item in &list.items {
+                    rendered += &format!("\n- {}", render_message(item).trim_end());
+                }
+                rendered += "\n";
+            }
+            MessageComponent::CodeBlock(code_block) => {
+                let language = code_block.language.as_deref().unwrap_or("");
+                rendered += &format!("\n```{language}\n{}\n```\n", code_block.code.trim_end());
+            }
+        }
+    }
+    rendered.trim().to_owned()
+}
@@ -0,0 +1,35 @@
+// Copyright (c) ZeroC, Inc.
+
+//! `slicec-lsp` is a language server for Slice. It communicates with editors over stdin and stdout, using the
+//! [Language Server Protocol](https://microsoft.github.io/language-server-protocol/).
+//!
+//! Every time a document is opened, changed, or closed, all the open documents are recompiled together (along with any
+//! unopened Slice files in the client's workspace), and the resulting diagnostics are published to the client. The
+//! server also supports hover, go-to-definition, and document symbol requests.
+//!
+//! Workspace files are read once when the server is initialized, and are only re-read when the client sends a
+//! `workspace/didChangeWatchedFiles` notification for them.
+
+mod convert;
+mod navigation;
+mod server;
+mod symbols;
+mod transport;
+
+use std::io::{stdin, stdout, BufReader};
+use std::process::exit;
+
+pub fn main() {
+    let mut input = BufReader::new(stdin().lock());
+    let mut output = stdout().lock();
+
+    let mut server = server::Server::default();
+    let exit_code = match server.run(&mut input, &mut output) {
+        Ok(()) => server.exit_code(),
+        Err(error) => {
+            eprintln!("slicec-lsp: {error}");
+            1
+        }
+    };
+    exit(exit_code);
+}
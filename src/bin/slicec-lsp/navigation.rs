@@ -0,0 +1,204 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::convert::{render_overview, span_contains};
+use slicec::grammar::*;
+use slicec::slice_file::{Location, SliceFile, Span};
+use slicec::visitor::Visitor;
+
+/// Information about the element found at a specific location in a Slice file.
+pub struct FoundElement {
+    /// The span of the element that was found (either an identifier or a type reference).
+    pub span: Span,
+
+    /// Markdown describing the element, for use in hover messages.
+    pub hover: String,
+
+    /// The span of the identifier of the definition that the element refers to, if there is one.
+    /// This is `None` for references to primitives, sequences, and dictionaries, since these have no definition.
+    pub definition: Option<Span>,
+}
+
+/// Finds the innermost identifier or type reference at the provided location in the provided file.
+///
+/// This can be used on ASTs with errors: references which couldn't be resolved are skipped, and types are
+/// described by the identifiers they were written with.
+pub fn find_element_at(slice_file: &SliceFile, location: Location) -> Option<FoundElement> {
+    let mut finder = ElementFinder { location, found: None };
+    slice_file.visit_with(&mut finder);
+    finder.found
+}
+
+struct ElementFinder {
+    location: Location,
+    found: Option<FoundElement>,
+}
+
+impl ElementFinder {
+    /// Checks whether the location is on the identifier of the provided definition.
+    fn check_definition(&mut self, entity: &dyn Entity, type_string: Option<String>) {
+        let identifier_span = entity.raw_identifier().span();
+        if span_contains(identifier_span, &self.location) {
+            let signature = match type_string {
+                Some(type_string) => format!("{} {}: {type_string}", entity.kind(), entity.identifier()),
+                None => format!("{} {}", entity.kind(), entity.module_scoped_identifier()),
+            };
+            self.found = Some(FoundElement {
+                span: identifier_span.clone(),
+                hover: format_hover(&signature, Some(entity)),
+                definition: Some(identifier_span.clone()),
+            });
+        }
+    }
+
+    /// Checks whether the location is on the provided type reference.
+    /// Nested type references are visited after their enclosing type reference, so the innermost one is kept.
+    fn check_reference(&mut self, span: &Span, type_string: String, definition: Option<&dyn Entity>) {
+        if span_contains(span, &self.location) {
+            self.found = Some(FoundElement {
+                span: span.clone(),
+                hover: format_hover(&type_string, definition),
+                definition: definition.map(|entity| entity.raw_identifier().span().clone()),
+            });
+        }
+    }
+
+    fn check_type_ref(&mut self, type_ref: &TypeRef) {
+        let TypeRefDefinition::Patched(definition) = &type_ref.definition else {
+            return;
+        };
+        let definition: Option<&dyn Entity> = match definition.borrow().concrete_type() {
+            Types::Struct(struct_def) => Some(struct_def),
+            Types::Class(class_def) => Some(class_def),
+            Types::Exception(exception_def) => Some(exception_def),
+            Types::Interface(interface_def) => Some(interface_def),
+            Types::Enum(enum_def) => Some(enum_def),
+            Types::CustomType(custom_type) => Some(custom_type),
+            Types::Sequence(_) | Types::Dictionary(_) | Types::Primitive(_) => None,
+        };
+        self.check_reference(type_ref.span(), type_string(type_ref), definition);
+    }
+
+    fn check_entity_ref<T: Entity + Type>(&mut self, type_ref: &TypeRef<T>) {
+        let TypeRefDefinition::Patched(definition) = &type_ref.definition else {
+            return;
+        };
+        self.check_reference(type_ref.span(), type_string(type_ref), Some(definition.borrow()));
+    }
+}
+
+impl Visitor for ElementFinder {
+    fn visit_file(&mut self, _: &SliceFile) {}
+
+    fn visit_module(&mut self, module_def: &Module) {
+        let identifier_span = module_def.raw_identifier().span();
+        if span_contains(identifier_span, &self.location) {
+            let signature = format!("module {}", module_def.nested_module_identifier());
+            self.found = Some(FoundElement {
+                span: identifier_span.clone(),
+                hover: format_hover(&signature, None),
+                definition: None,
+            });
+        }
+    }
+
+    fn visit_struct(&mut self, struct_def: &Struct) {
+        self.check_definition(struct_def, None);
+    }
+
+    fn visit_class(&mut self, class_def: &Class) {
+        self.check_definition(class_def, None);
+        if let Some(base) = &class_def.base {
+            self.check_entity_ref(base);
+        }
+    }
+
+    fn visit_exception(&mut self, exception_def: &Exception) {
+        self.check_definition(exception_def, None);
+        if let Some(base) = &exception_def.base {
+            self.check_entity_ref(base);
+        }
+    }
+
+    fn visit_interface(&mut self, interface_def: &Interface) {
+        self.check_definition(interface_def, None);
+        for base in &interface_def.bases {
+            self.check_entity_ref(base);
+        }
+    }
+
+    fn visit_enum(&mut self, enum_def: &Enum) {
+        self.check_definition(enum_def, None);
+        if let Some(underlying) = &enum_def.underlying {
+            let TypeRefDefinition::Patched(_) = &underlying.definition else {
+                return;
+            };
+            self.check_reference(underlying.span(), type_string(underlying), None);
+        }
+    }
+
+    fn visit_operation(&mut self, operation: &Operation) {
+        self.check_definition(operation, None);
+        if let Throws::Specific(exception_ref) = &operation.throws {
+            self.check_entity_ref(exception_ref);
+        }
+    }
+
+    fn visit_custom_type(&mut self, custom_type: &CustomType) {
+        self.check_definition(custom_type, None);
+    }
+
+    fn visit_type_alias(&mut self, type_alias: &TypeAlias) {
+        self.check_definition(type_alias, Some(type_string(&type_alias.underlying)));
+    }
+
+    fn visit_constant(&mut self, constant: &Constant) {
+        self.check_definition(constant, Some(type_string(&constant.data_type)));
+    }
+
+    fn visit_field(&mut self, field: &Field) {
+        self.check_definition(field, Some(type_string(&field.data_type)));
+    }
+
+    fn visit_parameter(&mut self, parameter: &Parameter) {
+        self.check_definition(parameter, Some(type_string(&parameter.data_type)));
+    }
+
+    fn visit_enumerator(&mut self, enumerator: &Enumerator) {
+        self.check_definition(enumerator, None);
+    }
+
+    fn visit_type_ref(&mut self, type_ref: &TypeRef) {
+        self.check_type_ref(type_ref);
+    }
+}
+
+/// Formats a hover message: a Slice code block containing the provided signature, followed by the overview of the
+/// provided entity's doc comment (if it has one).
+fn format_hover(signature: &str, entity: Option<&dyn Entity>) -> String {
+    let mut hover = format!("```slice\n{signature}\n```");
+    if let Some(overview) = entity.and_then(render_overview) {
+        hover += &format!("\n\n{overview}");
+    }
+    hover
+}
+
+/// Returns the type string of the provided type reference. Unlike [`TypeRef::type_string`], this doesn't panic if the
+/// type reference (or a type nested within it) is unpatched; the identifier it was written with is used instead.
+fn type_string<T: Type + ?Sized>(type_ref: &TypeRef<T>) -> String {
+    let mut s = match &type_ref.definition {
+        TypeRefDefinition::Patched(definition) => match definition.borrow().concrete_type() {
+            Types::Sequence(sequence) => format!("sequence<{}>", type_string(&sequence.element_type)),
+            Types::Dictionary(dictionary) => format!(
+                "dictionary<{}, {}>",
+                type_string(&dictionary.key_type),
+                type_string(&dictionary.value_type),
+            ),
+            _ => definition.borrow().type_string(),
+        },
+        TypeRefDefinition::Unpatched(identifier) => identifier.value.clone(),
+    };
+    if type_ref.is_optional {
+        s += "?";
+    }
+    s
+}
@@ -0,0 +1,354 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::convert::{convert_diagnostic, location_from_position, path_from_uri, range_from_span, uri_from_path};
+use crate::navigation::find_element_at;
+use crate::symbols::document_symbols;
+use crate::transport::{read_message, write_message};
+use serde_json::{json, Value};
+use slicec::compilation_state::CompilationState;
+use slicec::compile_from_strings;
+use slicec::diagnostics::DiagnosticLevel;
+use slicec::slice_file::{Location, SliceFile};
+use std::fs;
+use std::io::{BufRead, Result, Write};
+use std::path::{Path, PathBuf};
+
+/// The error code the JSON-RPC specification defines for requests with an unknown method.
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// The `FileChangeType` the LSP specification defines for deleted files (the others are for created and changed files).
+const FILE_DELETED: u64 = 3;
+
+/// The state produced by compiling all the open documents and workspace files, along with the URIs of those files.
+/// The file at index `i` of `uris` was compiled as the file named `string-{i}`. Open documents come first.
+struct Compilation {
+    state: CompilationState,
+    uris: Vec<String>,
+}
+
+impl Compilation {
+    fn file_for_uri(&self, uri: &str) -> Option<&SliceFile> {
+        let index = self.uris.iter().position(|u| u == uri)?;
+        self.state.files.get(&format!("string-{index}"))
+    }
+
+    fn uri_for_file(&self, file_name: &str) -> Option<&str> {
+        self.uris.get(document_index(file_name)?).map(String::as_str)
+    }
+
+    fn text_for_file(&self, file_name: &str) -> Option<&str> {
+        self.state.files.get(file_name).map(|file| file.raw_text.as_str())
+    }
+}
+
+#[derive(Default)]
+pub struct Server {
+    /// The URIs and contents of all the currently open documents, in the order they were opened.
+    documents: Vec<(String, String)>,
+
+    /// The paths and contents of the Slice files in the client's workspace folders. These are read when the server is
+    /// initialized, and only re-read when the client notifies us that they've changed on disk.
+    /// Any of these which aren't open are compiled alongside the open documents, so their definitions can be used.
+    workspace_files: Vec<(PathBuf, String)>,
+
+    /// Whether the client supports dynamically registering file watchers, see [`Server::register_file_watchers`].
+    can_register_file_watchers: bool,
+
+    /// The most recent compilation of the open documents. This is used to answer requests even if it has errors.
+    compilation: Option<Compilation>,
+
+    /// Whether the client has sent a `shutdown` request yet.
+    shutdown_requested: bool,
+}
+
+impl Server {
+    /// Reads and handles messages from `input` until the client sends an `exit` notification, or closes the input.
+    /// Any responses or notifications sent by the server are written to `output`.
+    pub fn run(&mut self, input: &mut impl BufRead, output: &mut impl Write) -> Result<()> {
+        while let Some(message) = read_message(input)? {
+            // Messages without a method are responses to requests sent by the server. The only request we send is to
+            // register file watchers, and there's nothing to do with its response.
+            let Some(method) = message["method"].as_str() else {
+                continue;
+            };
+            if method == "exit" {
+                break;
+            }
+
+            let params = &message["params"];
+            match message.get("id") {
+                Some(id) => {
+                    let response = match self.handle_request(method, params) {
+                        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                        Err((code, message)) => json!({
+                            "jsonrpc": "2.0",
+                            "id": id,
+                            "error": { "code": code, "message": message },
+                        }),
+                    };
+                    write_message(output, &response)?;
+                }
+                None => {
+                    for notification in self.handle_notification(method, params) {
+                        write_message(output, &notification)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the exit code the server should exit with. Per the LSP specification, this is 0 if the client sent a
+    /// `shutdown` request before exiting, and 1 otherwise.
+    pub fn exit_code(&self) -> i32 {
+        i32::from(!self.shutdown_requested)
+    }
+
+    fn handle_request(&mut self, method: &str, params: &Value) -> std::result::Result<Value, (i64, String)> {
+        match method {
+            "initialize" => {
+                self.workspace_files = find_workspace_files(params)
+                    .into_iter()
+                    .filter_map(|path| Some((path.clone(), fs::read_to_string(path).ok()?)))
+                    .collect();
+                let dynamic_registration = &params["capabilities"]["workspace"]["didChangeWatchedFiles"];
+                self.can_register_file_watchers = dynamic_registration["dynamicRegistration"] == true;
+                Ok(json!({
+                    "capabilities": {
+                        // Full document synchronization: the client sends the entire document on every change.
+                        "textDocumentSync": 1,
+                        "hoverProvider": true,
+                        "definitionProvider": true,
+                        "documentSymbolProvider": true,
+                    },
+                    "serverInfo": {
+                        "name": "slicec-lsp",
+                        "version": env!("CARGO_PKG_VERSION"),
+                    },
+                }))
+            }
+            "shutdown" => {
+                self.shutdown_requested = true;
+                Ok(Value::Null)
+            }
+            "textDocument/hover" => Ok(self.hover(params).unwrap_or_default()),
+            "textDocument/definition" => Ok(self.definition(params).unwrap_or_default()),
+            "textDocument/documentSymbol" => Ok(self.document_symbols(params).unwrap_or_default()),
+            _ => Err((METHOD_NOT_FOUND, format!("method '{method}' is not supported"))),
+        }
+    }
+
+    /// Handles a notification from the client, and returns any messages that should be sent in reply.
+    fn handle_notification(&mut self, method: &str, params: &Value) -> Vec<Value> {
+        match method {
+            "initialized" => self.register_file_watchers(),
+            "workspace/didChangeWatchedFiles" => {
+                self.update_workspace_files(params);
+                self.recompile()
+            }
+            _ => match params["textDocument"]["uri"].as_str() {
+                Some(uri) => self.handle_document_notification(method, uri, params),
+                None => Vec::new(),
+            },
+        }
+    }
+
+    /// Handles a notification about the document with the provided URI.
+    fn handle_document_notification(&mut self, method: &str, uri: &str, params: &Value) -> Vec<Value> {
+        match method {
+            "textDocument/didOpen" => {
+                let text = params["textDocument"]["text"].as_str().unwrap_or_default().to_owned();
+                match self.documents.iter_mut().find(|(u, _)| u == uri) {
+                    Some((_, contents)) => *contents = text,
+                    None => self.documents.push((uri.to_owned(), text)),
+                }
+                self.recompile()
+            }
+            "textDocument/didChange" => {
+                // We only support full document synchronization, so the last change holds the entire document.
+                let text = params["contentChanges"].as_array().and_then(|changes| changes.last());
+                let Some(text) = text.and_then(|change| change["text"].as_str()) else {
+                    return Vec::new();
+                };
+                if let Some((_, contents)) = self.documents.iter_mut().find(|(u, _)| u == uri) {
+                    *contents = text.to_owned();
+                }
+                self.recompile()
+            }
+            "textDocument/didClose" => {
+                self.documents.retain(|(u, _)| u != uri);
+                let mut notifications = self.recompile();
+                // Clear any diagnostics that were previously published for the closed document.
+                notifications.push(publish_diagnostics(uri, Vec::new()));
+                notifications
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Asks the client to notify us whenever a Slice file is created, changed, or deleted, if it supports doing so.
+    /// Otherwise, changes to unopened workspace files won't be seen until the server is restarted.
+    fn register_file_watchers(&self) -> Vec<Value> {
+        if !self.can_register_file_watchers {
+            return Vec::new();
+        }
+        let registration = json!({
+            "id": "slice-file-watcher",
+            "method": "workspace/didChangeWatchedFiles",
+            "registerOptions": { "watchers": [{ "globPattern": "**/*.slice" }] },
+        });
+        vec![json!({
+            "jsonrpc": "2.0",
+            "id": "register-file-watchers",
+            "method": "client/registerCapability",
+            "params": { "registrations": [registration] },
+        })]
+    }
+
+    /// Updates the stored workspace files with the changes from a `workspace/didChangeWatchedFiles` notification.
+    fn update_workspace_files(&mut self, params: &Value) {
+        for change in params["changes"].as_array().into_iter().flatten() {
+            let path = change["uri"].as_str().and_then(path_from_uri);
+            let Some(path) = path.filter(|path| path.extension().is_some_and(|extension| extension == "slice")) else {
+                continue;
+            };
+
+            // Remove any stale contents, and then re-read the file if it still exists.
+            self.workspace_files.retain(|(p, _)| *p != path);
+            if change["type"] != FILE_DELETED {
+                if let Ok(contents) = fs::read_to_string(&path) {
+                    self.workspace_files.push((path, contents));
+                }
+            }
+        }
+    }
+
+    /// Compiles all the open documents (along with any unopened workspace files), and returns a `publishDiagnostics`
+    /// notification for each of the open documents.
+    fn recompile(&mut self) -> Vec<Value> {
+        let open_paths = self
+            .documents
+            .iter()
+            .filter_map(|(uri, _)| path_from_uri(uri))
+            .collect::<Vec<_>>();
+        let workspace_files = self
+            .workspace_files
+            .iter()
+            .filter(|(path, _)| !open_paths.contains(path))
+            .map(|(path, contents)| (uri_from_path(path), contents.as_str()));
+
+        let documents = self.documents.iter().map(|(uri, text)| (uri.clone(), text.as_str()));
+        let (uris, inputs): (Vec<_>, Vec<_>) = documents.chain(workspace_files).unzip();
+
+        let mut state = compile_from_strings(&inputs, None, |_| {}, |_| {});
+        state.diagnostic_reporter.update_diagnostics(&state.ast, &state.files);
+        let compilation = Compilation { state, uris };
+
+        // Diagnostics are only published for open documents, not for workspace files.
+        let mut diagnostics_by_document = vec![Vec::new(); self.documents.len()];
+        for diagnostic in &compilation.state.diagnostic_reporter.diagnostics {
+            // Diagnostics without spans (like those about command line options) can't be shown in any document.
+            let Some(span) = diagnostic.span() else {
+                continue;
+            };
+            if diagnostic.level() == DiagnosticLevel::Allowed {
+                continue;
+            }
+            let document_diagnostics = document_index(&span.file).and_then(|i| diagnostics_by_document.get_mut(i));
+            if let Some(document_diagnostics) = document_diagnostics {
+                let file_info = |file: &str| {
+                    let uri = compilation.uri_for_file(file)?.to_owned();
+                    Some((uri, compilation.text_for_file(file)?))
+                };
+                document_diagnostics.push(convert_diagnostic(diagnostic, span, file_info));
+            }
+        }
+
+        let notifications = compilation
+            .uris
+            .iter()
+            .zip(diagnostics_by_document)
+            .map(|(uri, diagnostics)| publish_diagnostics(uri, diagnostics))
+            .collect();
+
+        self.compilation = Some(compilation);
+        notifications
+    }
+
+    /// Returns the compilation, file, and location referenced by a `TextDocumentPositionParams`.
+    fn resolve_position(&self, params: &Value) -> Option<(&Compilation, &SliceFile, Location)> {
+        let compilation = self.compilation.as_ref()?;
+        let slice_file = compilation.file_for_uri(params["textDocument"]["uri"].as_str()?)?;
+        let location = location_from_position(&params["position"], &slice_file.raw_text)?;
+        Some((compilation, slice_file, location))
+    }
+
+    fn hover(&self, params: &Value) -> Option<Value> {
+        let (_, slice_file, location) = self.resolve_position(params)?;
+        let element = find_element_at(slice_file, location)?;
+        Some(json!({
+            "contents": { "kind": "markdown", "value": element.hover },
+            "range": range_from_span(&element.span, &slice_file.raw_text),
+        }))
+    }
+
+    fn definition(&self, params: &Value) -> Option<Value> {
+        let (compilation, slice_file, location) = self.resolve_position(params)?;
+        let definition = find_element_at(slice_file, location)?.definition?;
+        Some(json!({
+            "uri": compilation.uri_for_file(&definition.file)?,
+            "range": range_from_span(&definition, compilation.text_for_file(&definition.file)?),
+        }))
+    }
+
+    fn document_symbols(&self, params: &Value) -> Option<Value> {
+        let compilation = self.compilation.as_ref()?;
+        let uri = params["textDocument"]["uri"].as_str()?;
+        let slice_file = compilation.file_for_uri(uri)?;
+        Some(Value::from(document_symbols(slice_file, uri)))
+    }
+}
+
+/// Returns the index of the document that was compiled as the provided file, see [`Compilation`].
+fn document_index(file_name: &str) -> Option<usize> {
+    file_name.strip_prefix("string-")?.parse().ok()
+}
+
+/// Returns the paths of all the Slice files in the workspace folders (or root) provided by an `initialize` request.
+fn find_workspace_files(params: &Value) -> Vec<PathBuf> {
+    let roots = match params["workspaceFolders"].as_array() {
+        Some(folders) => folders.iter().filter_map(|folder| folder["uri"].as_str()).collect(),
+        None => params["rootUri"].as_str().into_iter().collect::<Vec<_>>(),
+    };
+
+    let mut files = Vec::new();
+    for root in roots.into_iter().filter_map(path_from_uri) {
+        find_slice_files_in(&root, &mut files);
+    }
+    files
+}
+
+/// Recursively finds all the Slice files in the provided directory, skipping any hidden directories.
+fn find_slice_files_in(directory: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return;
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let is_hidden = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        if path.is_dir() && !is_hidden {
+            find_slice_files_in(&path, files);
+        } else if path.is_file() && path.extension().is_some_and(|extension| extension == "slice") {
+            files.push(path);
+        }
+    }
+}
+
+fn publish_diagnostics(uri: &str, diagnostics: Vec<Value>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": { "uri": uri, "diagnostics": diagnostics },
+    })
+}
@@ -0,0 +1,116 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::convert::range_from_span;
+use serde_json::{json, Value};
+use slicec::grammar::*;
+use slicec::slice_file::SliceFile;
+use slicec::visitor::Visitor;
+
+// Symbol kinds, as defined by the LSP specification.
+const MODULE: u32 = 2;
+const CLASS: u32 = 5;
+const METHOD: u32 = 6;
+const FIELD: u32 = 8;
+const ENUM: u32 = 10;
+const INTERFACE: u32 = 11;
+const VARIABLE: u32 = 13;
+const CONSTANT: u32 = 14;
+const ENUM_MEMBER: u32 = 22;
+const STRUCT: u32 = 23;
+const TYPE_PARAMETER: u32 = 26;
+
+/// Returns a `SymbolInformation` for every definition in the provided file (including modules).
+///
+/// This only looks at definitions, not type references, so it can be used on ASTs with errors.
+pub fn document_symbols(slice_file: &SliceFile, uri: &str) -> Vec<Value> {
+    let mut collector = SymbolCollector {
+        uri,
+        text: &slice_file.raw_text,
+        symbols: Vec::new(),
+    };
+    slice_file.visit_with(&mut collector);
+    collector.symbols
+}
+
+struct SymbolCollector<'a> {
+    uri: &'a str,
+    text: &'a str,
+    symbols: Vec<Value>,
+}
+
+impl SymbolCollector<'_> {
+    fn add_symbol(&mut self, symbol: &dyn NamedSymbol, container_name: Option<&str>, kind: u32) {
+        self.symbols.push(json!({
+            "name": symbol.identifier(),
+            "kind": kind,
+            "location": {
+                "uri": self.uri,
+                "range": range_from_span(symbol.span(), self.text),
+            },
+            "containerName": container_name,
+        }));
+    }
+
+    fn add_entity(&mut self, entity: &dyn Entity, kind: u32) {
+        let container_name = Some(entity.parser_scope()).filter(|scope| !scope.is_empty());
+        self.add_symbol(entity, container_name, kind);
+    }
+}
+
+impl Visitor for SymbolCollector<'_> {
+    fn visit_file(&mut self, _: &SliceFile) {}
+
+    fn visit_module(&mut self, module_def: &Module) {
+        self.add_symbol(module_def, None, MODULE);
+    }
+
+    fn visit_struct(&mut self, struct_def: &Struct) {
+        self.add_entity(struct_def, STRUCT);
+    }
+
+    fn visit_class(&mut self, class_def: &Class) {
+        self.add_entity(class_def, CLASS);
+    }
+
+    fn visit_exception(&mut self, exception_def: &Exception) {
+        self.add_entity(exception_def, CLASS);
+    }
+
+    fn visit_interface(&mut self, interface_def: &Interface) {
+        self.add_entity(interface_def, INTERFACE);
+    }
+
+    fn visit_enum(&mut self, enum_def: &Enum) {
+        self.add_entity(enum_def, ENUM);
+    }
+
+    fn visit_operation(&mut self, operation: &Operation) {
+        self.add_entity(operation, METHOD);
+    }
+
+    fn visit_custom_type(&mut self, custom_type: &CustomType) {
+        self.add_entity(custom_type, TYPE_PARAMETER);
+    }
+
+    fn visit_type_alias(&mut self, type_alias: &TypeAlias) {
+        self.add_entity(type_alias, TYPE_PARAMETER);
+    }
+
+    fn visit_constant(&mut self, constant: &Constant) {
+        self.add_entity(constant, CONSTANT);
+    }
+
+    fn visit_field(&mut self, field: &Field) {
+        self.add_entity(field, FIELD);
+    }
+
+    fn visit_parameter(&mut self, parameter: &Parameter) {
+        self.add_entity(parameter, VARIABLE);
+    }
+
+    fn visit_enumerator(&mut self, enumerator: &Enumerator) {
+        self.add_entity(enumerator, ENUM_MEMBER);
+    }
+
+    fn visit_type_ref(&mut self, _: &TypeRef) {}
+}
@@ -0,0 +1,55 @@
+// Copyright (c) ZeroC, Inc.
+
+use serde_json::Value;
+use std::io::{BufRead, Error, ErrorKind, Result, Write};
+
+/// Reads a single JSON-RPC message from the provided input, using the framing defined by the LSP specification:
+/// a set of `Name: value` headers terminated by an empty line, followed by a JSON body of `Content-Length` bytes.
+///
+/// Returns `Ok(None)` if the input was closed before a message started.
+pub fn read_message(input: &mut impl BufRead) -> Result<Option<Value>> {
+    let mut content_length = None;
+
+    loop {
+        let mut header = String::new();
+        if input.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+
+        // An empty line marks the end of the headers.
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+
+        // We only care about the `Content-Length` header. The only other header, `Content-Type`, is ignored.
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("Content-Length") {
+                let length = value
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+                content_length = Some(length);
+            }
+        }
+    }
+
+    let Some(content_length) = content_length else {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "message is missing a 'Content-Length' header",
+        ));
+    };
+
+    let mut body = vec![0; content_length];
+    input.read_exact(&mut body)?;
+    let message = serde_json::from_slice(&body).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+    Ok(Some(message))
+}
+
+/// Writes a single JSON-RPC message to the provided output, with a `Content-Length` header.
+pub fn write_message(output: &mut impl Write, message: &Value) -> Result<()> {
+    let body = message.to_string();
+    write!(output, "Content-Length: {}\r\n\r\n{body}", body.len())?;
+    output.flush()
+}
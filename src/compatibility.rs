@@ -0,0 +1,458 @@
+// Copyright (c) ZeroC, Inc.
+
+//! Checks whether a new version of a Slice API is wire-compatible with an old version of it.
+//!
+//! Both versions are compiled separately, and then compared definition by definition, matching elements by their
+//! scoped identifiers. Changes which would stop deployed peers from communicating are reported with the
+//! [`BreakingChange`](Lint::BreakingChange) lint, and changes which are safe are reported with the
+//! [`CompatibleChange`](Lint::CompatibleChange) lint. Diagnostics are reported on the new version of an element, with a
+//! note pointing to its old version. Changes that remove an element are reported on the old version of it instead.
+//!
+//! Since lint levels are resolved against the new version, breaking changes can be intentionally accepted by applying
+//! `[allow(BreakingChange)]` to the changed element (or to any element enclosing it).
+
+use crate::ast::node::Node;
+use crate::ast::Ast;
+use crate::compilation_state::CompilationState;
+use crate::diagnostics::{Diagnostic, Lint};
+use crate::grammar::*;
+use crate::utils::string_util::indefinite_article;
+
+/// Compares the definitions of two compilations, and returns diagnostics describing the differences between them that
+/// affect the wire. Both compilations must have completed without errors.
+///
+/// The following changes are considered breaking:
+/// - Adding or removing non-tagged fields, parameters, or return members, or changing the type of the one at any
+///   position. Non-tagged members are matched by position, so reordering members of different types is breaking.
+/// - Reusing a tag for a different member, changing the tag of a member, or changing the type of a tagged member.
+/// - Changing the value of an enumerator, removing an enumerator from a checked enum, or changing an enum's underlying
+///   type.
+/// - Removing an operation.
+/// - Removing support for an encoding from a type.
+/// - Changing the kind of a type (ex: changing a struct into an exception).
+/// - Adding, removing, or changing the base type of a class or exception.
+/// - Changing whether a struct is compact, or whether an enum is unchecked.
+///
+/// The following changes are reported as compatible: renaming non-tagged members, adding or removing tagged members,
+/// adding operations to an existing interface, and removing enumerators from unchecked enums.
+pub fn check_compatibility(old_state: &CompilationState, new_state: &CompilationState) -> Vec<Diagnostic> {
+    let mut checker = CompatibilityChecker {
+        new_ast: &new_state.ast,
+        diagnostics: Vec::new(),
+    };
+
+    for old_node in old_state.ast.as_slice() {
+        checker.check_node(old_node);
+    }
+
+    // Report any operations which were added to interfaces that exist in the old compilation.
+    for new_node in new_state.ast.as_slice() {
+        if let Node::Operation(operation_ptr) = new_node {
+            let operation = operation_ptr.borrow();
+            let identifier = operation.parser_scoped_identifier();
+            let interface_identifier = operation.parent().parser_scoped_identifier();
+            let is_new = old_state.ast.find_element::<Operation>(&identifier).is_err();
+            if is_new && old_state.ast.find_element::<Interface>(&interface_identifier).is_ok() {
+                let message = format!("operation '{identifier}' was added");
+                checker.report(Lint::CompatibleChange { message }, operation, None);
+            }
+        }
+    }
+
+    checker.diagnostics
+}
+
+struct CompatibilityChecker<'a> {
+    new_ast: &'a Ast,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> CompatibilityChecker<'a> {
+    fn check_node(&mut self, old_node: &Node) {
+        if let Node::Operation(operation_ptr) = old_node {
+            return self.check_operation(operation_ptr.borrow());
+        }
+        let Some((old_entity, old_type)) = as_definition(old_node) else {
+            return;
+        };
+        let identifier = old_entity.parser_scoped_identifier();
+
+        // Types can be safely removed, as long as nothing references them. Any references to a removed type are
+        // reported as type changes on the members which held them.
+        let Some((new_entity, new_type)) = self.new_ast.find_node(&identifier).ok().and_then(as_definition) else {
+            return;
+        };
+
+        match (old_type.concrete_type(), new_type.concrete_type()) {
+            (Types::Struct(old_struct), Types::Struct(new_struct)) => {
+                if old_struct.is_compact != new_struct.is_compact {
+                    let message = match new_struct.is_compact {
+                        true => format!("struct '{identifier}' was changed from non-compact to compact"),
+                        false => format!("struct '{identifier}' was changed from compact to non-compact"),
+                    };
+                    self.report(Lint::BreakingChange { message }, new_struct, Some(old_struct));
+                }
+                self.check_members(old_struct.fields(), new_struct.fields(), new_struct, field_signature);
+            }
+            (Types::Class(old_class), Types::Class(new_class)) => {
+                let old_base = old_class.base_class().map(|base| base as &dyn Entity);
+                let new_base = new_class.base_class().map(|base| base as &dyn Entity);
+                self.check_base(old_base, new_base, new_class, old_class);
+                self.check_members(old_class.fields(), new_class.fields(), new_class, field_signature);
+            }
+            (Types::Exception(old_exception), Types::Exception(new_exception)) => {
+                let old_base = old_exception.base_exception().map(|base| base as &dyn Entity);
+                let new_base = new_exception.base_exception().map(|base| base as &dyn Entity);
+                self.check_base(old_base, new_base, new_exception, old_exception);
+                let (old_fields, new_fields) = (old_exception.fields(), new_exception.fields());
+                self.check_members(old_fields, new_fields, new_exception, field_signature);
+            }
+            (Types::Enum(old_enum), Types::Enum(new_enum)) => self.check_enum(old_enum, new_enum),
+            (Types::Interface(_), Types::Interface(_)) | (Types::CustomType(_), Types::CustomType(_)) => {}
+            _ => {
+                let (old_kind, new_kind) = (old_entity.kind(), new_entity.kind());
+                let article = indefinite_article(new_kind);
+                let message = format!("{old_kind} '{identifier}' was changed into {article} {new_kind}");
+                self.report(Lint::BreakingChange { message }, new_entity, Some(old_entity));
+                return;
+            }
+        }
+
+        // Check that the type still supports every encoding it used to.
+        let (old_encodings, new_encodings) = (old_type.supported_encodings(), new_type.supported_encodings());
+        for encoding in [Encoding::Slice1, Encoding::Slice2] {
+            if old_encodings.supports(&encoding) && !new_encodings.supports(&encoding) {
+                let kind = new_entity.kind();
+                let message = format!("{kind} '{identifier}' no longer supports the {encoding} encoding");
+                self.report(Lint::BreakingChange { message }, new_entity, Some(old_entity));
+            }
+        }
+    }
+
+    fn check_operation(&mut self, old_operation: &Operation) {
+        let identifier = old_operation.parser_scoped_identifier();
+        match self.new_ast.find_element::<Operation>(&identifier) {
+            Ok(new_operation) => {
+                let parameter_signature = |parameter: &Parameter| match parameter.is_streamed {
+                    true => format!("stream {}", type_signature(&parameter.data_type)),
+                    false => type_signature(&parameter.data_type),
+                };
+                self.check_members(
+                    old_operation.parameters(),
+                    new_operation.parameters(),
+                    new_operation,
+                    parameter_signature,
+                );
+                self.check_members(
+                    old_operation.return_members(),
+                    new_operation.return_members(),
+                    new_operation,
+                    parameter_signature,
+                );
+            }
+            Err(_) => {
+                let message = format!("operation '{identifier}' was removed");
+                let diagnostic = Diagnostic::new(Lint::BreakingChange { message })
+                    .set_span(old_operation.raw_identifier().span())
+                    .set_scope(old_operation.parent().parser_scoped_identifier());
+                self.diagnostics.push(diagnostic);
+            }
+        }
+    }
+
+    /// Checks that a class or exception still has the same base type. The fields of a type are encoded after the fields
+    /// of its bases, so adding, removing, or changing a base type changes how the type is encoded.
+    fn check_base(
+        &mut self,
+        old_base: Option<&dyn Entity>,
+        new_base: Option<&dyn Entity>,
+        new_type: &dyn Entity,
+        old_type: &dyn Entity,
+    ) {
+        let old_base = old_base.map(|base| base.parser_scoped_identifier());
+        let new_base = new_base.map(|base| base.parser_scoped_identifier());
+        if old_base != new_base {
+            let message = format!(
+                "base type of {} '{}' changed from {} to {}",
+                new_type.kind(),
+                new_type.parser_scoped_identifier(),
+                old_base.map_or("none".to_owned(), |base| format!("'{base}'")),
+                new_base.map_or("none".to_owned(), |base| format!("'{base}'")),
+            );
+            self.report(Lint::BreakingChange { message }, new_type, Some(old_type));
+        }
+    }
+
+    fn check_enum(&mut self, old_enum: &Enum, new_enum: &Enum) {
+        if old_enum.is_unchecked != new_enum.is_unchecked {
+            let identifier = new_enum.parser_scoped_identifier();
+            let message = match new_enum.is_unchecked {
+                true => format!("enum '{identifier}' was changed from checked to unchecked"),
+                false => format!("enum '{identifier}' was changed from unchecked to checked"),
+            };
+            self.report(Lint::BreakingChange { message }, new_enum, Some(old_enum));
+        }
+
+        let old_underlying = old_enum.underlying.as_ref().map(|underlying| underlying.type_string());
+        let new_underlying = new_enum.underlying.as_ref().map(|underlying| underlying.type_string());
+        if old_underlying != new_underlying {
+            let message = format!(
+                "underlying type of enum '{}' changed from {} to {}",
+                new_enum.parser_scoped_identifier(),
+                old_underlying.map_or("none".to_owned(), |underlying| format!("'{underlying}'")),
+                new_underlying.map_or("none".to_owned(), |underlying| format!("'{underlying}'")),
+            );
+            self.report(Lint::BreakingChange { message }, new_enum, Some(old_enum));
+        }
+
+        for old_enumerator in old_enum.enumerators() {
+            let new_enumerator = new_enum
+                .enumerators()
+                .into_iter()
+                .find(|new_enumerator| new_enumerator.identifier() == old_enumerator.identifier());
+
+            match new_enumerator {
+                Some(new_enumerator) => {
+                    if old_enumerator.value() != new_enumerator.value() {
+                        let message = format!(
+                            "value of enumerator '{}' changed from {} to {}",
+                            new_enumerator.parser_scoped_identifier(),
+                            old_enumerator.value(),
+                            new_enumerator.value(),
+                        );
+                        self.report(Lint::BreakingChange { message }, new_enumerator, Some(old_enumerator));
+                    }
+                    self.check_members(
+                        old_enumerator.fields(),
+                        new_enumerator.fields(),
+                        new_enumerator,
+                        field_signature,
+                    );
+                }
+                None => {
+                    let identifier = old_enumerator.parser_scoped_identifier();
+                    let lint = match old_enum.is_unchecked {
+                        true => Lint::CompatibleChange {
+                            message: format!("enumerator '{identifier}' was removed from unchecked enum"),
+                        },
+                        false => Lint::BreakingChange {
+                            message: format!("enumerator '{identifier}' was removed from checked enum"),
+                        },
+                    };
+                    let diagnostic = Diagnostic::new(lint)
+                        .set_span(old_enumerator.raw_identifier().span())
+                        .set_scope(new_enum.parser_scoped_identifier());
+                    self.diagnostics.push(diagnostic);
+                }
+            }
+        }
+    }
+
+    /// Compares the members of two versions of a container.
+    ///
+    /// Non-tagged members are encoded in order, so they must match by position and type. Tagged members are matched by
+    /// tag, so they can be freely added and removed, but a tag can never be given to a different member.
+    fn check_members<T: Member>(
+        &mut self,
+        old_members: Vec<&T>,
+        new_members: Vec<&T>,
+        new_container: &dyn Entity,
+        signature: impl Fn(&T) -> String,
+    ) {
+        let (old_required, old_tagged): (Vec<&T>, Vec<&T>) = old_members.iter().partition(|m| !m.is_tagged());
+        let (new_required, new_tagged): (Vec<&T>, Vec<&T>) = new_members.iter().partition(|m| !m.is_tagged());
+
+        // Non-tagged members are encoded by position, so they're matched by position, instead of by identifier.
+        // Only the type at each position matters on the wire, so renaming a member is compatible.
+        for position in 0..old_required.len().max(new_required.len()) {
+            match (old_required.get(position), new_required.get(position)) {
+                (Some(&old_member), Some(&new_member)) => {
+                    let (old_signature, new_signature) = (signature(old_member), signature(new_member));
+                    let kind = new_member.kind();
+                    if old_member.identifier() == new_member.identifier() {
+                        self.check_member_type(old_member, new_member, &signature);
+                    } else if old_signature != new_signature {
+                        let message = format!(
+                            "type of non-tagged {kind} at position {} changed from '{old_signature}' to '{new_signature}'",
+                            position + 1,
+                        );
+                        self.report(Lint::BreakingChange { message }, new_member, Some(old_member));
+                    } else {
+                        let message = format!(
+                            "non-tagged {kind} '{}' was renamed to '{}'",
+                            old_member.parser_scoped_identifier(),
+                            new_member.identifier(),
+                        );
+                        self.report(Lint::CompatibleChange { message }, new_member, Some(old_member));
+                    }
+                }
+
+                // A non-tagged member was removed, or had a tag added to it.
+                (Some(&old_member), None) => {
+                    let kind = old_member.kind();
+                    let identifier = old_member.parser_scoped_identifier();
+                    match find_by_identifier(&new_members, old_member) {
+                        Some(new_member) if new_member.is_tagged() => {
+                            let message = format!("{kind} '{identifier}' was changed from non-tagged to tagged");
+                            self.report(Lint::BreakingChange { message }, new_member, Some(old_member));
+                        }
+                        _ => {
+                            let message = format!("non-tagged {kind} '{identifier}' was removed");
+                            let diagnostic = Diagnostic::new(Lint::BreakingChange { message })
+                                .set_span(old_member.raw_identifier().span())
+                                .set_scope(new_container.parser_scoped_identifier());
+                            self.diagnostics.push(diagnostic);
+                        }
+                    }
+                }
+
+                // A non-tagged member was added, or had its tag removed.
+                (None, Some(&new_member)) => {
+                    let kind = new_member.kind();
+                    let identifier = new_member.parser_scoped_identifier();
+                    match find_by_identifier(&old_members, new_member) {
+                        Some(old_member) if old_member.is_tagged() => {
+                            let message = format!("{kind} '{identifier}' was changed from tagged to non-tagged");
+                            self.report(Lint::BreakingChange { message }, new_member, Some(old_member));
+                        }
+                        _ => {
+                            let message = format!("non-tagged {kind} '{identifier}' was added");
+                            self.report(Lint::BreakingChange { message }, new_member, None);
+                        }
+                    }
+                }
+
+                (None, None) => unreachable!("position is beyond both lists of members"),
+            }
+        }
+
+        // Tagged members must keep their tags and types, and tags can't be reused for different members.
+        for &new_member in &new_tagged {
+            let kind = new_member.kind();
+            let identifier = new_member.parser_scoped_identifier();
+            let tag = new_member.tag().unwrap();
+
+            let old_member = find_by_identifier(&old_members, new_member);
+            match old_member {
+                Some(old_member) if old_member.tag() == Some(tag) => {
+                    self.check_member_type(old_member, new_member, &signature);
+                }
+                Some(old_member) if old_member.is_tagged() => {
+                    let old_tag = old_member.tag().unwrap();
+                    let message = format!("tag of {kind} '{identifier}' changed from {old_tag} to {tag}");
+                    self.report(Lint::BreakingChange { message }, new_member, Some(old_member));
+                }
+                Some(_) => {}
+                None => {}
+            }
+
+            let previous_owner = old_tagged.iter().find(|m| m.tag() == Some(tag));
+            match previous_owner {
+                Some(&previous_owner) if previous_owner.identifier() != new_member.identifier() => {
+                    let message = format!(
+                        "tag {tag} of {kind} '{identifier}' was previously used by {} '{}'",
+                        previous_owner.kind(),
+                        previous_owner.parser_scoped_identifier(),
+                    );
+                    self.report(Lint::BreakingChange { message }, new_member, Some(previous_owner));
+                }
+                None if old_member.is_none() => {
+                    let message = format!("tagged {kind} '{identifier}' was added");
+                    self.report(Lint::CompatibleChange { message }, new_member, None);
+                }
+                _ => {}
+            }
+        }
+
+        // Tagged members can be removed, as long as their tags are never reused.
+        for &old_member in &old_tagged {
+            if find_by_identifier(&new_members, old_member).is_none() {
+                let kind = old_member.kind();
+                let identifier = old_member.parser_scoped_identifier();
+                let message = format!("tagged {kind} '{identifier}' was removed");
+                let diagnostic = Diagnostic::new(Lint::CompatibleChange { message })
+                    .set_span(old_member.raw_identifier().span())
+                    .set_scope(new_container.parser_scoped_identifier());
+                self.diagnostics.push(diagnostic);
+            }
+        }
+    }
+
+    fn check_member_type<T: Member>(&mut self, old_member: &T, new_member: &T, signature: &impl Fn(&T) -> String) {
+        let (old_signature, new_signature) = (signature(old_member), signature(new_member));
+        if old_signature != new_signature {
+            let message = format!(
+                "type of {} '{}' changed from '{old_signature}' to '{new_signature}'",
+                new_member.kind(),
+                new_member.parser_scoped_identifier(),
+            );
+            self.report(Lint::BreakingChange { message }, new_member, Some(old_member));
+        }
+    }
+
+    /// Reports a change to an element that exists in the new compilation. If the element also existed in the old
+    /// compilation, a note pointing to its old version is added to the diagnostic.
+    fn report(&mut self, lint: Lint, new_element: &dyn Entity, old_element: Option<&dyn Entity>) {
+        let mut diagnostic = Diagnostic::new(lint)
+            .set_span(new_element.raw_identifier().span())
+            .set_scope(new_element.parser_scoped_identifier());
+        if let Some(old_element) = old_element {
+            let message = format!(
+                "{} '{}' was previously defined here",
+                old_element.kind(),
+                old_element.identifier()
+            );
+            diagnostic = diagnostic.add_note(message, Some(old_element.raw_identifier().span()));
+        }
+        self.diagnostics.push(diagnostic);
+    }
+}
+
+/// Returns the signature of a field's type.
+fn field_signature(field: &Field) -> String {
+    type_signature(&field.data_type)
+}
+
+/// Returns a string describing the type that a type reference points to, where any user defined types are written
+/// with their fully scoped identifiers. Unlike [`TypeRef::type_string`], two references to the same type will always
+/// have the same signature, regardless of how they were written, or whether they went through a type alias.
+fn type_signature(type_ref: &TypeRef) -> String {
+    let signature = match type_ref.concrete_type() {
+        Types::Struct(struct_def) => struct_def.parser_scoped_identifier(),
+        Types::Class(class_def) => class_def.parser_scoped_identifier(),
+        Types::Exception(exception_def) => exception_def.parser_scoped_identifier(),
+        Types::Interface(interface_def) => interface_def.parser_scoped_identifier(),
+        Types::Enum(enum_def) => enum_def.parser_scoped_identifier(),
+        Types::CustomType(custom_type) => custom_type.parser_scoped_identifier(),
+        Types::Sequence(sequence) => format!("sequence<{}>", type_signature(&sequence.element_type)),
+        Types::Dictionary(dictionary) => format!(
+            "dictionary<{}, {}>",
+            type_signature(&dictionary.key_type),
+            type_signature(&dictionary.value_type),
+        ),
+        Types::Primitive(primitive) => primitive.type_string(),
+    };
+
+    match type_ref.is_optional {
+        true => signature + "?",
+        false => signature,
+    }
+}
+
+/// Returns the user defined type held by the provided node (if it holds one), as both an [Entity] and a [Type].
+fn as_definition(node: &Node) -> Option<(&dyn Entity, &dyn Type)> {
+    match node {
+        Node::Struct(struct_ptr) => Some((struct_ptr.borrow(), struct_ptr.borrow())),
+        Node::Class(class_ptr) => Some((class_ptr.borrow(), class_ptr.borrow())),
+        Node::Exception(exception_ptr) => Some((exception_ptr.borrow(), exception_ptr.borrow())),
+        Node::Interface(interface_ptr) => Some((interface_ptr.borrow(), interface_ptr.borrow())),
+        Node::Enum(enum_ptr) => Some((enum_ptr.borrow(), enum_ptr.borrow())),
+        Node::CustomType(custom_type_ptr) => Some((custom_type_ptr.borrow(), custom_type_ptr.borrow())),
+        _ => None,
+    }
+}
+
+/// Returns the member with the same identifier as `member` from the provided list, if there is one.
+fn find_by_identifier<'b, T: Member>(members: &[&'b T], member: &T) -> Option<&'b T> {
+    members.iter().find(|m| m.identifier() == member.identifier()).copied()
+}
@@ -1,13 +1,14 @@
 // Copyright (c) ZeroC, Inc.
 
 use crate::ast::Ast;
-use crate::diagnostics::{Diagnostic, DiagnosticLevel, DiagnosticReporter};
+use crate::diagnostics::{Diagnostic, DiagnosticLevel, DiagnosticReporter, Error, Lint};
 use crate::slice_file::{SliceFile, Span};
 use crate::slice_options::{DiagnosticFormat, SliceOptions};
 use console::Term;
 use serde::ser::SerializeStruct;
 use serde::Serializer;
-use std::collections::HashMap;
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
 use std::io::{Result, Write};
 
 #[derive(Debug)]
@@ -15,6 +16,16 @@ pub struct CompilationState {
     pub ast: Ast,
     pub diagnostic_reporter: DiagnosticReporter,
     pub files: HashMap<String, SliceFile>,
+    /// If true, files can only use definitions from themselves, and from the files and definitions they import.
+    pub strict_imports: bool,
+    /// The parser scoped identifiers of every definition that's referenced by a type reference or a doc comment link.
+    /// This is populated during patching, and references that a definition makes to itself aren't included.
+    pub referenced_definitions: HashSet<String>,
+    /// The imports that are used by a type reference or a doc comment link, stored as `(file path, import index)` pairs.
+    /// This is populated during patching.
+    pub used_imports: HashSet<(String, usize)>,
+    /// The number of files that were parsed from their source text. Files loaded from the parse cache aren't counted.
+    pub parsed_file_count: usize,
 }
 
 impl CompilationState {
@@ -23,6 +34,10 @@ impl CompilationState {
             ast: Ast::create(),
             diagnostic_reporter: DiagnosticReporter::new(options),
             files: HashMap::new(),
+            strict_imports: options.strict_imports,
+            referenced_definitions: HashSet::new(),
+            used_imports: HashSet::new(),
+            parsed_file_count: 0,
         }
     }
 
@@ -72,6 +87,7 @@ impl CompilationState {
         match self.diagnostic_reporter.diagnostic_format {
             DiagnosticFormat::Human => self.emit_diagnostics_in_human(output),
             DiagnosticFormat::Json => self.emit_diagnostics_in_json(output),
+            DiagnosticFormat::Sarif => self.emit_diagnostics_in_sarif(output),
         }
         .expect("failed to print diagnostics");
 
@@ -88,11 +104,12 @@ impl CompilationState {
             };
 
             let mut serializer = serde_json::Serializer::new(&mut *output);
-            let mut state = serializer.serialize_struct("Diagnostic", 5)?;
+            let mut state = serializer.serialize_struct("Diagnostic", 6)?;
             state.serialize_field("message", &diagnostic.message())?;
             state.serialize_field("severity", severity)?;
             state.serialize_field("span", &diagnostic.span())?;
             state.serialize_field("notes", diagnostic.notes())?;
+            state.serialize_field("suggestions", diagnostic.suggestions())?;
             state.serialize_field("error_code", diagnostic.code())?;
             state.end()?;
             writeln!(output)?; // Separate each diagnostic by a newline character.
@@ -100,6 +117,104 @@ impl CompilationState {
         Ok(())
     }
 
+    fn emit_diagnostics_in_sarif(self, output: &mut impl Write) -> Result<()> {
+        fn create_region(span: &Span) -> Value {
+            json!({
+                "startLine": span.start.row,
+                "startColumn": span.start.col,
+                "endLine": span.end.row,
+                "endColumn": span.end.col,
+            })
+        }
+
+        fn create_location(span: &Span) -> Value {
+            json!({
+                "physicalLocation": {
+                    "artifactLocation": { "uri": span.file },
+                    "region": create_region(span),
+                },
+            })
+        }
+
+        // Generate a rule for every kind of diagnostic, so results can reference their rules by index.
+        let descriptors = Error::DESCRIPTORS.iter().chain(Lint::DESCRIPTORS);
+        let rules = descriptors.clone().map(|descriptor| {
+            json!({
+                "id": descriptor.code,
+                "name": descriptor.name,
+                "messageStrings": { "default": { "text": descriptor.message_template } },
+            })
+        });
+
+        let mut results = Vec::new();
+        for diagnostic in self.diagnostic_reporter.diagnostics {
+            let level = match diagnostic.level() {
+                DiagnosticLevel::Error => "error",
+                DiagnosticLevel::Warning => "warning",
+                DiagnosticLevel::Allowed => continue,
+            };
+
+            // Notes with spans are stored as related locations. Notes without spans have no location to attach to, so
+            // they're appended to the result's message instead.
+            let (located_notes, unlocated_notes): (Vec<_>, Vec<_>) =
+                diagnostic.notes().iter().partition(|note| note.span.is_some());
+            let mut message = diagnostic.message();
+            for note in unlocated_notes {
+                message = format!("{message}\n{}", note.message);
+            }
+
+            let mut result = json!({
+                "ruleId": diagnostic.code(),
+                "ruleIndex": descriptors.clone().position(|descriptor| descriptor.code == diagnostic.code()),
+                "level": level,
+                "message": { "text": message },
+                "locations": diagnostic.span().map(create_location).into_iter().collect::<Vec<_>>(),
+            });
+
+            let related_locations = located_notes.into_iter().enumerate().map(|(i, note)| {
+                let mut location = create_location(note.span.as_ref().unwrap());
+                location["id"] = json!(i);
+                location["message"] = json!({ "text": note.message });
+                location
+            });
+            result["relatedLocations"] = related_locations.collect();
+
+            // Suggestions are stored as fixes, each of which replaces a single region of a single file.
+            let fixes = diagnostic.suggestions().iter().map(|suggestion| {
+                json!({
+                    "description": { "text": suggestion.message },
+                    "artifactChanges": [{
+                        "artifactLocation": { "uri": suggestion.span.file },
+                        "replacements": [{
+                            "deletedRegion": create_region(&suggestion.span),
+                            "insertedContent": { "text": suggestion.replacement },
+                        }],
+                    }],
+                })
+            });
+            result["fixes"] = fixes.collect();
+
+            results.push(result);
+        }
+
+        let log = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "slicec",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules.collect::<Vec<_>>(),
+                    },
+                },
+                "results": results,
+            }],
+        });
+        serde_json::to_writer(&mut *output, &log)?;
+        writeln!(output)
+    }
+
     fn emit_diagnostics_in_human(self, output: &mut impl Write) -> Result<()> {
         fn append_snippet(message: &mut Vec<String>, span: &Span, files: &HashMap<String, SliceFile>) {
             // Display the file name and line row and column where the error began.
@@ -147,6 +262,22 @@ impl CompilationState {
                     }
                 }
             }
+
+            // If the diagnostic contains suggestions, display them along with a diff of the suggested change.
+            for suggestion in diagnostic.suggestions() {
+                message.push(format!(
+                    "    {} {}: {:}",
+                    console::style("=").blue().bold(),
+                    console::style("help").bold(),
+                    console::style(&suggestion.message).bold(),
+                ));
+
+                let span = &suggestion.span;
+                let file_location = format!("{}:{}:{}", &span.file, span.start.row, span.start.col);
+                message.push(format!(" {} {file_location}", console::style("-->").blue().bold()));
+                let file = self.files.get(&span.file).unwrap();
+                message.push(file.get_suggestion_snippet(span, &suggestion.replacement));
+            }
             writeln!(output, "{}", message.join("\n"))?;
         }
         Ok(())
@@ -2,7 +2,7 @@
 
 use super::{Diagnostic, DiagnosticKind, DiagnosticLevel, Lint};
 use crate::ast::Ast;
-use crate::grammar::{attributes, Attributable, Entity};
+use crate::grammar::{attributes, Attributable, Attribute, Entity};
 use crate::slice_file::SliceFile;
 use crate::slice_options::{DiagnosticFormat, SliceOptions};
 use std::collections::HashMap;
@@ -13,6 +13,12 @@ pub struct DiagnosticReporter {
     pub diagnostics: Vec<Diagnostic>,
     /// Lists all the lints that should be allowed by this reporter.
     pub allowed_lints: Vec<String>,
+    /// Lists all the lints that should be raised to the `Warning` level by this reporter.
+    pub warned_lints: Vec<String>,
+    /// Lists all the lints that should be raised to the `Error` level by this reporter.
+    pub denied_lints: Vec<String>,
+    /// If true, any lints with the `Warning` level are raised to the `Error` level (after all other configuration).
+    pub warnings_as_errors: bool,
     /// Can specify json to serialize errors as JSON or console to output errors to console.
     pub diagnostic_format: DiagnosticFormat,
     /// If true, diagnostic output will not be styled with colors.
@@ -26,6 +32,9 @@ impl DiagnosticReporter {
             diagnostic_format: slice_options.diagnostic_format,
             disable_color: slice_options.disable_color,
             allowed_lints: slice_options.allowed_lints.clone(),
+            warned_lints: slice_options.warned_lints.clone(),
+            denied_lints: slice_options.denied_lints.clone(),
+            warnings_as_errors: slice_options.warnings_as_errors,
         }
     }
 
@@ -37,17 +46,37 @@ impl DiagnosticReporter {
 
     /// Updates the levels of any lints reported with this reporter.
     /// Lint levels can be configured via attributes or command line options, but these aren't applied until this runs.
+    ///
+    /// Each lint starts at its default level, which is then overridden by each of the following, in order:
+    /// 1. The `--warn`, `--deny`, and `--allow` command line options. Options naming a lint take precedence over
+    ///    options for `All` lints. Between options of the same specificity, `--allow` beats `--deny` beats `--warn`.
+    /// 2. `warn`, `deny`, and `allow` attributes on the file the lint was reported in.
+    /// 3. `warn`, `deny`, and `allow` attributes on the lint's scope, and on the entities enclosing that scope.
+    ///
+    /// So attributes always take precedence over command line options, and the innermost scope that configures a lint
+    /// determines its level. Within a single scope, the last attribute that configures the lint is the one that wins.
+    ///
+    /// Finally, if `--warnings-as-errors` was passed, any lints left at the `Warning` level are raised to `Error`.
     pub fn update_diagnostics(&mut self, ast: &Ast, files: &HashMap<String, SliceFile>) -> (usize, usize) {
-        // Helper function that checks whether a lint should be allowed according to the provided identifiers.
-        fn is_lint_allowed_by<'b>(mut identifiers: impl Iterator<Item = &'b String>, lint: &Lint) -> bool {
+        // Helper function that checks whether a lint is matched by any of the provided identifiers.
+        fn is_lint_matched_by<'b>(mut identifiers: impl Iterator<Item = &'b String>, lint: &Lint) -> bool {
             identifiers.any(|identifier| identifier == "All" || identifier == lint.code())
         }
 
-        // Helper function that checks whether a lint is allowed by attributes on the provided entity.
-        fn is_lint_allowed_by_attributes(attributable: &(impl Attributable + ?Sized), lint: &Lint) -> bool {
-            let attributes = attributable.all_attributes().concat().into_iter();
-            let mut allowed = attributes.filter_map(|a| a.downcast::<attributes::Allow>());
-            allowed.any(|allow| is_lint_allowed_by(allow.allowed_lints.iter(), lint))
+        // Helper function that returns the level that the provided attributes configure a lint to, if any.
+        // Attributes are checked from last to first, so the last attribute configuring the lint takes precedence.
+        fn get_level_from_attributes(attributes: &[&Attribute], lint: &Lint) -> Option<DiagnosticLevel> {
+            attributes.iter().rev().find_map(|attribute| {
+                if let Some(allow) = attribute.downcast::<attributes::Allow>() {
+                    is_lint_matched_by(allow.allowed_lints.iter(), lint).then_some(DiagnosticLevel::Allowed)
+                } else if let Some(warn) = attribute.downcast::<attributes::Warn>() {
+                    is_lint_matched_by(warn.warned_lints.iter(), lint).then_some(DiagnosticLevel::Warning)
+                } else if let Some(deny) = attribute.downcast::<attributes::Deny>() {
+                    is_lint_matched_by(deny.denied_lints.iter(), lint).then_some(DiagnosticLevel::Error)
+                } else {
+                    None
+                }
+            })
         }
 
         // Store a running count of the total number of warnings and errors.
@@ -56,27 +85,46 @@ impl DiagnosticReporter {
         for diagnostic in &mut self.diagnostics {
             // If this diagnostic is a lint, update its diagnostic level. Errors always have a level of `Error`.
             if let DiagnosticKind::Lint(lint) = &diagnostic.kind {
-                // Check if the lint is allowed by an `--allow` flag passed on the command line.
-                if is_lint_allowed_by(self.allowed_lints.iter(), lint) {
-                    diagnostic.level = DiagnosticLevel::Allowed;
+                // Check if the lint is configured by any flags passed on the command line.
+                // Flags for `All` lints are applied first, so that flags naming this specific lint can override them.
+                let command_line_options = [
+                    (&self.warned_lints, DiagnosticLevel::Warning),
+                    (&self.denied_lints, DiagnosticLevel::Error),
+                    (&self.allowed_lints, DiagnosticLevel::Allowed),
+                ];
+                for is_specific in [false, true] {
+                    for (lints, level) in &command_line_options {
+                        let expected = if is_specific { lint.code() } else { "All" };
+                        if lints.iter().any(|identifier| identifier == expected) {
+                            diagnostic.level = *level;
+                        }
+                    }
                 }
 
-                // If the diagnostic has a span, check if it's affected by an `allow` attribute on its file.
+                // If the diagnostic has a span, check if it's affected by any attributes on its file.
                 if let Some(span) = diagnostic.span() {
                     let file = files.get(&span.file).expect("slice file didn't exist");
-                    if is_lint_allowed_by_attributes(file, lint) {
-                        diagnostic.level = DiagnosticLevel::Allowed;
+                    if let Some(level) = get_level_from_attributes(&file.attributes(), lint) {
+                        diagnostic.level = level;
                     }
                 }
 
-                // If the diagnostic has a scope, check if it's affected by an `allow` attribute in that scope.
+                // If the diagnostic has a scope, check if it's affected by any attributes in that scope.
+                // Scopes are checked from the innermost outwards, and the first scope that configures the lint wins.
                 if let Some(scope) = diagnostic.scope() {
                     if let Ok(entity) = ast.find_element::<dyn Entity>(scope) {
-                        if is_lint_allowed_by_attributes(entity, lint) {
-                            diagnostic.level = DiagnosticLevel::Allowed;
+                        let all_attributes = entity.all_attributes();
+                        let mut levels = all_attributes.iter().map(|a| get_level_from_attributes(a, lint));
+                        if let Some(level) = levels.find_map(|level| level) {
+                            diagnostic.level = level;
                         }
                     }
                 }
+
+                // Check if warnings should be treated as errors.
+                if self.warnings_as_errors && diagnostic.level == DiagnosticLevel::Warning {
+                    diagnostic.level = DiagnosticLevel::Error;
+                }
             }
 
             // Update the total number of errors/warnings accordingly.
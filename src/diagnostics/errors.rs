@@ -109,6 +109,20 @@ pub enum Error {
         kind: Option<String>,
     },
 
+    /// An enumerator in a flags enum has a value that isn't zero or a power of two.
+    FlagsEnumeratorValueNotPowerOfTwo {
+        /// The identifier of the enumerator.
+        enumerator_identifier: String,
+        /// The value of the enumerator.
+        value: i128,
+    },
+
+    /// An enumerator has associated fields, but its enum has an underlying type.
+    EnumeratorCannotContainFields {
+        /// The identifier of the enumerator.
+        enumerator_identifier: String,
+    },
+
     // ----------------  Exception Errors ---------------- //
     /// Exceptions cannot be used as a data type in Slice1 mode.
     ExceptionAsDataType,
@@ -133,6 +147,15 @@ pub enum Error {
     /// Compact structs cannot contain tagged fields.
     CompactStructCannotContainTaggedFields,
 
+    // ----------------  Field Errors ---------------- //
+    /// A field was given a default value, but its type doesn't support default values.
+    DefaultValueNotSupported {
+        /// The identifier of the field.
+        identifier: String,
+        /// The type of the field.
+        kind: String,
+    },
+
     // ----------------  Tag Errors ---------------- //
     /// A duplicate tag value was found.
     CannotHaveDuplicateTag {
@@ -222,6 +245,36 @@ pub enum Error {
         identifier: String,
     },
 
+    /// A literal was used as the value of a type it isn't compatible with.
+    LiteralTypeMismatch {
+        /// The type that the literal was being used as.
+        expected: String,
+        /// The kind of literal that was found.
+        actual: String,
+    },
+
+    /// An integer literal was outside the bounds of the numeric type it was being used as.
+    LiteralValueOutOfBounds {
+        /// The value of the out of bounds literal.
+        value: i128,
+        /// The numeric type that the literal was being used as.
+        kind: String,
+        /// The minimum value of the numeric type.
+        min: i128,
+        /// The maximum value of the numeric type.
+        max: i128,
+    },
+
+    /// A float literal was outside the bounds of the floating point type it was being used as.
+    FloatLiteralOutOfBounds {
+        /// The value of the out of bounds literal.
+        value: f64,
+        /// The floating point type that the literal was being used as.
+        kind: String,
+        /// The largest finite magnitude of the floating point type.
+        max: f64,
+    },
+
     // ----------------  Attribute Errors ---------------- //
     /// An invalid argument was provided to an attribute directive.
     ArgumentNotSupported {
@@ -255,6 +308,58 @@ pub enum Error {
     // ----------------  Type Alias Errors ---------------- //
     /// A type alias had an optional underlying type.
     TypeAliasOfOptional,
+
+    // ----------------  Constant Errors ---------------- //
+    /// A constant was declared with a type that constants cannot have.
+    ConstantTypeNotSupported {
+        /// The identifier of the constant.
+        identifier: String,
+        /// The type that the constant was declared with.
+        kind: String,
+    },
+
+    // ----------------  Import Errors ---------------- //
+    /// An import didn't refer to any Slice file or definition known to the compiler.
+    UnresolvedImport {
+        /// The path or identifier that was imported.
+        import: String,
+    },
+
+    /// A definition from another file was used without being imported, while strict imports were enabled.
+    DefinitionNotImported {
+        /// The identifier of the definition that was used.
+        identifier: String,
+        /// The path of the file the definition is defined in.
+        file: String,
+    },
+
+    // ----------------  Preprocessor Errors ---------------- //
+    /// An `#error` directive was encountered in an active branch of the preprocessor.
+    ErrorDirective {
+        /// The message supplied by the directive.
+        message: String,
+    },
+
+    /// An operand in a preprocessor expression had the wrong type.
+    /// Ex: `#if 5 && Foo`, `5` is an integer, but `&&` requires booleans.
+    PreprocessorTypeMismatch {
+        /// The type that was expected.
+        expected: String,
+        /// The type of the operand.
+        actual: String,
+    },
+
+    /// A preprocessor symbol was used as an integer, but it wasn't defined with a value.
+    SymbolHasNoValue {
+        /// The identifier of the symbol.
+        symbol: String,
+    },
+
+    /// A preprocessor symbol passed on the command line was defined with an invalid value.
+    InvalidSymbolDefinition {
+        /// The definition that was passed, ex: `Foo=bar`.
+        definition: String,
+    },
 }
 
 implement_diagnostic_functions!(
@@ -262,6 +367,7 @@ implement_diagnostic_functions!(
     (
         "E001",
         IO,
+        "unable to {0} '{1}': {2}",
         format!("unable to {action} '{path}': {}", io_error_message(error)),
         action,
         path,
@@ -270,12 +376,14 @@ implement_diagnostic_functions!(
     (
         "E002",
         Syntax,
+        "invalid syntax: {0}",
         format!("invalid syntax: {message}"),
         message
     ),
     (
         "E004",
         ArgumentNotSupported,
+        "'{0}' is not a legal argument for the '{1}' attribute",
         format!("'{argument}' is not a legal argument for the '{directive}' attribute"),
         argument,
         directive
@@ -283,40 +391,47 @@ implement_diagnostic_functions!(
     (
         "E005",
         KeyMustBeNonOptional,
+        "optional types are not valid dictionary key types",
         "optional types are not valid dictionary key types"
     ),
     (
         "E006",
         StructKeyMustBeCompact,
+        "structs must be compact to be used as a dictionary key type",
         "structs must be compact to be used as a dictionary key type"
     ),
     (
         "E007",
         KeyTypeNotSupported,
+        "invalid dictionary key type: {0}",
         format!("invalid dictionary key type: {kind}"),
         kind
     ),
     (
         "E008",
         StructKeyContainsDisallowedType,
+        "struct '{0}' contains fields that are not a valid dictionary key types",
         format!("struct '{struct_identifier}' contains fields that are not a valid dictionary key types"),
         struct_identifier
     ),
     (
         "E009",
         CannotUseOptionalUnderlyingType,
+        "invalid enum '{0}': enums cannot have optional underlying types",
         format!("invalid enum '{enum_identifier}': enums cannot have optional underlying types"),
         enum_identifier
     ),
     (
         "E010",
         MustContainEnumerators,
+        "invalid enum '{0}': enums must contain at least one enumerator",
         format!("invalid enum '{enum_identifier}': enums must contain at least one enumerator"),
         enum_identifier
     ),
     (
         "E011",
         EnumUnderlyingTypeNotSupported,
+        "invalid enum '{0}': underlying type '{1}' is not supported",
         {
             if let Some(kind) = kind {
                 format!("invalid enum '{enum_identifier}': underlying type '{kind}' is not supported", )
@@ -330,58 +445,68 @@ implement_diagnostic_functions!(
     (
         "E012",
         Redefinition,
+        "redefinition of '{0}'",
         format!("redefinition of '{identifier}'"),
         identifier
     ),
     (
         "E013",
         Shadows,
+        "'{0}' shadows another symbol",
         format!("'{identifier}' shadows another symbol"),
         identifier
     ),
     (
         "E014",
         CannotHaveDuplicateTag,
+        "invalid tag on member '{0}': tags must be unique",
         format!("invalid tag on member '{identifier}': tags must be unique"),
         identifier
     ),
     (
         "E016",
         StreamedMembersMustBeLast,
+        "invalid parameter '{0}': only the last parameter in an operation can use the stream modifier",
         format!("invalid parameter '{parameter_identifier}': only the last parameter in an operation can use the stream modifier"),
         parameter_identifier
     ),
     (
         "E017",
         ReturnTuplesMustContainAtLeastTwoElements,
+        "return tuples must have at least 2 elements",
         "return tuples must have at least 2 elements"
     ),
     (
         "E018",
         CompactStructCannotContainTaggedFields,
+        "tagged fields are not supported in compact structs",
         "tagged fields are not supported in compact structs\nconsider removing the tag, or making the struct non-compact"
     ),
     (
         "E019",
         TaggedMemberMustBeOptional,
+        "invalid tag on member '{0}': tagged members must be optional",
         format!("invalid tag on member '{identifier}': tagged members must be optional"),
         identifier
     ),
     (
         "E020",
         CannotTagClass,
+        "invalid tag on member '{0}': tagged members cannot be classes",
         format!("invalid tag on member '{identifier}': tagged members cannot be classes"),
         identifier
     ),
     (
         "E021",
         CannotTagContainingClass,
+        "invalid tag on member '{0}': tagged members cannot contain classes",
         format!("invalid tag on member '{identifier}': tagged members cannot contain classes"),
         identifier
     ),
     (
         "E022",
         TypeMismatch,
+        "type mismatch: expected '{0}' but found '{1}'",
         format!(
             "type mismatch: expected {} '{expected}' but found {} '{actual}'{}",
             indefinite_article(expected),
@@ -399,17 +524,20 @@ implement_diagnostic_functions!(
     (
         "E024",
         CompactStructCannotBeEmpty,
+        "compact structs must be non-empty",
         "compact structs must be non-empty"
     ),
     (
         "E025",
         SelfReferentialTypeAliasNeedsConcreteType,
+        "self-referential type alias '{0}' has no concrete type",
         format!("self-referential type alias '{identifier}' has no concrete type"),
         identifier
     ),
     (
         "E026",
         EnumeratorValueOutOfBounds,
+        "invalid enumerator '{0}': enumerator value '{1}' is out of bounds. The value must be between '{2}..{3}', inclusive",
         format!(
             "invalid enumerator '{enumerator_identifier}': enumerator value '{value}' is out of bounds. The value must be between '{min}..{max}', inclusive",
         ),
@@ -418,23 +546,27 @@ implement_diagnostic_functions!(
     (
         "E027",
         TagValueOutOfBounds,
+        "tag values must be within the range 0 <= value <= 2147483647",
         "tag values must be within the range 0 <= value <= 2147483647"
     ),
     (
         "E028",
         DuplicateEnumeratorValue,
+        "enumerator values must be unique; the value '{0}' is already in use",
         format!("enumerator values must be unique; the value '{enumerator_value}' is already in use"),
         enumerator_value
     ),
     (
         "E029",
         NotSupportedInCompilationMode,
+        "{0} '{1}' cannot be used in {2} mode",
         format!("{kind} '{identifier}' cannot be used in {mode} mode"),
         kind, identifier, mode
     ),
     (
         "E030",
         UnsupportedType,
+        "the type '{0}' cannot be used in {1} mode",
         format!("the type '{kind}' cannot be used in {compilation_mode} mode"),
         kind,
         compilation_mode
@@ -442,102 +574,217 @@ implement_diagnostic_functions!(
     (
         "E031",
         ExceptionAsDataType,
+        "exceptions cannot be used as a data type in Slice1 mode",
         "exceptions cannot be used as a data type in Slice1 mode"
     ),
     (
         "E032",
         OptionalsNotSupported,
+        "optionals of type '{0}' cannot be used in Slice1 mode",
         format!("optionals of type '{kind}' cannot be used in Slice1 mode"),
         kind
     ),
     (
         "E033",
         StreamedParametersNotSupported,
+        "streamed parameters cannot be used in Slice1 mode",
         "streamed parameters cannot be used in Slice1 mode"
     ),
     (
         "E034",
         UnexpectedAttribute,
+        "unexpected attribute '{0}'",
         format!("unexpected attribute '{attribute}'"),
         attribute
     ),
     (
         "E035",
         MissingRequiredArgument,
+        "missing required argument '{0}'",
         format!("missing required argument '{argument}'"),
         argument
     ),
     (
         "E036",
         TooManyArguments,
+        "too many arguments, expected '{0}'",
         format!("too many arguments, expected '{expected}'"),
         expected
     ),
     (
         "E037",
         MissingRequiredAttribute,
+        "missing required attribute '{0}'",
         format!("missing required attribute '{attribute}'"),
         attribute
     ),
     (
         "E038",
         MultipleStreamedMembers,
+        "cannot have multiple streamed members",
         "cannot have multiple streamed members"
     ),
     (
         "E039",
         CompactIdOutOfBounds,
+        "compact IDs must be within the range 0 <= ID <= 2147483647",
         "compact IDs must be within the range 0 <= ID <= 2147483647"
     ),
     (
         "E040",
         IntegerLiteralOverflows,
+        "integer literal is outside the parsable range of -2^127 <= i <= 2^127 - 1",
         "integer literal is outside the parsable range of -2^127 <= i <= 2^127 - 1"
     ),
     (
         "E041",
         InvalidIntegerLiteral,
+        "integer literal contains illegal characters for base-{0}",
         format!("integer literal contains illegal characters for base-{base}"),
         base
     ),
     (
         "E042",
         InvalidCompilationMode,
+        "'{0}' is not a valid Slice compilation mode",
         format!("'{mode}' is not a valid Slice compilation mode"),
         mode
     ),
     (
         "E043",
         MultipleCompilationModes,
+        "the compilation mode can only be specified once per file",
         "the compilation mode can only be specified once per file"
     ),
     (
         "E045",
         AnyExceptionNotSupported,
+        "operations that throw AnyException cannot be used in Slice1 mode",
         "operations that throw AnyException cannot be used in Slice1 mode"
     ),
     (
         "E047",
         InfiniteSizeCycle,
+        "self-referential type {0} has infinite size",
         format!("self-referential type {type_id} has infinite size.\n{cycle}"),
         type_id, cycle
     ),
     (
         "E049",
         DoesNotExist,
+        "no element with identifier '{0}' exists",
         format!("no element with identifier '{identifier}' exists"),
         identifier
     ),
     (
         "E050",
         AttributeIsNotRepeatable,
+        "duplicate attribute '{0}'",
         format!("duplicate attribute '{attribute}'"),
         attribute
     ),
     (
         "E051",
         TypeAliasOfOptional,
+        "optional types cannot be aliased",
         "optional types cannot be aliased"
+    ),
+    (
+        "E052",
+        ConstantTypeNotSupported,
+        "invalid constant '{0}': constants cannot be of type '{1}'",
+        format!("invalid constant '{identifier}': constants cannot be of type '{kind}'"),
+        identifier,
+        kind
+    ),
+    (
+        "E053",
+        LiteralTypeMismatch,
+        "type mismatch: expected a value of type '{0}' but found {1}",
+        format!("type mismatch: expected a value of type '{expected}' but found {} {actual}", indefinite_article(actual)),
+        expected,
+        actual
+    ),
+    (
+        "E054",
+        LiteralValueOutOfBounds,
+        "value '{0}' is out of bounds for type '{1}'. The value must be between '{2}..{3}', inclusive",
+        format!("value '{value}' is out of bounds for type '{kind}'. The value must be between '{min}..{max}', inclusive"),
+        value, kind, min, max
+    ),
+    (
+        "E055",
+        DefaultValueNotSupported,
+        "invalid default value on field '{0}': fields of type '{1}' cannot have default values",
+        format!("invalid default value on field '{identifier}': fields of type '{kind}' cannot have default values"),
+        identifier,
+        kind
+    ),
+    (
+        "E056",
+        EnumeratorCannotContainFields,
+        "invalid enumerator '{0}': enumerators of enums with underlying types cannot have fields",
+        format!("invalid enumerator '{enumerator_identifier}': enumerators of enums with underlying types cannot have fields"),
+        enumerator_identifier
+    ),
+    (
+        "E057",
+        FlagsEnumeratorValueNotPowerOfTwo,
+        "invalid enumerator '{0}': enumerators of flags enums must have values that are zero or a power of two, but '{1}' is not",
+        format!("invalid enumerator '{enumerator_identifier}': enumerators of flags enums must have values that are zero or a power of two, but '{value}' is not"),
+        enumerator_identifier,
+        value
+    ),
+    (
+        "E058",
+        UnresolvedImport,
+        "unresolved import '{0}'",
+        format!("unresolved import '{import}'"),
+        import
+    ),
+    (
+        "E059",
+        DefinitionNotImported,
+        "'{0}' is defined in '{1}', which isn't imported by this file",
+        format!("'{identifier}' is defined in '{file}', which isn't imported by this file"),
+        identifier,
+        file
+    ),
+    (
+        "E060",
+        ErrorDirective,
+        "#error: {0}",
+        format!("#error: {message}"),
+        message
+    ),
+    (
+        "E061",
+        PreprocessorTypeMismatch,
+        "type mismatch: expected {0} but found {1}",
+        format!("type mismatch: expected {} {expected} but found {} {actual}", indefinite_article(expected), indefinite_article(actual)),
+        expected,
+        actual
+    ),
+    (
+        "E062",
+        SymbolHasNoValue,
+        "preprocessor symbol '{0}' cannot be used as an integer because it doesn't have a value",
+        format!("preprocessor symbol '{symbol}' cannot be used as an integer because it doesn't have a value"),
+        symbol
+    ),
+    (
+        "E063",
+        InvalidSymbolDefinition,
+        "invalid preprocessor symbol definition '{0}': symbol values must be integers",
+        format!("invalid preprocessor symbol definition '{definition}': symbol values must be integers"),
+        definition
+    ),
+    (
+        "E064",
+        FloatLiteralOutOfBounds,
+        "value '{0}' is out of bounds for type '{1}'. The value must be between '-{2}..{2}', inclusive",
+        format!("value '{value:e}' is out of bounds for type '{kind}'. The value must be between '-{max:e}..{max:e}', inclusive"),
+        value, kind, max
     )
 );
 
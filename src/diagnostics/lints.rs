@@ -34,6 +34,87 @@ pub enum Lint {
     /// - The tag itself is incorrect. Ex: using `@throws` on an element that can't or doesn't throw an exception.
     /// - The tag describes something incorrect. Ex: specifying `@param foo` when no parameter named "foo" exists.
     IncorrectDocComment { message: String },
+
+    /// An import was never used to resolve a type reference in the file that declared it.
+    UnusedImport {
+        /// The path or identifier that was imported.
+        import: String,
+    },
+
+    /// A `#warning` directive was encountered in an active branch of the preprocessor.
+    WarningDirective {
+        /// The message supplied by the directive.
+        message: String,
+    },
+
+    /// A Slice element doesn't have a doc comment.
+    /// This lint is allowed by default, and must be explicitly enabled (ex: `slicec -W MissingDocComment`).
+    MissingDocComment {
+        /// The kind of the element.
+        kind: String,
+
+        /// The identifier of the element.
+        identifier: String,
+    },
+
+    /// A parameter isn't documented, either by a `@param` tag on its operation, or by its own doc comment.
+    /// This lint is allowed by default.
+    MissingParamDoc {
+        /// The identifier of the parameter.
+        identifier: String,
+
+        /// The identifier of the operation the parameter belongs to.
+        operation: String,
+    },
+
+    /// A non-void operation doesn't document what it returns, either with a `@returns` tag, or with doc comments on
+    /// its return members. This lint is allowed by default.
+    MissingReturnsDoc {
+        /// The identifier of the operation.
+        operation: String,
+    },
+
+    /// An operation throws an exception, but its doc comment doesn't have a `@throws` tag for that exception.
+    /// This lint is allowed by default.
+    UndocumentedThrows {
+        /// The identifier of the operation.
+        operation: String,
+
+        /// The identifier of the exception the operation throws.
+        exception: String,
+    },
+
+    /// An identifier doesn't follow the Slice naming conventions for its kind of element:
+    /// modules, types, and enumerators should be PascalCase; operations, parameters, and fields should be camelCase.
+    /// This lint is allowed by default.
+    NamingConvention {
+        /// The kind of the element.
+        kind: String,
+
+        /// The identifier of the element.
+        identifier: String,
+
+        /// The case that the identifier should be written in.
+        case: String,
+    },
+
+    /// A type defined in a source file is never referenced, either by a type reference or by a doc comment link.
+    /// Interfaces are never reported, since they're the entry points of a Slice API. This lint is allowed by default.
+    Unused {
+        /// The kind of the element.
+        kind: String,
+
+        /// The identifier of the element.
+        identifier: String,
+    },
+
+    /// A change between two versions of a Slice API that breaks wire-compatibility between them.
+    /// This lint is only reported by the compatibility checker, and is an error by default.
+    BreakingChange { message: String },
+
+    /// A change between two versions of a Slice API that preserves wire-compatibility between them.
+    /// This lint is only reported by the compatibility checker, and is allowed by default.
+    CompatibleChange { message: String },
 }
 
 impl Lint {
@@ -45,6 +126,16 @@ impl Lint {
             Self::MalformedDocComment { .. } => DiagnosticLevel::Warning,
             Self::BrokenDocLink { .. } => DiagnosticLevel::Warning,
             Self::IncorrectDocComment { .. } => DiagnosticLevel::Warning,
+            Self::UnusedImport { .. } => DiagnosticLevel::Warning,
+            Self::WarningDirective { .. } => DiagnosticLevel::Warning,
+            Self::MissingDocComment { .. } => DiagnosticLevel::Allowed,
+            Self::MissingParamDoc { .. } => DiagnosticLevel::Allowed,
+            Self::MissingReturnsDoc { .. } => DiagnosticLevel::Allowed,
+            Self::UndocumentedThrows { .. } => DiagnosticLevel::Allowed,
+            Self::NamingConvention { .. } => DiagnosticLevel::Allowed,
+            Self::Unused { .. } => DiagnosticLevel::Allowed,
+            Self::BreakingChange { .. } => DiagnosticLevel::Error,
+            Self::CompatibleChange { .. } => DiagnosticLevel::Allowed,
         }
     }
 }
@@ -53,11 +144,13 @@ implement_diagnostic_functions!(
     Lint,
     (
         DuplicateFile,
+        "slice file was provided more than once: '{0}'",
         format!("slice file was provided more than once: '{path}'"),
         path
     ),
     (
         Deprecated,
+        "'{0}' is deprecated: {1}",
         if let Some(reason) = reason {
             format!("'{identifier}' is deprecated: {reason}")
         } else {
@@ -66,7 +159,63 @@ implement_diagnostic_functions!(
         identifier,
         reason
     ),
-    (MalformedDocComment, message, message),
-    (BrokenDocLink, message, message),
-    (IncorrectDocComment, message, message)
+    (MalformedDocComment, "{0}", message, message),
+    (BrokenDocLink, "{0}", message, message),
+    (IncorrectDocComment, "{0}", message, message),
+    (
+        UnusedImport,
+        "unused import '{0}'",
+        format!("unused import '{import}'"),
+        import
+    ),
+    (
+        WarningDirective,
+        "#warning: {0}",
+        format!("#warning: {message}"),
+        message
+    ),
+    (
+        MissingDocComment,
+        "{0} '{1}' is missing a doc comment",
+        format!("{kind} '{identifier}' is missing a doc comment"),
+        kind,
+        identifier
+    ),
+    (
+        MissingParamDoc,
+        "parameter '{0}' of operation '{1}' is not documented",
+        format!("parameter '{identifier}' of operation '{operation}' is not documented"),
+        identifier,
+        operation
+    ),
+    (
+        MissingReturnsDoc,
+        "operation '{0}' returns a value, but its doc comment doesn't document it",
+        format!("operation '{operation}' returns a value, but its doc comment doesn't document it"),
+        operation
+    ),
+    (
+        UndocumentedThrows,
+        "operation '{0}' throws '{1}', but its doc comment has no '@throws' tag for it",
+        format!("operation '{operation}' throws '{exception}', but its doc comment has no '@throws' tag for it"),
+        operation,
+        exception
+    ),
+    (
+        NamingConvention,
+        "{0} '{1}' should be written in {2}",
+        format!("{kind} '{identifier}' should be written in {case}"),
+        kind,
+        identifier,
+        case
+    ),
+    (
+        Unused,
+        "{0} '{1}' is never used",
+        format!("{kind} '{identifier}' is never used"),
+        kind,
+        identifier
+    ),
+    (BreakingChange, "{0}", message, message),
+    (CompatibleChange, "{0}", message, message)
 );
@@ -20,6 +20,7 @@ pub struct Diagnostic {
     span: Option<Span>,
     scope: Option<String>,
     notes: Vec<Note>,
+    suggestions: Vec<Suggestion>,
 }
 
 impl Diagnostic {
@@ -36,6 +37,7 @@ impl Diagnostic {
             span: None,
             scope: None,
             notes: Vec::new(),
+            suggestions: Vec::new(),
         }
     }
 
@@ -76,6 +78,11 @@ impl Diagnostic {
         &self.notes
     }
 
+    /// Returns any [Suggestions](Suggestion) for fixing this diagnostic.
+    pub fn suggestions(&self) -> &[Suggestion] {
+        &self.suggestions
+    }
+
     pub fn set_span(mut self, span: &Span) -> Self {
         self.span = Some(span.to_owned());
         self
@@ -99,6 +106,17 @@ impl Diagnostic {
         self
     }
 
+    /// Suggests a fix for this diagnostic: replacing the text covered by `span` with `replacement`.
+    /// Suggestions should only be added when the fix is unambiguous, since tools may apply them automatically.
+    pub fn add_suggestion(mut self, message: impl Into<String>, span: &Span, replacement: impl Into<String>) -> Self {
+        self.suggestions.push(Suggestion {
+            span: span.to_owned(),
+            replacement: replacement.into(),
+            message: message.into(),
+        });
+        self
+    }
+
     pub fn report(self, diagnostic_reporter: &mut DiagnosticReporter) {
         diagnostic_reporter.report(self);
     }
@@ -129,6 +147,39 @@ pub struct Note {
     pub span: Option<Span>,
 }
 
+/// A machine-applicable fix for a diagnostic, which replaces the text covered by `span` with `replacement`.
+/// Insertions are represented by empty spans, and deletions by empty replacements.
+#[derive(Serialize, Debug, Clone)]
+pub struct Suggestion {
+    pub span: Span,
+    pub replacement: String,
+    pub message: String,
+}
+
+/// Describes a kind of diagnostic: its code, the name of its variant, and a template of its message.
+/// Every [Error] and [Lint] has a descriptor, see [`Error::DESCRIPTORS`] and [`Lint::DESCRIPTORS`].
+#[derive(Clone, Copy, Debug)]
+pub struct DiagnosticDescriptor {
+    pub code: &'static str,
+    pub name: &'static str,
+
+    /// A template of the diagnostic's message, where values that are filled in when the diagnostic is reported are
+    /// shown as SARIF-style placeholders: `{0}` is the diagnostic's first field, `{1}` its second, and so on.
+    /// Ex: `"unused import '{0}'"`.
+    pub message_template: &'static str,
+}
+
+impl DiagnosticDescriptor {
+    #[doc(hidden)] // This is only public so it can be called from `implement_diagnostic_functions`.
+    pub const fn new(code: &'static str, name: &'static str, message_template: &'static str) -> Self {
+        DiagnosticDescriptor {
+            code,
+            name,
+            message_template,
+        }
+    }
+}
+
 /// Diagnostic levels describe the severity of a diagnostic, and how the compiler should react to their emission.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum DiagnosticLevel {
@@ -146,15 +197,21 @@ pub enum DiagnosticLevel {
 /// A macro that implements the `code` and `message` functions for [Lint] and [Error] enums.
 #[macro_export]
 macro_rules! implement_diagnostic_functions {
-    (Lint, $(($kind:ident, $message:expr $(, $variant:ident)* )),*) => {
+    (Lint, $(($kind:ident, $template:literal, $message:expr $(, $variant:ident)* )),*) => {
         impl Lint {
             // TODO maybe we should move this somewhere other than `Lint`? Like in `Attribute` maybe?
-            /// This array contains all the valid arguments for the 'allow' attribute.
-            pub const ALLOWABLE_LINT_IDENTIFIERS: [&str; 6] = [
+            /// This array contains all the valid arguments for the 'allow', 'warn', and 'deny' attributes,
+            /// and the `--allow`, `--warn`, and `--deny` flags.
+            pub const ALLOWABLE_LINT_IDENTIFIERS: [&str; 16] = [
                 "All",
                 $(stringify!($kind)),*
             ];
 
+            /// Descriptors for every kind of lint, in the order they're declared. Lints use their names as codes.
+            pub const DESCRIPTORS: &'static [$crate::diagnostics::DiagnosticDescriptor] = &[
+                $($crate::diagnostics::DiagnosticDescriptor::new(stringify!($kind), stringify!($kind), $template)),*
+            ];
+
             pub fn code(&self) -> &str {
                 match self {
                     $(
@@ -173,8 +230,13 @@ macro_rules! implement_diagnostic_functions {
         }
     };
 
-    (Error, $(($code:literal, $kind:ident, $message:expr $(, $variant:ident)* )),*) => {
+    (Error, $(($code:literal, $kind:ident, $template:literal, $message:expr $(, $variant:ident)* )),*) => {
         impl Error {
+            /// Descriptors for every kind of error, in the order they're declared.
+            pub const DESCRIPTORS: &'static [$crate::diagnostics::DiagnosticDescriptor] = &[
+                $($crate::diagnostics::DiagnosticDescriptor::new($code, stringify!($kind), $template)),*
+            ];
+
             pub fn code(&self) -> &str {
                 match self {
                     $(
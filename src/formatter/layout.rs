@@ -0,0 +1,794 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::MAX_WIDTH;
+use crate::diagnostics::{Diagnostic, Error};
+use crate::parsers::SliceTokenKind as TokenKind;
+use crate::slice_file::{Location, Span};
+
+const INDENT: &str = "    ";
+
+/// An element of a Slice file, as seen by the formatter. This is either a token (including comments), or a
+/// preprocessor directive. Directives are stored with the row they're on, since they always span an entire line.
+#[derive(Debug)]
+pub enum Element<'a> {
+    Token(Location, TokenKind<'a>, Location),
+    Directive(usize, &'a str),
+}
+
+impl Element<'_> {
+    /// Returns true if this element is a non-doc comment or a preprocessor directive. These elements aren't part of
+    /// the Slice grammar, and can appear between any two tokens.
+    fn is_trivia(&self) -> bool {
+        matches!(
+            self,
+            Element::Directive(..) | Element::Token(_, TokenKind::LineComment(_) | TokenKind::BlockComment(_), _),
+        )
+    }
+}
+
+/// Describes which lists in an operation's signature should be wrapped onto separate lines.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Wrapping {
+    None,
+    Parameters,
+    All,
+}
+
+/// The state of the layout, which is saved and restored when trying different ways of wrapping a signature.
+#[derive(Clone, Debug, Default)]
+struct State {
+    /// The index of the next element to consume.
+    position: usize,
+
+    /// The text of the line that is currently being written, without indentation.
+    line: String,
+
+    /// How many levels the current line is indented by.
+    indent: usize,
+
+    /// Whether the current line continues the previous line, in which case it is indented by an additional level.
+    is_continuation: bool,
+
+    /// The indentation level of the current line, fixed when the first text is written to it (since blocks increase
+    /// the indentation level before the line which opens them is ended).
+    line_indent: usize,
+
+    /// The row that the most recently consumed element ended on. Used to check whether comments trail a token, and
+    /// whether there was a blank line between 2 elements.
+    last_row: usize,
+
+    /// Whether a space should be written before the next text on this line.
+    pending_space: bool,
+
+    /// Whether the next text must be written on a new line. This is set after writing a trailing line comment.
+    needs_break: bool,
+
+    /// Whether the current line opens a block.
+    opened_block: bool,
+
+    /// Whether the most recently written line opened a block. Blank lines are never written at the start of a block.
+    at_block_start: bool,
+}
+
+struct Checkpoint {
+    state: State,
+    output_length: usize,
+}
+
+/// Lays out the elements of a Slice file in the canonical style.
+///
+/// This is a recursive descent parser, which writes the file's tokens as it parses them. Trivia (comments and
+/// preprocessor directives) are written whenever the layout consumes the token after them, or starts a new line.
+pub struct Layout<'a> {
+    file_name: &'a str,
+    source_lines: Vec<&'a str>,
+    elements: Vec<Element<'a>>,
+    output: Vec<String>,
+    state: State,
+}
+
+#[allow(clippy::result_large_err)]
+impl<'a> Layout<'a> {
+    pub fn new(file_name: &'a str, raw_text: &'a str, elements: Vec<Element<'a>>) -> Self {
+        Layout {
+            file_name,
+            source_lines: raw_text.split('\n').collect(),
+            elements,
+            output: Vec::new(),
+            state: State::default(),
+        }
+    }
+
+    pub fn format_file(mut self) -> Result<String, Diagnostic> {
+        while self.peek().is_some() {
+            self.item()?;
+        }
+        self.newline();
+
+        while self.output.last().is_some_and(String::is_empty) {
+            self.output.pop();
+        }
+        Ok(self.output.iter().map(|line| line.clone() + "\n").collect())
+    }
+
+    /// Formats a module, a definition, or a file-level statement (a file attribute, `mode`, or an `import`).
+    fn item(&mut self) -> Result<(), Diagnostic> {
+        self.prelude()?;
+        match self.peek() {
+            Some(TokenKind::DoubleLeftBracket) => self.attribute(),
+            Some(TokenKind::ModeKeyword) => {
+                self.keyword()?;
+                self.expect_spaced("'='", |k| matches!(k, TokenKind::Equals))?;
+                self.identifier()
+            }
+            Some(TokenKind::ImportKeyword) => {
+                self.keyword()?;
+                match self.peek() {
+                    Some(TokenKind::StringLiteral(_)) => self.token(),
+                    _ => self.scoped_identifier(),
+                }
+            }
+            Some(TokenKind::ModuleKeyword) => {
+                self.keyword()?;
+                self.scoped_identifier()?;
+                match self.peek() {
+                    Some(TokenKind::LeftBrace) => self.body(Self::item),
+                    _ => Ok(()),
+                }
+            }
+            Some(TokenKind::CompactKeyword | TokenKind::StructKeyword) => {
+                if self.peek_matches(|k| matches!(k, TokenKind::CompactKeyword)) {
+                    self.keyword()?;
+                }
+                self.expect("'struct'", |k| matches!(k, TokenKind::StructKeyword))?;
+                self.space();
+                self.identifier()?;
+                self.body(Self::field)
+            }
+            Some(TokenKind::ExceptionKeyword) => {
+                self.keyword()?;
+                self.identifier()?;
+                self.base_types()?;
+                self.body(Self::field)
+            }
+            Some(TokenKind::ClassKeyword) => {
+                self.keyword()?;
+                self.identifier()?;
+                if self.peek_matches(|k| matches!(k, TokenKind::LeftParenthesis)) {
+                    self.token()?;
+                    self.signed_integer()?;
+                    self.expect("')'", |k| matches!(k, TokenKind::RightParenthesis))?;
+                }
+                self.base_types()?;
+                self.body(Self::field)
+            }
+            Some(TokenKind::InterfaceKeyword) => {
+                self.keyword()?;
+                self.identifier()?;
+                self.base_types()?;
+                self.body(Self::operation)
+            }
+            Some(TokenKind::UncheckedKeyword | TokenKind::EnumKeyword) => {
+                if self.peek_matches(|k| matches!(k, TokenKind::UncheckedKeyword)) {
+                    self.keyword()?;
+                }
+                self.expect("'enum'", |k| matches!(k, TokenKind::EnumKeyword))?;
+                self.space();
+                self.identifier()?;
+                self.base_types()?;
+                self.body(Self::enumerator)
+            }
+            Some(TokenKind::CustomKeyword) => {
+                self.keyword()?;
+                self.identifier()
+            }
+            Some(TokenKind::TypeAliasKeyword) => {
+                self.keyword()?;
+                self.identifier()?;
+                self.expect_spaced("'='", |k| matches!(k, TokenKind::Equals))?;
+                self.type_ref()
+            }
+            Some(TokenKind::ConstKeyword) => {
+                self.keyword()?;
+                self.identifier()?;
+                self.expect("':'", |k| matches!(k, TokenKind::Colon))?;
+                self.space();
+                self.type_ref()?;
+                self.expect_spaced("'='", |k| matches!(k, TokenKind::Equals))?;
+                self.literal()
+            }
+            _ => Err(self.unexpected("a definition")),
+        }
+    }
+
+    /// Formats the doc comments and attributes before an element, placing each of them on its own line.
+    fn prelude(&mut self) -> Result<(), Diagnostic> {
+        loop {
+            self.start_statement();
+            match self.peek() {
+                Some(TokenKind::DocComment(_)) => self.token()?,
+                Some(TokenKind::LeftBracket) => self.attribute()?,
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    /// Formats the doc comments and attributes before an element of a parenthesized list. Attributes are kept on the
+    /// same line as the element. Doc comments are placed on their own line when the list is wrapped, and otherwise
+    /// force the list to be wrapped (since nothing can follow a doc comment on the same line).
+    fn inline_prelude(&mut self, wrapped: bool) -> Result<(), Diagnostic> {
+        loop {
+            match self.peek() {
+                Some(TokenKind::DocComment(_)) => {
+                    self.token()?;
+                    match wrapped {
+                        true => self.newline(),
+                        false => self.state.needs_break = true,
+                    }
+                }
+                Some(TokenKind::LeftBracket) => {
+                    self.attribute()?;
+                    self.space();
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    /// Formats the base types of a class, exception or interface, or the underlying type of an enum, if present.
+    fn base_types(&mut self) -> Result<(), Diagnostic> {
+        if !self.peek_matches(|k| matches!(k, TokenKind::Colon)) {
+            return Ok(());
+        }
+
+        self.space();
+        self.token()?;
+        self.space();
+        self.type_ref()?;
+        while self.peek_matches(|k| matches!(k, TokenKind::Comma)) {
+            self.skip()?;
+            // Trailing commas are removed.
+            if self.peek_matches(|k| matches!(k, TokenKind::LeftBrace)) {
+                break;
+            }
+            self.write(",");
+            self.space();
+            self.type_ref()?;
+        }
+        Ok(())
+    }
+
+    /// Formats a braced block, with each of its members on separate lines. Empty blocks are written as `{}`.
+    fn body(&mut self, member: fn(&mut Self) -> Result<(), Diagnostic>) -> Result<(), Diagnostic> {
+        self.space();
+        self.expect("'{'", |k| matches!(k, TokenKind::LeftBrace))?;
+
+        // If there's nothing (not even a comment) before the closing brace, we keep the braces together.
+        if matches!(
+            self.elements.get(self.state.position),
+            Some(Element::Token(_, TokenKind::RightBrace, _)),
+        ) {
+            return self.token();
+        }
+
+        self.state.opened_block = true;
+        self.state.indent += 1;
+        while !self.peek_matches(|k| matches!(k, TokenKind::RightBrace)) {
+            member(self)?;
+        }
+        self.newline();
+        self.state.indent -= 1;
+        self.token()
+    }
+
+    fn field(&mut self) -> Result<(), Diagnostic> {
+        self.prelude()?;
+        self.member()?;
+        self.skip_optional_comma()
+    }
+
+    fn operation(&mut self) -> Result<(), Diagnostic> {
+        self.prelude()?;
+
+        // First try to fit the entire signature on one line. If it doesn't fit, wrap the parameters, and if it still
+        // doesn't fit, wrap the return members too.
+        let checkpoint = self.checkpoint();
+        for wrapping in [Wrapping::None, Wrapping::Parameters, Wrapping::All] {
+            self.restore(&checkpoint);
+            self.operation_signature(wrapping)?;
+            if wrapping == Wrapping::All || self.fits(&checkpoint, wrapping == Wrapping::None) {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn operation_signature(&mut self, wrapping: Wrapping) -> Result<(), Diagnostic> {
+        if self.peek_matches(|k| matches!(k, TokenKind::IdempotentKeyword)) {
+            self.keyword()?;
+        }
+        self.identifier()?;
+        self.parenthesized_list(wrapping != Wrapping::None)?;
+
+        if self.peek_matches(|k| matches!(k, TokenKind::Arrow)) {
+            self.space();
+            self.token()?;
+            self.space();
+            match self.peek() {
+                Some(TokenKind::LeftParenthesis) => self.parenthesized_list(wrapping == Wrapping::All)?,
+                _ => self.unnamed_member()?,
+            }
+        }
+
+        if self.peek_matches(|k| matches!(k, TokenKind::ThrowsKeyword)) {
+            self.space();
+            self.keyword()?;
+            match self.peek() {
+                Some(TokenKind::AnyExceptionKeyword) => self.token()?,
+                _ => self.type_ref()?,
+            }
+        }
+        Ok(())
+    }
+
+    fn enumerator(&mut self) -> Result<(), Diagnostic> {
+        self.prelude()?;
+
+        let checkpoint = self.checkpoint();
+        for wrapped in [false, true] {
+            self.restore(&checkpoint);
+            self.identifier()?;
+            if self.peek_matches(|k| matches!(k, TokenKind::LeftParenthesis)) {
+                self.parenthesized_list(wrapped)?;
+            }
+            if self.peek_matches(|k| matches!(k, TokenKind::Equals)) {
+                self.expect_spaced("'='", |k| matches!(k, TokenKind::Equals))?;
+                self.signed_integer()?;
+            }
+            if wrapped || self.fits(&checkpoint, true) {
+                break;
+            }
+        }
+        self.skip_optional_comma()
+    }
+
+    /// Formats a parenthesized list of parameters, return members, or enumerator fields.
+    ///
+    /// Unwrapped lists are written on one line, with elements separated by `", "`. Wrapped lists have each of their
+    /// elements on a separate line, followed by a comma, with the closing parenthesis on its own line.
+    fn parenthesized_list(&mut self, wrapped: bool) -> Result<(), Diagnostic> {
+        let is_list_end = |k: &TokenKind| matches!(k, TokenKind::RightParenthesis);
+        self.expect("'('", |k| matches!(k, TokenKind::LeftParenthesis))?;
+
+        if wrapped && !self.peek_matches(is_list_end) {
+            self.state.indent += 1;
+            while !self.peek_matches(is_list_end) {
+                self.newline();
+                self.inline_prelude(true)?;
+                self.member()?;
+                self.write(",");
+                self.skip_optional_comma()?;
+            }
+            self.newline();
+            self.state.indent -= 1;
+        } else {
+            let mut is_first = true;
+            while !self.peek_matches(is_list_end) {
+                if !is_first {
+                    self.write(",");
+                    self.space();
+                }
+                is_first = false;
+                self.inline_prelude(false)?;
+                self.member()?;
+                self.skip_optional_comma()?;
+            }
+        }
+        self.expect("')'", is_list_end)
+    }
+
+    /// Formats a field, parameter, or return member (excluding its prelude).
+    fn member(&mut self) -> Result<(), Diagnostic> {
+        self.tag()?;
+        self.identifier()?;
+        self.expect("':'", |k| matches!(k, TokenKind::Colon))?;
+        self.space();
+        self.unnamed_member()?;
+        if self.peek_matches(|k| matches!(k, TokenKind::Equals)) {
+            self.expect_spaced("'='", |k| matches!(k, TokenKind::Equals))?;
+            self.literal()?;
+        }
+        Ok(())
+    }
+
+    /// Formats a type, along with its (optional) tag and `stream` modifier.
+    fn unnamed_member(&mut self) -> Result<(), Diagnostic> {
+        self.tag()?;
+        if self.peek_matches(|k| matches!(k, TokenKind::StreamKeyword)) {
+            self.keyword()?;
+        }
+        self.type_ref()
+    }
+
+    fn tag(&mut self) -> Result<(), Diagnostic> {
+        if self.peek_matches(|k| matches!(k, TokenKind::TagKeyword)) {
+            self.token()?;
+            self.expect("'('", |k| matches!(k, TokenKind::LeftParenthesis))?;
+            self.signed_integer()?;
+            self.expect("')'", |k| matches!(k, TokenKind::RightParenthesis))?;
+            self.space();
+        }
+        Ok(())
+    }
+
+    fn type_ref(&mut self) -> Result<(), Diagnostic> {
+        while self.peek_matches(|k| matches!(k, TokenKind::LeftBracket)) {
+            self.attribute()?;
+            self.space();
+        }
+
+        match self.peek() {
+            Some(TokenKind::SequenceKeyword) => {
+                self.token()?;
+                self.expect("'<'", |k| matches!(k, TokenKind::LeftChevron))?;
+                self.type_ref()?;
+                self.expect("'>'", |k| matches!(k, TokenKind::RightChevron))?;
+            }
+            Some(TokenKind::DictionaryKeyword) => {
+                self.token()?;
+                self.expect("'<'", |k| matches!(k, TokenKind::LeftChevron))?;
+                self.type_ref()?;
+                self.expect("','", |k| matches!(k, TokenKind::Comma))?;
+                self.space();
+                self.type_ref()?;
+                self.expect("'>'", |k| matches!(k, TokenKind::RightChevron))?;
+            }
+            Some(kind) if is_primitive(kind) => self.token()?,
+            _ => self.scoped_identifier()?,
+        }
+
+        if self.peek_matches(|k| matches!(k, TokenKind::QuestionMark)) {
+            self.token()?;
+        }
+        Ok(())
+    }
+
+    /// Formats a local attribute (`[...]`) or a file attribute (`[[...]]`).
+    fn attribute(&mut self) -> Result<(), Diagnostic> {
+        self.token()?; // The opening bracket(s).
+        self.scoped_identifier()?;
+        if self.peek_matches(|k| matches!(k, TokenKind::LeftParenthesis)) {
+            self.token()?;
+            self.comma_list(|k| matches!(k, TokenKind::RightParenthesis))?;
+            self.token()?;
+        }
+        self.expect("']'", |k| {
+            matches!(k, TokenKind::RightBracket | TokenKind::DoubleRightBracket)
+        })
+    }
+
+    fn attribute_argument(&mut self) -> Result<(), Diagnostic> {
+        match self.peek() {
+            Some(TokenKind::StringLiteral(_)) => self.token(),
+            Some(TokenKind::LeftBracket) => {
+                self.token()?;
+                self.comma_list(|k| matches!(k, TokenKind::RightBracket))?;
+                self.token()
+            }
+            Some(TokenKind::Identifier(_)) => {
+                self.token()?;
+                if self.peek_matches(|k| matches!(k, TokenKind::Equals)) {
+                    self.expect_spaced("'='", |k| matches!(k, TokenKind::Equals))?;
+                    self.attribute_argument()?;
+                }
+                Ok(())
+            }
+            _ => self.signed_integer(),
+        }
+    }
+
+    /// Formats a comma separated list of attribute arguments, stopping before the token that ends the list.
+    fn comma_list(&mut self, is_list_end: fn(&TokenKind) -> bool) -> Result<(), Diagnostic> {
+        while !self.peek_matches(is_list_end) {
+            self.attribute_argument()?;
+            if self.peek_matches(|k| matches!(k, TokenKind::Comma)) {
+                self.skip()?;
+                // Trailing commas are removed.
+                if !self.peek_matches(is_list_end) {
+                    self.write(",");
+                    self.space();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn literal(&mut self) -> Result<(), Diagnostic> {
+        if self.peek_matches(|k| matches!(k, TokenKind::Minus)) {
+            self.token()?;
+        }
+        match self.peek() {
+            Some(TokenKind::IntegerLiteral(_) | TokenKind::FloatLiteral(_) | TokenKind::StringLiteral(_)) => {
+                self.token()
+            }
+            _ => self.scoped_identifier(),
+        }
+    }
+
+    fn signed_integer(&mut self) -> Result<(), Diagnostic> {
+        if self.peek_matches(|k| matches!(k, TokenKind::Minus)) {
+            self.token()?;
+        }
+        self.expect("an integer", |k| matches!(k, TokenKind::IntegerLiteral(_)))
+    }
+
+    fn identifier(&mut self) -> Result<(), Diagnostic> {
+        self.expect("an identifier", |k| matches!(k, TokenKind::Identifier(_)))
+    }
+
+    fn scoped_identifier(&mut self) -> Result<(), Diagnostic> {
+        if self.peek_matches(|k| matches!(k, TokenKind::DoubleColon)) {
+            self.token()?;
+        }
+        self.identifier()?;
+        while self.peek_matches(|k| matches!(k, TokenKind::DoubleColon)) {
+            self.token()?;
+            self.identifier()?;
+        }
+        Ok(())
+    }
+
+    /// Writes the next token (which should be a keyword), followed by a space.
+    fn keyword(&mut self) -> Result<(), Diagnostic> {
+        self.token()?;
+        self.space();
+        Ok(())
+    }
+
+    fn skip_optional_comma(&mut self) -> Result<(), Diagnostic> {
+        if self.peek_matches(|k| matches!(k, TokenKind::Comma)) {
+            self.skip()?;
+        }
+        Ok(())
+    }
+
+    // ========== Token Handling ========== //
+
+    /// Returns the kind of the next token, skipping over any trivia.
+    fn peek(&self) -> Option<&TokenKind<'a>> {
+        self.elements[self.state.position..]
+            .iter()
+            .find(|element| !element.is_trivia())
+            .map(|element| match element {
+                Element::Token(_, kind, _) => kind,
+                Element::Directive(..) => unreachable!("directives are trivia"),
+            })
+    }
+
+    fn peek_matches(&self, predicate: impl FnOnce(&TokenKind) -> bool) -> bool {
+        self.peek().is_some_and(predicate)
+    }
+
+    /// Consumes the next token without writing it, and returns its location and text.
+    /// Any trivia before the token is written first.
+    fn next_token(&mut self) -> Result<(Location, TokenKind<'a>, Location), Diagnostic> {
+        self.flush_trivia();
+        match self.elements.get(self.state.position) {
+            Some(Element::Token(start, kind, end)) => {
+                let token = (*start, kind.clone(), *end);
+                self.state.position += 1;
+                self.state.last_row = end.row;
+                Ok(token)
+            }
+            _ => Err(self.unexpected("more tokens")),
+        }
+    }
+
+    /// Consumes and writes the next token.
+    fn token(&mut self) -> Result<(), Diagnostic> {
+        let (start, kind, end) = self.next_token()?;
+        let text = match kind {
+            TokenKind::DocComment(content) => format!("///{}", content.trim_end()),
+            _ => self.source_text(start, end),
+        };
+        self.write(&text);
+        Ok(())
+    }
+
+    /// Consumes and writes the next token, returning an error if it isn't what was expected.
+    fn expect(&mut self, expected: &str, predicate: impl FnOnce(&TokenKind) -> bool) -> Result<(), Diagnostic> {
+        match self.peek_matches(predicate) {
+            true => self.token(),
+            false => Err(self.unexpected(expected)),
+        }
+    }
+
+    /// Consumes and writes the next token surrounded by spaces, returning an error if it isn't what was expected.
+    fn expect_spaced(&mut self, expected: &str, predicate: impl FnOnce(&TokenKind) -> bool) -> Result<(), Diagnostic> {
+        self.space();
+        self.expect(expected, predicate)?;
+        self.space();
+        Ok(())
+    }
+
+    /// Consumes the next token without writing it.
+    fn skip(&mut self) -> Result<(), Diagnostic> {
+        self.next_token().map(|_| ())
+    }
+
+    fn unexpected(&self, expected: &str) -> Diagnostic {
+        let next_token = self.elements[self.state.position..]
+            .iter()
+            .find(|element| !element.is_trivia());
+        let (message, start, end) = match next_token {
+            Some(Element::Token(start, kind, end)) => (format!("found '{kind}'"), *start, *end),
+            _ => {
+                let location = Location {
+                    row: self.source_lines.len(),
+                    col: self.source_lines.last().map_or(0, |line| line.chars().count()) + 1,
+                };
+                ("reached the end of the file".to_owned(), location, location)
+            }
+        };
+
+        // The file has already been successfully parsed, so this can only happen when preprocessor directives split
+        // a definition in a way that is only valid when some of the directives' branches are removed.
+        let message = format!(
+            "unable to format file: expected {expected}, but {message}; this is likely caused by preprocessor \
+             directives splitting a definition",
+        );
+        Diagnostic::new(Error::Syntax { message }).set_span(&Span::new(start, end, self.file_name))
+    }
+
+    /// Returns the text between 2 locations in the source file.
+    fn source_text(&self, start: Location, end: Location) -> String {
+        let column_offset = |line: &str, col: usize| line.char_indices().nth(col - 1).map_or(line.len(), |(i, _)| i);
+
+        let first_line = self.source_lines[start.row - 1];
+        if start.row == end.row {
+            let last_line = first_line;
+            return last_line[column_offset(first_line, start.col)..column_offset(last_line, end.col)].to_owned();
+        }
+
+        let last_line = self.source_lines[end.row - 1];
+        let mut lines = vec![&first_line[column_offset(first_line, start.col)..]];
+        lines.extend(&self.source_lines[start.row..end.row - 1]);
+        lines.push(&last_line[..column_offset(last_line, end.col)]);
+        lines.iter().map(|line| line.trim_end()).collect::<Vec<_>>().join("\n")
+    }
+
+    // ========== Writing ========== //
+
+    /// Writes any comments and preprocessor directives before the next token.
+    ///
+    /// Comments that start on the same line as the previously written token are kept at the end of the current line.
+    /// All other comments and directives are written on their own line. Directives are never indented.
+    fn flush_trivia(&mut self) {
+        while let Some(element) = self.elements.get(self.state.position).filter(|e| e.is_trivia()) {
+            let (start, end, text, is_line_comment) = match element {
+                Element::Directive(row, directive) => {
+                    let location = Location { row: *row, col: 1 };
+                    (location, location, directive.to_string(), false)
+                }
+                Element::Token(start, kind, end) => {
+                    let is_line_comment = matches!(kind, TokenKind::LineComment(_));
+                    (*start, *end, self.source_text(*start, *end), is_line_comment)
+                }
+            };
+            let is_directive = matches!(element, Element::Directive(..));
+            self.state.position += 1;
+
+            if !is_directive && start.row == self.state.last_row && !self.state.line.is_empty() {
+                self.state.line += " ";
+                self.state.line += &text;
+                self.state.needs_break |= is_line_comment;
+            } else {
+                self.end_line();
+                self.blank_line_before(start.row);
+                let indent = if is_directive { 0 } else { self.state.indent };
+                self.output.push(INDENT.repeat(indent) + text.trim_end());
+                self.state.at_block_start = false;
+            }
+            self.state.last_row = end.row;
+        }
+    }
+
+    /// Writes the provided text to the current line, preceded by a space if one is pending.
+    fn write(&mut self, text: &str) {
+        if self.state.needs_break {
+            self.end_line();
+            self.state.is_continuation = true;
+        }
+        if self.state.line.is_empty() {
+            self.state.line_indent = self.state.indent + usize::from(self.state.is_continuation);
+        } else if self.state.pending_space {
+            self.state.line.push(' ');
+        }
+        self.state.pending_space = false;
+        self.state.line += text;
+    }
+
+    fn space(&mut self) {
+        self.state.pending_space = true;
+    }
+
+    /// Writes any pending trivia, and ends the current line.
+    fn newline(&mut self) {
+        self.flush_trivia();
+        self.end_line();
+    }
+
+    /// Ends the current line, and starts a new statement on the next line. If there were any blank lines between the
+    /// previous statement and this one in the source file, a single blank line is written between them.
+    fn start_statement(&mut self) {
+        self.newline();
+        let next_token = self.elements[self.state.position..].first();
+        if let Some(Element::Token(start, ..)) = next_token {
+            self.blank_line_before(start.row);
+        }
+    }
+
+    fn blank_line_before(&mut self, row: usize) {
+        let follows_blank_line = row > self.state.last_row + 1;
+        let is_first_line = self.output.is_empty();
+        if follows_blank_line && !is_first_line && !self.state.at_block_start {
+            self.output.push(String::new());
+        }
+    }
+
+    fn end_line(&mut self) {
+        if !self.state.line.is_empty() {
+            let line = std::mem::take(&mut self.state.line);
+            self.output
+                .push(INDENT.repeat(self.state.line_indent) + line.trim_end());
+            self.state.at_block_start = self.state.opened_block;
+        }
+        self.state.opened_block = false;
+        self.state.is_continuation = false;
+        self.state.pending_space = false;
+        self.state.needs_break = false;
+    }
+
+    /// Returns true if the text written since the checkpoint fits within the maximum width.
+    /// If `single_line` is true, the text must also have been written on a single line.
+    fn fits(&self, checkpoint: &Checkpoint, single_line: bool) -> bool {
+        let width = INDENT.len() * self.state.line_indent + self.state.line.chars().count();
+        let is_single_line = self.output.len() == checkpoint.output_length && !self.state.needs_break;
+        width <= MAX_WIDTH && (is_single_line || !single_line)
+    }
+
+    fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            state: self.state.clone(),
+            output_length: self.output.len(),
+        }
+    }
+
+    fn restore(&mut self, checkpoint: &Checkpoint) {
+        self.state = checkpoint.state.clone();
+        self.output.truncate(checkpoint.output_length);
+    }
+}
+
+fn is_primitive(kind: &TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::BoolKeyword
+            | TokenKind::Int8Keyword
+            | TokenKind::UInt8Keyword
+            | TokenKind::Int16Keyword
+            | TokenKind::UInt16Keyword
+            | TokenKind::Int32Keyword
+            | TokenKind::UInt32Keyword
+            | TokenKind::VarInt32Keyword
+            | TokenKind::VarUInt32Keyword
+            | TokenKind::Int64Keyword
+            | TokenKind::UInt64Keyword
+            | TokenKind::VarInt62Keyword
+            | TokenKind::VarUInt62Keyword
+            | TokenKind::Float32Keyword
+            | TokenKind::Float64Keyword
+            | TokenKind::StringKeyword
+            | TokenKind::AnyClassKeyword
+    )
+}
@@ -0,0 +1,129 @@
+// Copyright (c) ZeroC, Inc.
+
+//! The formatter re-emits Slice files in a canonical style, so that layout doesn't need to be discussed in reviews.
+//!
+//! Formatting works directly on the file's tokens instead of on its AST, since the AST doesn't store comments or
+//! preprocessor directives, and only contains the definitions from active preprocessor branches. Files are still
+//! parsed before being formatted, so that syntactically invalid files are rejected instead of being mangled.
+//!
+//! The canonical style is:
+//! - Definitions and members are each placed on their own line, indented by 4 spaces per level of nesting.
+//! - Doc comments and attributes are placed on their own lines, before the element they apply to.
+//! - `:` is followed by a space, and is also preceded by one when it introduces a base type or underlying type.
+//!   `->`, `=`, and `throws` are surrounded by spaces. List elements are separated by `", "`.
+//! - Optional commas between members are removed. Operations that are longer than [`MAX_WIDTH`] characters have their
+//!   parameters (and return members, if necessary) wrapped onto separate lines, each followed by a comma.
+//! - Comments and preprocessor directives are kept. Single blank lines between elements are kept, but runs of blank
+//!   lines are collapsed into one.
+
+mod layout;
+
+use self::layout::{Element, Layout};
+use crate::ast::Ast;
+use crate::diagnostics::{Diagnostic, DiagnosticKind, Error};
+use crate::parsers::{parse_file, SliceLexer, SourceBlock};
+use crate::predefined_symbols;
+use crate::slice_file::{Location, SliceFile, Span};
+
+/// The maximum width of a line, before the formatter wraps it (if the line can be wrapped).
+pub const MAX_WIDTH: usize = 120;
+
+/// Formats the provided Slice source code in the canonical style, and returns the formatted code.
+///
+/// If the code contains syntax errors, or preprocessor directives that split a definition in a way the formatter
+/// can't understand, the code isn't formatted, and the errors are returned instead.
+pub fn format_slice(file_name: &str, raw_text: &str) -> Result<String, Vec<Diagnostic>> {
+    // Parse the file to check that it's syntactically valid.
+    let mut slice_file = SliceFile::new(file_name.to_owned(), raw_text.to_owned(), false);
+    let mut diagnostics = Vec::new();
+    parse_file(
+        &mut slice_file,
+        &mut Ast::create(),
+        &mut diagnostics,
+        predefined_symbols(),
+    );
+    diagnostics.retain(|diagnostic| matches!(diagnostic.kind, DiagnosticKind::Error(_)));
+    if !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+
+    let elements = lex_with_trivia(file_name, raw_text)?;
+    Layout::new(file_name, raw_text, elements)
+        .format_file()
+        .map_err(|error| vec![error])
+}
+
+/// Lexes the provided source code into a stream of tokens, comments, and preprocessor directives.
+///
+/// Unlike the preprocessor, this doesn't evaluate directives; every line of source code is lexed, even those which are
+/// in inactive branches. Since directives always span an entire line, this splits the code into lines, and lexes the
+/// lines between directives, before merging the directives back into the resulting token stream.
+fn lex_with_trivia<'a>(file_name: &str, raw_text: &'a str) -> Result<Vec<Element<'a>>, Vec<Diagnostic>> {
+    let mut directives = Vec::new();
+    let mut source_blocks = Vec::new();
+    let mut block_start: Option<(usize, Location)> = None;
+
+    let mut line_start = 0;
+    for (index, line) in raw_text.split_inclusive('\n').enumerate() {
+        let location = Location { row: index + 1, col: 1 };
+        if line.trim_start().starts_with('#') {
+            if let Some((start, start_location)) = block_start.take() {
+                source_blocks.push(create_source_block(
+                    raw_text,
+                    start,
+                    line_start,
+                    start_location,
+                    location,
+                ));
+            }
+            directives.push((location.row, line.trim()));
+        } else if block_start.is_none() {
+            block_start = Some((line_start, location));
+        }
+        line_start += line.len();
+    }
+    if let Some((start, start_location)) = block_start {
+        let end_location = Location {
+            row: raw_text.lines().count().max(1),
+            col: 1,
+        };
+        source_blocks.push(create_source_block(
+            raw_text,
+            start,
+            raw_text.len(),
+            start_location,
+            end_location,
+        ));
+    }
+
+    let mut elements = Vec::new();
+    let mut directives = directives.into_iter().peekable();
+    for token in SliceLexer::with_trivia(source_blocks.into_iter()) {
+        let (start, token_kind, end) = token.map_err(|(start, error, end)| {
+            let message = error.to_string();
+            vec![Diagnostic::new(Error::Syntax { message }).set_span(&Span::new(start, end, file_name))]
+        })?;
+
+        // Any directives which come before this token are inserted before it.
+        while let Some((row, directive)) = directives.next_if(|(row, _)| *row < start.row) {
+            elements.push(Element::Directive(row, directive));
+        }
+        elements.push(Element::Token(start, token_kind, end));
+    }
+    elements.extend(directives.map(|(row, directive)| Element::Directive(row, directive)));
+    Ok(elements)
+}
+
+fn create_source_block(
+    raw_text: &str,
+    start: usize,
+    end: usize,
+    start_location: Location,
+    end_location: Location,
+) -> SourceBlock<'_> {
+    SourceBlock {
+        content: &raw_text[start..end],
+        start: start_location,
+        end: end_location,
+    }
+}
@@ -11,39 +11,7 @@ impl Allow {
     pub fn parse_from(Unparsed { directive, args }: &Unparsed, span: &Span, reporter: &mut DiagnosticReporter) -> Self {
         debug_assert_eq!(directive, Self::directive());
 
-        check_that_arguments_were_provided(args, Self::directive(), span, reporter);
-
-        for arg in args {
-            let mut is_valid = Lint::ALLOWABLE_LINT_IDENTIFIERS.contains(&arg.as_str());
-
-            // The `DuplicateFile` lint can't be configured by attributes because it's a command-line specific lint.
-            if arg == "DuplicateFile" {
-                is_valid = false;
-            }
-
-            // Emit an error if the argument wasn't valid.
-            if !is_valid {
-                // TODO we should emit a link to the lint page when we write it!
-                let mut error = Diagnostic::new(Error::ArgumentNotSupported {
-                    argument: arg.to_owned(),
-                    directive: "allow".to_owned(),
-                })
-                .set_span(span);
-
-                // Check if the argument only differs in case from a valid one.
-                let suggestion = Lint::ALLOWABLE_LINT_IDENTIFIERS
-                    .iter()
-                    .find(|identifier| identifier.eq_ignore_ascii_case(arg));
-                if let Some(identifier) = suggestion {
-                    let message = format!("attribute arguments are case sensitive, perhaps you meant '{identifier}'?");
-                    error = error.add_note(message, None);
-                }
-
-                error.report(reporter);
-            }
-        }
-
-        let allowed_lints = args.clone();
+        let allowed_lints = parse_lint_arguments(args, Self::directive(), span, reporter);
         Allow { allowed_lints }
     }
 
@@ -17,14 +17,14 @@ impl Compress {
         let (mut compress_args, mut compress_return) = (false, false);
         for arg in args {
             match arg.as_str() {
-                "Args" => compress_args = true,
-                "Return" => compress_return = true,
+                Some("Args") => compress_args = true,
+                Some("Return") => compress_return = true,
                 _ => {
                     Diagnostic::new(Error::ArgumentNotSupported {
-                        argument: arg.clone(),
+                        argument: arg.to_string(),
                         directive: Self::directive().to_owned(),
                     })
-                    .set_span(span)
+                    .set_span(&arg.span)
                     .add_note("'Args' and 'Return' are the only valid arguments", None)
                     .report(reporter);
                 }
@@ -13,7 +13,19 @@ impl Deprecated {
 
         check_that_at_most_one_argument_was_provided(args, Self::directive(), span, reporter);
 
-        let reason = args.first().cloned();
+        let reason = args.first().and_then(|arg| {
+            let reason = arg.as_str().map(str::to_owned);
+            if reason.is_none() {
+                Diagnostic::new(Error::ArgumentNotSupported {
+                    argument: arg.to_string(),
+                    directive: Self::directive().to_owned(),
+                })
+                .set_span(&arg.span)
+                .add_note("the deprecation reason must be a string", None)
+                .report(reporter);
+            }
+            reason
+        });
         Deprecated { reason }
     }
 
@@ -0,0 +1,33 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::*;
+
+#[derive(Debug)]
+pub struct Flags {}
+
+impl Flags {
+    pub fn parse_from(Unparsed { directive, args }: &Unparsed, span: &Span, reporter: &mut DiagnosticReporter) -> Self {
+        debug_assert_eq!(directive, Self::directive());
+
+        check_that_no_arguments_were_provided(args, Self::directive(), span, reporter);
+
+        Flags {}
+    }
+
+    pub fn validate_on(&self, applied_on: Attributables, span: &Span, reporter: &mut DiagnosticReporter) {
+        let is_unsigned = match applied_on {
+            Attributables::Enum(enum_def) => enum_def
+                .underlying
+                .as_ref()
+                .is_some_and(|underlying| underlying.is_unsigned_numeric()),
+            _ => false,
+        };
+
+        if !is_unsigned {
+            let note = "the flags attribute can only be applied to enums with unsigned underlying types";
+            report_unexpected_attribute(self, span, Some(note), reporter);
+        }
+    }
+}
+
+implement_attribute_kind_for!(Flags, "flags", false);
@@ -2,18 +2,24 @@
 
 mod allow;
 mod compress;
+mod deny;
 mod deprecated;
+mod flags;
 mod oneway;
 mod sliced_format;
+mod warn;
 
 pub use allow::*;
 pub use compress::*;
+pub use deny::*;
 pub use deprecated::*;
+pub use flags::*;
 pub use oneway::*;
 pub use sliced_format::*;
+pub use warn::*;
 
-use super::Attributables;
-use crate::diagnostics::{Diagnostic, DiagnosticReporter, Error, Lint};
+use super::{Attributables, AttributeArgument};
+use crate::diagnostics::{Diagnostic, DiagnosticReporter, Error};
 use crate::slice_file::Span;
 use crate::utils::attribute_parsing_util::*;
 
@@ -58,7 +64,7 @@ pub use implement_attribute_kind_for;
 #[derive(Debug)]
 pub struct Unparsed {
     pub directive: String,
-    pub args: Vec<String>,
+    pub args: Vec<AttributeArgument>,
 }
 
 impl AttributeKind for Unparsed {
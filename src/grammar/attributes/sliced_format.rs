@@ -17,14 +17,14 @@ impl SlicedFormat {
         let (mut sliced_args, mut sliced_return) = (false, false);
         for arg in args {
             match arg.as_str() {
-                "Args" => sliced_args = true,
-                "Return" => sliced_return = true,
+                Some("Args") => sliced_args = true,
+                Some("Return") => sliced_return = true,
                 _ => {
                     Diagnostic::new(Error::ArgumentNotSupported {
-                        argument: arg.clone(),
+                        argument: arg.to_string(),
                         directive: Self::directive().to_owned(),
                     })
-                    .set_span(span)
+                    .set_span(&arg.span)
                     .add_note("'Args' and 'Return' are the only valid arguments", None)
                     .report(reporter);
                 }
@@ -0,0 +1,25 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::*;
+
+#[derive(Debug)]
+pub struct Warn {
+    pub warned_lints: Vec<String>,
+}
+
+impl Warn {
+    pub fn parse_from(Unparsed { directive, args }: &Unparsed, span: &Span, reporter: &mut DiagnosticReporter) -> Self {
+        debug_assert_eq!(directive, Self::directive());
+
+        let warned_lints = parse_lint_arguments(args, Self::directive(), span, reporter);
+        Warn { warned_lints }
+    }
+
+    pub fn validate_on(&self, applied_on: Attributables, span: &Span, reporter: &mut DiagnosticReporter) {
+        if matches!(applied_on, Attributables::Module(_) | Attributables::TypeRef(_)) {
+            report_unexpected_attribute(self, span, None, reporter);
+        }
+    }
+}
+
+implement_attribute_kind_for!(Warn, "warn", true);
@@ -10,6 +10,10 @@ pub struct DocComment {
     pub returns: Vec<ReturnsTag>,
     pub throws: Vec<ThrowsTag>,
     pub see: Vec<SeeTag>,
+    pub remarks: Vec<RemarksTag>,
+    pub examples: Vec<ExampleTag>,
+    pub since: Vec<SinceTag>,
+    pub deprecated: Vec<DeprecatedTag>,
     pub span: Span,
 }
 
@@ -64,6 +68,31 @@ impl SeeTag {
     }
 }
 
+#[derive(Debug)]
+pub struct RemarksTag {
+    pub message: Message,
+    pub span: Span,
+}
+
+/// A usage example. Unlike other tags, the body of an example is kept verbatim, and isn't checked for inline tags.
+#[derive(Debug)]
+pub struct ExampleTag {
+    pub code: String,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct SinceTag {
+    pub version: String,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct DeprecatedTag {
+    pub message: Message,
+    pub span: Span,
+}
+
 #[derive(Debug)]
 pub struct LinkTag {
     pub link: TypeRefDefinition<dyn Entity>,
@@ -79,10 +108,44 @@ impl LinkTag {
     }
 }
 
+/// A span of inline code, ex: `` `foo()` ``.
+#[derive(Debug)]
+pub struct InlineCode {
+    pub code: String,
+    pub span: Span,
+}
+
+/// A span of emphasized text, ex: `*important*`. Strong emphasis uses double asterisks, ex: `**very important**`.
+#[derive(Debug)]
+pub struct Emphasis {
+    pub text: String,
+    pub is_strong: bool,
+    pub span: Span,
+}
+
+/// A list of consecutive lines that each start with a bullet, either `-` or `*`.
+#[derive(Debug)]
+pub struct BulletList {
+    pub items: Vec<Message>,
+    pub span: Span,
+}
+
+/// A block of code surrounded by fences ("```"). The opening fence can optionally specify the code's language.
+#[derive(Debug)]
+pub struct FencedCodeBlock {
+    pub language: Option<String>,
+    pub code: String,
+    pub span: Span,
+}
+
 #[derive(Debug)]
 pub enum MessageComponent {
     Text(String),
     Link(LinkTag),
+    Code(InlineCode),
+    Emphasis(Emphasis),
+    List(BulletList),
+    CodeBlock(FencedCodeBlock),
 }
 
 pub type Message = Vec<MessageComponent>;
@@ -99,5 +162,21 @@ implement_Element_for!(ThrowsTag, "throws tag");
 implement_Symbol_for!(ThrowsTag);
 implement_Element_for!(SeeTag, "see tag");
 implement_Symbol_for!(SeeTag);
+implement_Element_for!(RemarksTag, "remarks tag");
+implement_Symbol_for!(RemarksTag);
+implement_Element_for!(ExampleTag, "example tag");
+implement_Symbol_for!(ExampleTag);
+implement_Element_for!(SinceTag, "since tag");
+implement_Symbol_for!(SinceTag);
+implement_Element_for!(DeprecatedTag, "deprecated tag");
+implement_Symbol_for!(DeprecatedTag);
 implement_Element_for!(LinkTag, "link tag");
 implement_Symbol_for!(LinkTag);
+implement_Element_for!(InlineCode, "inline code");
+implement_Symbol_for!(InlineCode);
+implement_Element_for!(Emphasis, "emphasis");
+implement_Symbol_for!(Emphasis);
+implement_Element_for!(BulletList, "bullet list");
+implement_Symbol_for!(BulletList);
+implement_Element_for!(FencedCodeBlock, "code block");
+implement_Symbol_for!(FencedCodeBlock);
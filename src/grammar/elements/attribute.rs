@@ -2,17 +2,25 @@
 
 use super::super::*;
 use crate::slice_file::Span;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Debug)]
 pub struct Attribute {
     pub kind: Box<dyn AttributeKind>,
+    /// The arguments this attribute was written with. Unlike the arguments stored in [`Unparsed`](attributes::Unparsed)
+    /// attributes, these are kept after the attribute is parsed into a specific kind.
+    pub args: Vec<AttributeArgument>,
     pub span: Span,
 }
 
 impl Attribute {
-    pub fn new(directive: String, args: Vec<String>, span: Span) -> Self {
-        let kind = Box::new(attributes::Unparsed { directive, args });
-        Self { kind, span }
+    pub fn new(directive: String, args: Vec<AttributeArgument>, span: Span) -> Self {
+        let kind = Box::new(attributes::Unparsed {
+            directive,
+            args: args.clone(),
+        });
+        Self { kind, args, span }
     }
 
     pub fn downcast<T: AttributeKind + 'static>(&self) -> Option<&T> {
@@ -22,3 +30,105 @@ impl Attribute {
 
 implement_Element_for!(Attribute, "attribute");
 implement_Symbol_for!(Attribute);
+
+/// A single argument that was passed to an attribute, ex: the `Args` in `[compress(Args)]`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AttributeArgument {
+    pub value: ArgumentValue,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ArgumentValue {
+    /// An unquoted identifier, ex: `Args`.
+    Identifier(String),
+
+    /// A string literal, with its escape sequences already processed.
+    String(String),
+
+    /// An integer literal, including any leading minus sign.
+    Integer(i128),
+
+    /// A boolean literal: `true` or `false`.
+    Bool(bool),
+
+    /// A bracketed list of arguments, ex: `[1, 2, 3]`.
+    List(Vec<AttributeArgument>),
+
+    /// A named argument, ex: `level = 3`.
+    KeyValue(Identifier, Box<AttributeArgument>),
+}
+
+impl AttributeArgument {
+    /// Returns the argument's text if it's an identifier or a string literal, and `None` otherwise.
+    pub fn as_str(&self) -> Option<&str> {
+        match &self.value {
+            ArgumentValue::Identifier(s) | ArgumentValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the argument's value if it's an integer literal, and `None` otherwise.
+    pub fn as_integer(&self) -> Option<i128> {
+        match &self.value {
+            ArgumentValue::Integer(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// Returns the argument's value if it's a boolean literal, and `None` otherwise.
+    pub fn as_bool(&self) -> Option<bool> {
+        match &self.value {
+            ArgumentValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Returns the argument's elements if it's a list, and `None` otherwise.
+    pub fn as_list(&self) -> Option<&[AttributeArgument]> {
+        match &self.value {
+            ArgumentValue::List(elements) => Some(elements),
+            _ => None,
+        }
+    }
+
+    /// Returns the argument's key and value if it's a `key = value` pair, and `None` otherwise.
+    pub fn as_key_value(&self) -> Option<(&str, &AttributeArgument)> {
+        match &self.value {
+            ArgumentValue::KeyValue(key, value) => Some((&key.value, value)),
+            _ => None,
+        }
+    }
+}
+
+impl Element for AttributeArgument {
+    fn kind(&self) -> &'static str {
+        match &self.value {
+            ArgumentValue::Identifier(_) => "identifier",
+            ArgumentValue::String(_) => "string literal",
+            ArgumentValue::Integer(_) => "integer literal",
+            ArgumentValue::Bool(_) => "boolean literal",
+            ArgumentValue::List(_) => "list",
+            ArgumentValue::KeyValue(..) => "key-value pair",
+        }
+    }
+}
+
+implement_Symbol_for!(AttributeArgument);
+
+impl fmt::Display for AttributeArgument {
+    /// Writes the argument as it would appear in a Slice file, except that string literals are written without their
+    /// quotes, so they can be embedded directly into diagnostic messages.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            ArgumentValue::Identifier(s) | ArgumentValue::String(s) => f.write_str(s),
+            ArgumentValue::Integer(i) => write!(f, "{i}"),
+            ArgumentValue::Bool(b) => write!(f, "{b}"),
+            ArgumentValue::List(elements) => {
+                let elements = elements.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+                write!(f, "[{}]", elements.join(", "))
+            }
+            ArgumentValue::KeyValue(key, value) => write!(f, "{} = {value}", key.value),
+        }
+    }
+}
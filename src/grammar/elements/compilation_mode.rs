@@ -2,8 +2,9 @@
 
 use super::super::*;
 use crate::slice_file::Span;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FileCompilationMode {
     pub version: CompilationMode,
     pub span: Span,
@@ -0,0 +1,32 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::super::*;
+use crate::slice_file::Span;
+use crate::utils::ptr_util::WeakPtr;
+
+#[derive(Debug)]
+pub struct Constant {
+    pub identifier: Identifier,
+    pub data_type: TypeRef,
+    pub value: Literal,
+    pub scope: Scope,
+    pub attributes: Vec<WeakPtr<Attribute>>,
+    pub comment: Option<DocComment>,
+    pub span: Span,
+}
+
+impl Constant {
+    /// Returns the enumerator this constant's value refers to, if the constant's type is an enum.
+    /// Returns `None` if the constant's type isn't an enum, or if no matching enumerator exists.
+    pub fn enumerator(&self) -> Option<&Enumerator> {
+        match self.data_type.concrete_type() {
+            Types::Enum(enum_def) => self.value.as_enumerator(enum_def),
+            _ => None,
+        }
+    }
+}
+
+implement_Element_for!(Constant, "constant");
+implement_Attributable_for!(Constant);
+implement_Entity_for!(Constant);
+implement_Commentable_for!(Constant);
@@ -1,5 +1,6 @@
 // Copyright (c) ZeroC, Inc.
 
+use super::super::attributes::Flags;
 use super::super::*;
 use crate::slice_file::Span;
 use crate::supported_encodings::SupportedEncodings;
@@ -23,6 +24,16 @@ impl Enum {
         self.enumerators.iter().map(WeakPtr::borrow).collect()
     }
 
+    /// Returns true if this enum is marked with the `flags` attribute, meaning its enumerators are bit flags.
+    pub fn is_flags(&self) -> bool {
+        self.has_attribute::<Flags>()
+    }
+
+    /// Returns true if any of this enum's enumerators have associated fields.
+    pub fn has_enumerators_with_fields(&self) -> bool {
+        self.enumerators().iter().any(|enumerator| enumerator.has_fields())
+    }
+
     pub fn get_min_max_values(&self) -> Option<(i128, i128)> {
         let values = self.enumerators.iter().map(|enumerator| enumerator.borrow().value());
 
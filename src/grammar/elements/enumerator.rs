@@ -3,11 +3,13 @@
 use super::super::*;
 use crate::slice_file::Span;
 use crate::utils::ptr_util::WeakPtr;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug)]
 pub struct Enumerator {
     pub identifier: Identifier,
     pub value: EnumeratorValue,
+    pub fields: Vec<WeakPtr<Field>>,
     pub parent: WeakPtr<Enum>,
     pub scope: Scope,
     pub attributes: Vec<WeakPtr<Attribute>>,
@@ -22,9 +24,18 @@ impl Enumerator {
             EnumeratorValue::Explicit(integer) => integer.value,
         }
     }
+
+    pub fn fields(&self) -> Vec<&Field> {
+        self.fields.iter().map(WeakPtr::borrow).collect()
+    }
+
+    /// Returns true if this enumerator has associated fields.
+    pub fn has_fields(&self) -> bool {
+        !self.fields.is_empty()
+    }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum EnumeratorValue {
     Implicit(i128),
     Explicit(Integer<i128>),
@@ -35,3 +46,4 @@ implement_Attributable_for!(@Contained Enumerator);
 implement_Entity_for!(Enumerator);
 implement_Commentable_for!(Enumerator);
 implement_Contained_for!(Enumerator, Enum);
+implement_Container_for!(Enumerator, WeakPtr<Field>, fields);
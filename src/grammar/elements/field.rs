@@ -9,6 +9,7 @@ pub struct Field {
     pub identifier: Identifier,
     pub data_type: TypeRef,
     pub tag: Option<Integer<u32>>,
+    pub default_value: Option<Literal>,
     pub parent: WeakPtr<dyn Container<WeakPtr<Field>>>,
     pub scope: Scope,
     pub attributes: Vec<WeakPtr<Attribute>>,
@@ -21,4 +22,4 @@ implement_Attributable_for!(@Contained Field);
 implement_Entity_for!(Field);
 implement_Commentable_for!(Field);
 implement_Contained_for!(Field, dyn Container<WeakPtr<Field>> + 'static);
-implement_Member_for!(Field);
+implement_Member_for!(@Defaultable Field);
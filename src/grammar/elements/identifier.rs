@@ -2,8 +2,9 @@
 
 use super::super::*;
 use crate::slice_file::Span;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Identifier {
     pub value: String,
     pub span: Span,
@@ -0,0 +1,26 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::super::*;
+use crate::slice_file::Span;
+use serde::{Deserialize, Serialize};
+
+/// An import declaration from the prelude of a Slice file, ex: `import "other.slice"` or `import Module::Type`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Import {
+    pub kind: ImportKind,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ImportKind {
+    /// Imports every definition in the Slice file with the provided path.
+    /// Relative paths are resolved against the directory of the importing file.
+    File(String),
+
+    /// Imports the definition with the provided identifier, along with anything nested inside of it.
+    /// This can be used to import entire modules, ex: `import Foo::Bar` imports everything in module `Foo::Bar`.
+    Definition(Identifier),
+}
+
+implement_Element_for!(Import, "import");
+implement_Symbol_for!(Import);
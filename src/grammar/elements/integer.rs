@@ -2,9 +2,10 @@
 
 use super::super::*;
 use crate::slice_file::Span;
+use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Integer<T: Debug> {
     pub value: T,
     pub span: Span,
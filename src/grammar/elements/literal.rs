@@ -0,0 +1,79 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::super::*;
+use crate::slice_file::Span;
+use serde::{Deserialize, Serialize};
+
+/// A literal value written in a Slice file, such as the value of a [Constant].
+///
+/// Literals are untyped when parsed; they are checked against the type they're assigned to during validation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Literal {
+    pub value: LiteralValue,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum LiteralValue {
+    /// A boolean literal: `true` or `false`.
+    Bool(bool),
+
+    /// An integer literal, including any leading minus sign.
+    Integer(i128),
+
+    /// A floating point literal, including any leading minus sign.
+    Float(f64),
+
+    /// A string literal, with its escape sequences already processed.
+    String(String),
+
+    /// A (possibly scoped) identifier. These are used to refer to enumerators, ex: `Color::Red`.
+    Identifier(String),
+}
+
+impl Literal {
+    /// If this literal is an identifier, returns the enumerator it refers to in the provided enum (if one exists).
+    ///
+    /// The identifier can either be just the enumerator's name (`Red`) or be qualified with the enum's identifier
+    /// (`Color::Red`, `MyModule::Color::Red`, or `::MyModule::Color::Red`).
+    pub fn as_enumerator<'a>(&self, enum_def: &'a Enum) -> Option<&'a Enumerator> {
+        let LiteralValue::Identifier(identifier) = &self.value else {
+            return None;
+        };
+
+        // Split the identifier into its enumerator name, and an optional qualifying prefix.
+        let (prefix, name) = match identifier.rsplit_once("::") {
+            Some((prefix, name)) => (Some(prefix.trim_start_matches("::")), name),
+            None => (None, identifier.as_str()),
+        };
+
+        // If a prefix was provided, it must name the enum that we're looking in.
+        if let Some(prefix) = prefix {
+            let is_match = prefix == enum_def.identifier()
+                || prefix == enum_def.module_scoped_identifier()
+                || prefix == enum_def.parser_scoped_identifier();
+            if !is_match {
+                return None;
+            }
+        }
+
+        enum_def
+            .enumerators()
+            .into_iter()
+            .find(|enumerator| enumerator.identifier() == name)
+    }
+}
+
+impl Element for Literal {
+    fn kind(&self) -> &'static str {
+        match &self.value {
+            LiteralValue::Bool(_) => "boolean literal",
+            LiteralValue::Integer(_) => "integer literal",
+            LiteralValue::Float(_) => "floating point literal",
+            LiteralValue::String(_) => "string literal",
+            LiteralValue::Identifier(_) => "identifier",
+        }
+    }
+}
+
+implement_Symbol_for!(Literal);
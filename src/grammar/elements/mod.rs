@@ -3,6 +3,7 @@
 mod attribute;
 mod class;
 mod compilation_mode;
+mod constant;
 mod custom_type;
 mod dictionary;
 mod r#enum;
@@ -10,8 +11,10 @@ mod enumerator;
 mod exception;
 mod field;
 mod identifier;
+mod import;
 mod integer;
 mod interface;
+mod literal;
 mod module;
 mod operation;
 mod parameter;
@@ -25,14 +28,17 @@ mod type_ref;
 pub use self::attribute::*;
 pub use self::class::*;
 pub use self::compilation_mode::*;
+pub use self::constant::*;
 pub use self::custom_type::*;
 pub use self::dictionary::*;
 pub use self::enumerator::*;
 pub use self::exception::*;
 pub use self::field::*;
 pub use self::identifier::*;
+pub use self::import::*;
 pub use self::integer::*;
 pub use self::interface::*;
+pub use self::literal::*;
 pub use self::module::*;
 pub use self::operation::*;
 pub use self::parameter::*;
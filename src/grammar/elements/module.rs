@@ -8,12 +8,13 @@ use crate::utils::ptr_util::WeakPtr;
 pub struct Module {
     pub identifier: Identifier,
     pub attributes: Vec<WeakPtr<Attribute>>,
+    pub comment: Option<DocComment>,
     pub span: Span,
 }
 
 impl Module {
-    /// If this module was declared using nested module syntax, this returns the entire nested identifier.
-    /// Otherwise this just returns the module's identifier.
+    /// If this module was declared using nested module syntax, or inside of another module's block, this returns the
+    /// entire nested identifier. Otherwise this just returns the module's identifier.
     pub fn nested_module_identifier(&self) -> &str {
         &self.identifier.value
     }
@@ -45,3 +46,4 @@ impl NamedSymbol for Module {
 implement_Element_for!(Module, "module");
 implement_Symbol_for!(Module);
 implement_Attributable_for!(Module);
+implement_Commentable_for!(Module);
@@ -14,6 +14,7 @@ pub struct Parameter {
     pub parent: WeakPtr<Operation>,
     pub scope: Scope,
     pub attributes: Vec<WeakPtr<Attribute>>,
+    pub comment: Option<DocComment>,
     pub span: Span,
 }
 
@@ -31,3 +32,4 @@ implement_Attributable_for!(@Contained Parameter);
 implement_Entity_for!(Parameter);
 implement_Contained_for!(Parameter, Operation);
 implement_Member_for!(Parameter);
+implement_Commentable_for!(Parameter);
@@ -2,7 +2,7 @@
 
 use super::attributes::AttributeKind;
 use super::comments::DocComment;
-use super::elements::{Attribute, Identifier, Integer, Module, TypeRef};
+use super::elements::{Attribute, Identifier, Integer, Literal, Module, TypeRef};
 use super::util::{Scope, TagFormat};
 use super::wrappers::{AsEntities, AsTypes};
 use crate::slice_file::Span;
@@ -84,6 +84,9 @@ pub trait Member: Entity {
     fn data_type(&self) -> &TypeRef;
     fn raw_tag(&self) -> Option<&Integer<u32>>;
 
+    /// Returns the literal this member's value defaults to, if it was given one.
+    fn default_value(&self) -> Option<&Literal>;
+
     fn tag(&self) -> Option<u32> {
         self.raw_tag().map(|tag| tag.value)
     }
@@ -93,7 +96,7 @@ pub trait Member: Entity {
     }
 }
 
-pub trait Commentable: Entity {
+pub trait Commentable: NamedSymbol + Attributable {
     fn comment(&self) -> Option<&DocComment>;
 }
 
@@ -249,6 +252,25 @@ macro_rules! implement_Member_for {
             fn raw_tag(&self) -> Option<&Integer<u32>> {
                 self.tag.as_ref()
             }
+
+            fn default_value(&self) -> Option<&Literal> {
+                None
+            }
+        }
+    };
+    (@Defaultable $type:ty) => {
+        impl Member for $type {
+            fn data_type(&self) -> &TypeRef {
+                &self.data_type
+            }
+
+            fn raw_tag(&self) -> Option<&Integer<u32>> {
+                self.tag.as_ref()
+            }
+
+            fn default_value(&self) -> Option<&Literal> {
+                self.default_value.as_ref()
+            }
         }
     };
 }
@@ -2,6 +2,7 @@
 
 use super::Module;
 use crate::utils::ptr_util::WeakPtr;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 #[derive(Clone, Debug, Default)]
@@ -47,7 +48,7 @@ pub fn get_scoped_identifier(identifier: &str, scope: &str) -> String {
 /// This enum specifies all the encodings supported by IceRPC.
 ///
 /// These encodings identity the format used to convert Slice types to and from byte streams.
-#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Encoding {
     /// Version 1 of the Slice encoding, supported by IceRPC, and compatible with Ice 3.5 or
     /// greater.
@@ -23,7 +23,7 @@ macro_rules! generate_definition_wrapper {
     };
 }
 
-generate_definition_wrapper!(Struct, Class, Exception, Interface, Enum, CustomType, TypeAlias);
+generate_definition_wrapper!(Struct, Class, Exception, Interface, Enum, CustomType, TypeAlias, Constant);
 
 macro_rules! generate_entities_wrapper {
     ($($variant:ident),*) => {
@@ -34,7 +34,7 @@ macro_rules! generate_entities_wrapper {
 
         $(
         impl AsEntities for $variant {
-            fn concrete_entity(&self) -> Entities {
+            fn concrete_entity(&self) -> Entities<'_> {
                 Entities::$variant(self)
             }
         }
@@ -43,11 +43,11 @@ macro_rules! generate_entities_wrapper {
 }
 
 pub trait AsEntities {
-    fn concrete_entity(&self) -> Entities;
+    fn concrete_entity(&self) -> Entities<'_>;
 }
 
 generate_entities_wrapper!(
-    Struct, Class, Exception, Field, Interface, Operation, Parameter, Enum, Enumerator, CustomType, TypeAlias
+    Struct, Class, Exception, Field, Interface, Operation, Parameter, Enum, Enumerator, CustomType, TypeAlias, Constant
 );
 
 macro_rules! generate_attributables_wrapper {
@@ -59,7 +59,7 @@ macro_rules! generate_attributables_wrapper {
 
         $(
         impl AsAttributables for $variant {
-            fn concrete_attributable(&self) -> Attributables {
+            fn concrete_attributable(&self) -> Attributables<'_> {
                 Attributables::$variant(self)
             }
         }
@@ -68,12 +68,12 @@ macro_rules! generate_attributables_wrapper {
 }
 
 pub trait AsAttributables {
-    fn concrete_attributable(&self) -> Attributables;
+    fn concrete_attributable(&self) -> Attributables<'_>;
 }
 
 generate_attributables_wrapper!(
     Module, Struct, Class, Exception, Field, Interface, Operation, Parameter, Enum, Enumerator, CustomType, TypeAlias,
-    TypeRef, SliceFile
+    Constant, TypeRef, SliceFile
 );
 
 macro_rules! generate_types_wrapper {
@@ -85,7 +85,7 @@ macro_rules! generate_types_wrapper {
 
         $(
         impl AsTypes for $variant {
-            fn concrete_type(&self) -> Types {
+            fn concrete_type(&self) -> Types<'_> {
                 Types::$variant(self)
             }
         }
@@ -109,7 +109,7 @@ macro_rules! generate_types_wrapper {
 }
 
 pub trait AsTypes {
-    fn concrete_type(&self) -> Types;
+    fn concrete_type(&self) -> Types<'_>;
 }
 
 generate_types_wrapper!(Struct, Class, Exception, Interface, Enum, CustomType, Sequence, Dictionary, Primitive);
@@ -1,9 +1,12 @@
 // Copyright (c) ZeroC, Inc.
 
 pub mod ast;
+pub mod ast_json;
 pub mod code_block;
+pub mod compatibility;
 pub mod compilation_state;
 pub mod diagnostics;
+pub mod formatter;
 pub mod grammar;
 pub mod parsers;
 pub mod slice_file;
@@ -15,15 +18,31 @@ pub mod validators;
 pub mod visitor;
 
 use compilation_state::CompilationState;
+use diagnostics::{Diagnostic, DiagnosticReporter, Error};
+use parsers::ParseCache;
 use slice_file::SliceFile;
 use slice_options::SliceOptions;
-use std::collections::HashSet;
+use std::collections::HashMap;
 use utils::file_util;
 
 pub fn compile_from_options(
     options: &SliceOptions,
     patcher: unsafe fn(&mut CompilationState),
     validator: fn(&mut CompilationState),
+) -> CompilationState {
+    compile_from_options_with_symbols(options, &[], patcher, validator)
+}
+
+/// Compiles the files specified by the provided options, like [compile_from_options], but also predefines the provided
+/// preprocessor symbols. This lets language compilers define their own symbols (ex: `SLICEC_CS` or `SLICEC_RS`) on top
+/// of the ones predefined by slicec, without mutating the user's options. Each symbol can optionally have a value.
+///
+/// Symbols defined by the user on the command line take precedence over symbols provided by this function.
+pub fn compile_from_options_with_symbols(
+    options: &SliceOptions,
+    symbols: &[(&str, Option<i64>)],
+    patcher: unsafe fn(&mut CompilationState),
+    validator: fn(&mut CompilationState),
 ) -> CompilationState {
     // Create an instance of `CompilationState` for holding all the compiler's state.
     let mut state = CompilationState::create(options);
@@ -33,7 +52,7 @@ pub fn compile_from_options(
 
     // If any files were unreadable, return without parsing. Otherwise, parse the files normally.
     if !state.diagnostic_reporter.has_errors() {
-        compile_files(files, &mut state, options, patcher, validator);
+        compile_files(files, &mut state, options, symbols, patcher, validator);
     }
     state
 }
@@ -55,7 +74,7 @@ pub fn compile_from_strings(
         files.push(SliceFile::new(format!("string-{i}"), input.to_owned(), false))
     }
 
-    compile_files(files, &mut state, &slice_options, patcher, validator);
+    compile_files(files, &mut state, &slice_options, &[], patcher, validator);
     state
 }
 
@@ -63,6 +82,7 @@ fn compile_files(
     files: Vec<SliceFile>,
     state: &mut CompilationState,
     options: &SliceOptions,
+    symbols: &[(&str, Option<i64>)],
     patcher: unsafe fn(&mut CompilationState),
     validator: fn(&mut CompilationState),
 ) {
@@ -70,7 +90,10 @@ fn compile_files(
     state.files = files.into_iter().map(|f| (f.relative_path.clone(), f)).collect();
 
     // Retrieve any preprocessor symbols defined by the compiler itself, or by the user on the command line.
-    let defined_symbols = HashSet::from_iter(options.defined_symbols.clone());
+    // Symbols are applied in order, so user-defined symbols take precedence over the compiler's symbols.
+    let mut defined_symbols = predefined_symbols();
+    defined_symbols.extend(symbols.iter().map(|(name, value)| (name.to_string(), *value)));
+    defined_symbols.extend(parse_defined_symbols(options, &mut state.diagnostic_reporter));
 
     // There are several phases of compilation handled by `slicec`:
     // 1) Parse the files passed in by the user.
@@ -78,11 +101,88 @@ fn compile_files(
     // 3) Apply the user-provided patching function.
     // 4) Validate the AST, checking for language-mapping agnostic errors.
     // 5) Apply the user-provided validation function.
-    parsers::parse_files(state, &defined_symbols);
+    // 6) Export the AST as JSON, if requested and compilation succeeded.
+    let parse_cache = open_parse_cache(options, &mut state.diagnostic_reporter);
+    parsers::parse_files(state, &defined_symbols, parse_cache.as_ref());
 
     unsafe { state.apply_unsafe(ast::patch_ast) };
     unsafe { state.apply_unsafe(patcher) };
 
     state.apply(validators::validate_ast);
     state.apply(validator);
+
+    // Lint levels are resolved before checking for errors, so lints which were raised to errors (with `--deny` or
+    // `--warnings-as-errors` for example) also prevent the AST from being exported.
+    if let Some(path) = &options.emit_ast_json {
+        let (_, total_errors) = state.diagnostic_reporter.update_diagnostics(&state.ast, &state.files);
+        if total_errors == 0 {
+            ast_json::emit_ast_json(state, path);
+        }
+    }
+}
+
+/// Opens the parse cache in the directory specified by the user, if any. If it can't be opened, this reports an error.
+fn open_parse_cache(options: &SliceOptions, diagnostic_reporter: &mut DiagnosticReporter) -> Option<ParseCache> {
+    let directory = options.parse_cache.as_ref()?;
+    match ParseCache::open(directory) {
+        Ok(parse_cache) => Some(parse_cache),
+        Err(error) => {
+            let path = directory.clone();
+            Diagnostic::new(Error::IO {
+                action: "open",
+                path,
+                error,
+            })
+            .report(diagnostic_reporter);
+            None
+        }
+    }
+}
+
+/// Returns the preprocessor symbols that slicec always defines:
+/// - `SLICEC_VERSION_MAJOR`: the major version of slicec.
+/// - `SLICEC_VERSION_MINOR`: the minor version of slicec.
+/// - `SLICEC_VERSION_PATCH`: the patch version of slicec.
+pub fn predefined_symbols() -> HashMap<String, Option<i64>> {
+    let version_component = |component: &str| component.parse::<i64>().ok();
+    HashMap::from([
+        (
+            "SLICEC_VERSION_MAJOR".to_owned(),
+            version_component(env!("CARGO_PKG_VERSION_MAJOR")),
+        ),
+        (
+            "SLICEC_VERSION_MINOR".to_owned(),
+            version_component(env!("CARGO_PKG_VERSION_MINOR")),
+        ),
+        (
+            "SLICEC_VERSION_PATCH".to_owned(),
+            version_component(env!("CARGO_PKG_VERSION_PATCH")),
+        ),
+    ])
+}
+
+/// Parses the preprocessor symbols that were defined on the command line. Symbols can be defined as `NAME`, or with an
+/// integer value as `NAME=VALUE`. If a symbol's value isn't an integer, this reports an error and skips the symbol.
+fn parse_defined_symbols(
+    options: &SliceOptions,
+    diagnostic_reporter: &mut DiagnosticReporter,
+) -> HashMap<String, Option<i64>> {
+    let mut defined_symbols = HashMap::new();
+    for definition in &options.defined_symbols {
+        match definition.split_once('=') {
+            None => {
+                defined_symbols.insert(definition.clone(), None);
+            }
+            Some((name, value)) => match value.trim().parse::<i64>() {
+                Ok(value) => {
+                    defined_symbols.insert(name.trim().to_owned(), Some(value));
+                }
+                Err(_) => {
+                    let definition = definition.clone();
+                    Diagnostic::new(Error::InvalidSymbolDefinition { definition }).report(diagnostic_reporter);
+                }
+            },
+        }
+    }
+    defined_symbols
 }
@@ -0,0 +1,178 @@
+// Copyright (c) ZeroC, Inc.
+
+//! An opt-in, on-disk cache of parsed Slice files, which is enabled with the `--parse-cache` option.
+//!
+//! Each entry holds a snapshot of a file's parsed contents (see [model]), along with the key it was stored under: the
+//! file's path, the length and hash of its contents, and the preprocessor symbols it was parsed with. Entries are named
+//! after a hash of their key, but since hashes can collide, the full key is compared when an entry is loaded. When an
+//! unchanged file is compiled again, its snapshot is rehydrated into the AST, instead of preprocessing, lexing, and
+//! parsing the file again. Entries which can't be read or rehydrated are ignored, and the file is parsed as usual.
+//!
+//! Only files which parsed without emitting any diagnostics are cached, so there are never any diagnostics to replay.
+//! Entries are only valid for the version of slicec and the cache format that wrote them; opening a cache that was
+//! written by a different version of either removes all of its entries.
+
+mod model;
+mod rehydrate;
+
+use self::model::CachedFile;
+use crate::ast::Ast;
+use crate::slice_file::SliceFile;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// The name of the file that records which version of slicec (and of the cache format) wrote the cache's entries.
+const VERSION_FILE_NAME: &str = "slicec-version";
+
+/// The version of the format entries are stored in. This must be incremented whenever [model] changes, since the
+/// version of slicec isn't necessarily changed along with it.
+const FORMAT_VERSION: u32 = 1;
+
+/// The extension of the cache's entries.
+const ENTRY_EXTENSION: &str = "json";
+
+#[derive(Debug)]
+pub struct ParseCache {
+    directory: PathBuf,
+}
+
+impl ParseCache {
+    /// Opens the parse cache stored in the provided directory, creating the directory if it doesn't exist yet.
+    /// If the cache was written by a different version of slicec or of the cache format, all of its entries are removed.
+    pub fn open(directory: impl Into<PathBuf>) -> io::Result<Self> {
+        let directory = directory.into();
+        fs::create_dir_all(&directory)?;
+
+        let version_file = directory.join(VERSION_FILE_NAME);
+        let version = cache_version();
+        if fs::read_to_string(&version_file).ok().as_deref() != Some(version.as_str()) {
+            // Only remove the cache's own entries, in case the directory is shared with other files.
+            for entry in fs::read_dir(&directory)? {
+                let path = entry?.path();
+                if path.extension().is_some_and(|extension| extension == ENTRY_EXTENSION) {
+                    fs::remove_file(path)?;
+                }
+            }
+            fs::write(version_file, version)?;
+        }
+
+        Ok(ParseCache { directory })
+    }
+
+    /// If this cache holds an entry for the provided file and symbols, this rehydrates the entry into the AST, stores
+    /// its contents in the file, and returns true. Otherwise, this returns false and leaves the file untouched.
+    ///
+    /// Unreadable, corrupted, or mismatched entries are treated as missing, so the file is parsed again, and the entry
+    /// replaced.
+    pub(crate) fn load(&self, file: &mut SliceFile, ast: &mut Ast, symbols: &HashMap<String, Option<i64>>) -> bool {
+        let key = EntryKey::new(file, symbols);
+        let Ok(contents) = fs::read_to_string(self.entry_path(&key)) else { return false };
+        let Ok(entry) = serde_json::from_str::<Entry>(&contents) else { return false };
+
+        entry.key == key && entry.file.rehydrate(file, ast).is_ok()
+    }
+
+    /// Stores the contents of a file that was just parsed in this cache. This must be called before the AST is patched.
+    ///
+    /// Caching is best-effort, so if the entry can't be written, the file will just be parsed again next time.
+    pub(crate) fn store(&self, file: &SliceFile, symbols: &HashMap<String, Option<i64>>) {
+        let entry = Entry {
+            key: EntryKey::new(file, symbols),
+            file: CachedFile::from_parsed_file(file),
+        };
+        let Ok(contents) = serde_json::to_string(&entry) else { return };
+
+        // Write the entry to a temporary file first, so concurrent compilations never see partially written entries.
+        let entry_path = self.entry_path(&entry.key);
+        let temporary_path = entry_path.with_extension(format!("{}.tmp", std::process::id()));
+        if fs::write(&temporary_path, contents).is_err() || fs::rename(&temporary_path, entry_path).is_err() {
+            let _ = fs::remove_file(temporary_path);
+        }
+    }
+
+    fn entry_path(&self, key: &EntryKey) -> PathBuf {
+        let mut hasher = Fnv1aHasher::default();
+        hasher.write(key.version.as_bytes());
+        hasher.write(key.path.as_bytes());
+        hasher.write(&key.source_length.to_le_bytes());
+        hasher.write(&key.source_hash.to_le_bytes());
+        for (name, value) in &key.symbols {
+            hasher.write(name.as_bytes());
+            hasher.write(format!("{value:?}").as_bytes());
+        }
+
+        let file_name = format!("{:016x}.{ENTRY_EXTENSION}", hasher.finish());
+        self.directory.join(file_name)
+    }
+}
+
+/// Returns the version that's recorded in the cache's version file, and in each of its entries.
+fn cache_version() -> String {
+    format!("{}+format{FORMAT_VERSION}", env!("CARGO_PKG_VERSION"))
+}
+
+/// A single entry of the cache: a snapshot of a file, and the key it was stored under.
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    key: EntryKey,
+    file: CachedFile,
+}
+
+/// Everything that the snapshot of a file depends on.
+#[derive(PartialEq, Serialize, Deserialize)]
+struct EntryKey {
+    version: String,
+    path: String,
+    source_length: usize,
+    source_hash: u64,
+    /// The preprocessor symbols, sorted by name, since the iteration order of a `HashMap` isn't stable.
+    symbols: Vec<(String, Option<i64>)>,
+}
+
+impl EntryKey {
+    fn new(file: &SliceFile, symbols: &HashMap<String, Option<i64>>) -> Self {
+        let mut hasher = Fnv1aHasher::default();
+        hasher.write(file.raw_text.as_bytes());
+
+        let mut symbols = symbols
+            .iter()
+            .map(|(name, value)| (name.clone(), *value))
+            .collect::<Vec<_>>();
+        symbols.sort();
+
+        EntryKey {
+            version: cache_version(),
+            path: file.relative_path.clone(),
+            source_length: file.raw_text.len(),
+            source_hash: hasher.finish(),
+            symbols,
+        }
+    }
+}
+
+/// The 64-bit FNV-1a hash function. Unlike the standard library's hashers, its output is stable across Rust versions
+/// and platforms, which is necessary for the hashes to be usable as keys of a persistent cache.
+struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Fnv1aHasher(0xcbf29ce484222325)
+    }
+}
+
+impl Fnv1aHasher {
+    /// Hashes the provided bytes, followed by a separator, so consecutive writes can't be confused with each other.
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter().chain(&[0xff]) {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
@@ -0,0 +1,549 @@
+// Copyright (c) ZeroC, Inc.
+
+//! The serializable form of a parsed Slice file, as it's stored in the [parse cache](super::ParseCache).
+//!
+//! Files are cached right after they're parsed, before the AST is patched. At that point, elements only point to their
+//! children, their module, their attributes, and anonymous types (primitives, sequences, and dictionaries). Every other
+//! reference is still an unpatched identifier, which can be stored as-is. So in this model, children are stored inline
+//! in their parents, modules are referred to by their index in the file, and anonymous types are stored inline.
+
+use crate::grammar::*;
+use crate::slice_file::{SliceFile, Span};
+use crate::utils::ptr_util::WeakPtr;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedFile {
+    pub mode: Option<FileCompilationMode>,
+    pub attributes: Vec<CachedAttribute>,
+    pub imports: Vec<Import>,
+    pub modules: Vec<CachedModule>,
+    pub definitions: Vec<CachedDefinition>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedModule {
+    pub identifier: Identifier,
+    pub attributes: Vec<CachedAttribute>,
+    pub comment: Option<CachedDocComment>,
+    pub span: Span,
+}
+
+/// The properties shared by all entities.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedEntity {
+    pub identifier: Identifier,
+    pub scope: CachedScope,
+    pub attributes: Vec<CachedAttribute>,
+    pub comment: Option<CachedDocComment>,
+    pub span: Span,
+}
+
+/// A [Scope], where the module is stored as an index into the file's modules.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedScope {
+    pub parser_scope: String,
+    pub module: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) enum CachedDefinition {
+    Struct {
+        entity: CachedEntity,
+        is_compact: bool,
+        fields: Vec<CachedField>,
+    },
+    Class {
+        entity: CachedEntity,
+        compact_id: Option<Integer<u32>>,
+        base: Option<CachedTypeRef>,
+        fields: Vec<CachedField>,
+    },
+    Exception {
+        entity: CachedEntity,
+        base: Option<CachedTypeRef>,
+        fields: Vec<CachedField>,
+    },
+    Interface {
+        entity: CachedEntity,
+        bases: Vec<CachedTypeRef>,
+        operations: Vec<CachedOperation>,
+    },
+    Enum {
+        entity: CachedEntity,
+        underlying: Option<CachedTypeRef>,
+        is_unchecked: bool,
+        enumerators: Vec<CachedEnumerator>,
+    },
+    CustomType {
+        entity: CachedEntity,
+    },
+    TypeAlias {
+        entity: CachedEntity,
+        underlying: CachedTypeRef,
+    },
+    Constant {
+        entity: CachedEntity,
+        data_type: CachedTypeRef,
+        value: Literal,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedField {
+    pub entity: CachedEntity,
+    pub data_type: CachedTypeRef,
+    pub tag: Option<Integer<u32>>,
+    pub default_value: Option<Literal>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedOperation {
+    pub entity: CachedEntity,
+    pub parameters: Vec<CachedParameter>,
+    pub return_type: Vec<CachedParameter>,
+    pub throws: CachedThrows,
+    pub is_idempotent: bool,
+    pub encoding: Encoding,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) enum CachedThrows {
+    None,
+    Specific(Box<CachedTypeRef>),
+    AnyException,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedParameter {
+    pub entity: CachedEntity,
+    pub data_type: CachedTypeRef,
+    pub tag: Option<Integer<u32>>,
+    pub is_streamed: bool,
+    pub is_returned: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedEnumerator {
+    pub entity: CachedEntity,
+    pub value: EnumeratorValue,
+    pub fields: Vec<CachedField>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedTypeRef {
+    pub definition: CachedTypeRefDefinition,
+    pub is_optional: bool,
+    pub scope: CachedScope,
+    pub attributes: Vec<CachedAttribute>,
+    pub span: Span,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) enum CachedTypeRefDefinition {
+    /// A reference to a user defined type, which is resolved during patching.
+    Unpatched(Identifier),
+    /// A primitive type, stored by its keyword.
+    Primitive(String),
+    Sequence(Box<CachedTypeRef>),
+    Dictionary(Box<CachedTypeRef>, Box<CachedTypeRef>),
+}
+
+/// An attribute, before it's parsed into a specific kind by the attribute patcher.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedAttribute {
+    pub directive: String,
+    pub args: Vec<AttributeArgument>,
+    pub span: Span,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedDocComment {
+    pub overview: Option<CachedSection>,
+    pub params: Vec<CachedParamTag>,
+    pub returns: Vec<CachedReturnsTag>,
+    pub throws: Vec<CachedThrowsTag>,
+    pub see: Vec<CachedLink>,
+    pub remarks: Vec<CachedSection>,
+    pub examples: Vec<CachedCode>,
+    pub since: Vec<CachedSinceTag>,
+    pub deprecated: Vec<CachedSection>,
+    pub span: Span,
+}
+
+/// A doc comment section that only holds a message: an overview, a remarks tag, or a deprecated tag.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedSection {
+    pub message: CachedMessage,
+    pub span: Span,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedParamTag {
+    pub identifier: Identifier,
+    pub message: CachedMessage,
+    pub span: Span,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedReturnsTag {
+    pub identifier: Option<Identifier>,
+    pub message: CachedMessage,
+    pub span: Span,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedThrowsTag {
+    pub thrown_type: Option<Identifier>,
+    pub message: CachedMessage,
+    pub span: Span,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedSinceTag {
+    pub version: String,
+    pub span: Span,
+}
+
+/// A `@see` or `{@link}` tag. Links are always unpatched when a file is cached.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedLink {
+    pub link: Identifier,
+    pub span: Span,
+}
+
+/// An example tag or a span of inline code.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct CachedCode {
+    pub code: String,
+    pub span: Span,
+}
+
+pub(super) type CachedMessage = Vec<CachedMessageComponent>;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) enum CachedMessageComponent {
+    Text(String),
+    Link(CachedLink),
+    Code(CachedCode),
+    Emphasis {
+        text: String,
+        is_strong: bool,
+        span: Span,
+    },
+    List {
+        items: Vec<CachedMessage>,
+        span: Span,
+    },
+    CodeBlock {
+        language: Option<String>,
+        code: String,
+        span: Span,
+    },
+}
+
+impl CachedFile {
+    /// Creates a snapshot of a file that was just parsed, and whose AST hasn't been patched yet.
+    pub fn from_parsed_file(file: &SliceFile) -> Self {
+        let snapshotter = Snapshotter { modules: &file.modules };
+        CachedFile {
+            mode: file.mode.clone(),
+            attributes: snapshotter.attributes(&file.attributes),
+            imports: file.imports.clone(),
+            modules: file.modules.iter().map(|m| snapshotter.module(m.borrow())).collect(),
+            definitions: file.contents.iter().map(|d| snapshotter.definition(d)).collect(),
+        }
+    }
+}
+
+struct Snapshotter<'a> {
+    modules: &'a [WeakPtr<Module>],
+}
+
+impl Snapshotter<'_> {
+    fn module(&self, module_def: &Module) -> CachedModule {
+        CachedModule {
+            identifier: module_def.identifier.clone(),
+            attributes: self.attributes(&module_def.attributes),
+            comment: module_def.comment.as_ref().map(|comment| self.doc_comment(comment)),
+            span: module_def.span.clone(),
+        }
+    }
+
+    fn definition(&self, definition: &Definition) -> CachedDefinition {
+        match definition {
+            Definition::Struct(struct_ptr) => {
+                let struct_def = struct_ptr.borrow();
+                CachedDefinition::Struct {
+                    entity: self.entity(struct_def, &struct_def.attributes, &struct_def.comment),
+                    is_compact: struct_def.is_compact,
+                    fields: self.fields(struct_def.fields()),
+                }
+            }
+            Definition::Class(class_ptr) => {
+                let class_def = class_ptr.borrow();
+                CachedDefinition::Class {
+                    entity: self.entity(class_def, &class_def.attributes, &class_def.comment),
+                    compact_id: class_def.compact_id.as_ref().cloned(),
+                    base: class_def.base.as_ref().map(|base| self.type_ref(base)),
+                    fields: self.fields(class_def.fields()),
+                }
+            }
+            Definition::Exception(exception_ptr) => {
+                let exception_def = exception_ptr.borrow();
+                CachedDefinition::Exception {
+                    entity: self.entity(exception_def, &exception_def.attributes, &exception_def.comment),
+                    base: exception_def.base.as_ref().map(|base| self.type_ref(base)),
+                    fields: self.fields(exception_def.fields()),
+                }
+            }
+            Definition::Interface(interface_ptr) => {
+                let interface_def = interface_ptr.borrow();
+                CachedDefinition::Interface {
+                    entity: self.entity(interface_def, &interface_def.attributes, &interface_def.comment),
+                    bases: interface_def.bases.iter().map(|base| self.type_ref(base)).collect(),
+                    operations: interface_def
+                        .operations()
+                        .into_iter()
+                        .map(|op| self.operation(op))
+                        .collect(),
+                }
+            }
+            Definition::Enum(enum_ptr) => {
+                let enum_def = enum_ptr.borrow();
+                CachedDefinition::Enum {
+                    entity: self.entity(enum_def, &enum_def.attributes, &enum_def.comment),
+                    underlying: enum_def.underlying.as_ref().map(|underlying| self.type_ref(underlying)),
+                    is_unchecked: enum_def.is_unchecked,
+                    enumerators: enum_def.enumerators().into_iter().map(|e| self.enumerator(e)).collect(),
+                }
+            }
+            Definition::CustomType(custom_type_ptr) => {
+                let custom_type = custom_type_ptr.borrow();
+                CachedDefinition::CustomType {
+                    entity: self.entity(custom_type, &custom_type.attributes, &custom_type.comment),
+                }
+            }
+            Definition::TypeAlias(type_alias_ptr) => {
+                let type_alias = type_alias_ptr.borrow();
+                CachedDefinition::TypeAlias {
+                    entity: self.entity(type_alias, &type_alias.attributes, &type_alias.comment),
+                    underlying: self.type_ref(&type_alias.underlying),
+                }
+            }
+            Definition::Constant(constant_ptr) => {
+                let constant = constant_ptr.borrow();
+                CachedDefinition::Constant {
+                    entity: self.entity(constant, &constant.attributes, &constant.comment),
+                    data_type: self.type_ref(&constant.data_type),
+                    value: constant.value.clone(),
+                }
+            }
+        }
+    }
+
+    fn fields(&self, fields: Vec<&Field>) -> Vec<CachedField> {
+        let fields = fields.into_iter().map(|field| CachedField {
+            entity: self.entity(field, &field.attributes, &field.comment),
+            data_type: self.type_ref(&field.data_type),
+            tag: field.tag.as_ref().cloned(),
+            default_value: field.default_value.as_ref().cloned(),
+        });
+        fields.collect()
+    }
+
+    fn operation(&self, operation: &Operation) -> CachedOperation {
+        let throws = match &operation.throws {
+            Throws::None => CachedThrows::None,
+            Throws::Specific(exception) => CachedThrows::Specific(Box::new(self.type_ref(exception))),
+            Throws::AnyException => CachedThrows::AnyException,
+        };
+
+        CachedOperation {
+            entity: self.entity(operation, &operation.attributes, &operation.comment),
+            parameters: operation.parameters().into_iter().map(|p| self.parameter(p)).collect(),
+            return_type: operation
+                .return_members()
+                .into_iter()
+                .map(|p| self.parameter(p))
+                .collect(),
+            throws,
+            is_idempotent: operation.is_idempotent,
+            encoding: operation.encoding,
+        }
+    }
+
+    fn parameter(&self, parameter: &Parameter) -> CachedParameter {
+        CachedParameter {
+            entity: self.entity(parameter, &parameter.attributes, &parameter.comment),
+            data_type: self.type_ref(&parameter.data_type),
+            tag: parameter.tag.as_ref().cloned(),
+            is_streamed: parameter.is_streamed,
+            is_returned: parameter.is_returned,
+        }
+    }
+
+    fn enumerator(&self, enumerator: &Enumerator) -> CachedEnumerator {
+        CachedEnumerator {
+            entity: self.entity(enumerator, &enumerator.attributes, &enumerator.comment),
+            value: enumerator.value.clone(),
+            fields: self.fields(enumerator.fields()),
+        }
+    }
+
+    fn entity(
+        &self,
+        entity: &dyn Entity,
+        attributes: &[WeakPtr<Attribute>],
+        comment: &Option<DocComment>,
+    ) -> CachedEntity {
+        CachedEntity {
+            identifier: entity.raw_identifier().clone(),
+            scope: self.scope(entity.get_raw_scope()),
+            attributes: self.attributes(attributes),
+            comment: comment.as_ref().map(|comment| self.doc_comment(comment)),
+            span: entity.span().clone(),
+        }
+    }
+
+    fn scope(&self, scope: &Scope) -> CachedScope {
+        // Every module an entity can be scoped to was declared in the same file as it.
+        let module = scope.module.as_ref().map(|module_ptr| {
+            let module_def = module_ptr.borrow();
+            let index = self.modules.iter().position(|m| *m == module_def);
+            index.expect("entity is scoped to a module that isn't declared in its file")
+        });
+
+        CachedScope {
+            parser_scope: scope.parser_scope.clone(),
+            module,
+        }
+    }
+
+    fn type_ref<T: Type + ?Sized>(&self, type_ref: &TypeRef<T>) -> CachedTypeRef {
+        let definition = match &type_ref.definition {
+            TypeRefDefinition::Unpatched(identifier) => CachedTypeRefDefinition::Unpatched(identifier.clone()),
+
+            // Only anonymous types are patched during parsing.
+            TypeRefDefinition::Patched(ptr) => match ptr.borrow().concrete_type() {
+                Types::Primitive(primitive) => CachedTypeRefDefinition::Primitive(primitive.kind().to_owned()),
+                Types::Sequence(sequence) => {
+                    CachedTypeRefDefinition::Sequence(Box::new(self.type_ref(&sequence.element_type)))
+                }
+                Types::Dictionary(dictionary) => CachedTypeRefDefinition::Dictionary(
+                    Box::new(self.type_ref(&dictionary.key_type)),
+                    Box::new(self.type_ref(&dictionary.value_type)),
+                ),
+                _ => unreachable!("a type reference to a user defined type was patched during parsing"),
+            },
+        };
+
+        CachedTypeRef {
+            definition,
+            is_optional: type_ref.is_optional,
+            scope: self.scope(&type_ref.scope),
+            attributes: self.attributes(&type_ref.attributes),
+            span: type_ref.span.clone(),
+        }
+    }
+
+    fn attributes(&self, attributes: &[WeakPtr<Attribute>]) -> Vec<CachedAttribute> {
+        let attributes = attributes.iter().map(|attribute_ptr| {
+            let attribute = attribute_ptr.borrow();
+            CachedAttribute {
+                directive: attribute.kind.directive().to_owned(),
+                args: attribute.args.clone(),
+                span: attribute.span.clone(),
+            }
+        });
+        attributes.collect()
+    }
+
+    fn doc_comment(&self, comment: &DocComment) -> CachedDocComment {
+        let section = |message: &Message, span: &Span| CachedSection {
+            message: self.message(message),
+            span: span.clone(),
+        };
+
+        let params = comment.params.iter().map(|param| CachedParamTag {
+            identifier: param.identifier.clone(),
+            message: self.message(&param.message),
+            span: param.span.clone(),
+        });
+        let returns = comment.returns.iter().map(|returns| CachedReturnsTag {
+            identifier: returns.identifier.clone(),
+            message: self.message(&returns.message),
+            span: returns.span.clone(),
+        });
+        let throws = comment.throws.iter().map(|throws| CachedThrowsTag {
+            thrown_type: throws.thrown_type.as_ref().map(unpatched_link),
+            message: self.message(&throws.message),
+            span: throws.span.clone(),
+        });
+        let see = comment.see.iter().map(|see| CachedLink {
+            link: unpatched_link(&see.link),
+            span: see.span.clone(),
+        });
+        let examples = comment.examples.iter().map(|example| CachedCode {
+            code: example.code.clone(),
+            span: example.span.clone(),
+        });
+        let since = comment.since.iter().map(|since| CachedSinceTag {
+            version: since.version.clone(),
+            span: since.span.clone(),
+        });
+
+        CachedDocComment {
+            overview: comment.overview.as_ref().map(|o| section(&o.message, &o.span)),
+            params: params.collect(),
+            returns: returns.collect(),
+            throws: throws.collect(),
+            see: see.collect(),
+            remarks: comment.remarks.iter().map(|r| section(&r.message, &r.span)).collect(),
+            examples: examples.collect(),
+            since: since.collect(),
+            deprecated: comment
+                .deprecated
+                .iter()
+                .map(|d| section(&d.message, &d.span))
+                .collect(),
+            span: comment.span.clone(),
+        }
+    }
+
+    fn message(&self, message: &Message) -> CachedMessage {
+        let components = message.iter().map(|component| match component {
+            MessageComponent::Text(text) => CachedMessageComponent::Text(text.clone()),
+            MessageComponent::Link(link_tag) => CachedMessageComponent::Link(CachedLink {
+                link: unpatched_link(&link_tag.link),
+                span: link_tag.span.clone(),
+            }),
+            MessageComponent::Code(code) => CachedMessageComponent::Code(CachedCode {
+                code: code.code.clone(),
+                span: code.span.clone(),
+            }),
+            MessageComponent::Emphasis(emphasis) => CachedMessageComponent::Emphasis {
+                text: emphasis.text.clone(),
+                is_strong: emphasis.is_strong,
+                span: emphasis.span.clone(),
+            },
+            MessageComponent::List(list) => CachedMessageComponent::List {
+                items: list.items.iter().map(|item| self.message(item)).collect(),
+                span: list.span.clone(),
+            },
+            MessageComponent::CodeBlock(code_block) => CachedMessageComponent::CodeBlock {
+                language: code_block.language.clone(),
+                code: code_block.code.clone(),
+                span: code_block.span.clone(),
+            },
+        });
+        components.collect()
+    }
+}
+
+fn unpatched_link<T: Element + ?Sized>(link: &TypeRefDefinition<T>) -> Identifier {
+    match link {
+        TypeRefDefinition::Unpatched(identifier) => identifier.clone(),
+        TypeRefDefinition::Patched(_) => unreachable!("a doc comment link was patched during parsing"),
+    }
+}
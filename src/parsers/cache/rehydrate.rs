@@ -0,0 +1,603 @@
+// Copyright (c) ZeroC, Inc.
+
+//! Rebuilds the elements of a cached Slice file, mirroring how the parser constructs them (see `parsers::slice::grammar`).
+
+use super::model::*;
+use crate::ast::node::Node;
+use crate::ast::Ast;
+use crate::grammar::*;
+use crate::slice_file::{SliceFile, Span};
+use crate::utils::ptr_util::{OwnedPtr, WeakPtr};
+use crate::{downgrade_as, upcast_weak_as};
+
+/// An entry which can't be rehydrated, because it's inconsistent with itself or with the AST.
+/// The parser never produces such snapshots, so this only happens if an entry was modified after it was written.
+#[derive(Debug)]
+pub struct InvalidEntry;
+
+impl CachedFile {
+    /// Moves the elements stored in this snapshot into the AST, and stores them in the provided file,
+    /// leaving both in the same state as if the file had just been parsed.
+    ///
+    /// The snapshot is validated before anything is moved, so if this returns an error, the AST and file are untouched.
+    pub fn rehydrate(self, file: &mut SliceFile, ast: &mut Ast) -> Result<(), InvalidEntry> {
+        let validator = Validator {
+            ast,
+            module_count: self.modules.len(),
+        };
+        validator.file(&self)?;
+
+        let mut rehydrator = Rehydrator {
+            ast,
+            modules: Vec::new(),
+        };
+        file.mode = self.mode;
+        file.attributes = rehydrator.attributes(self.attributes);
+        file.imports = self.imports;
+
+        // Modules are only added to the AST after the definitions they contain, like in `parsers::parse_file`.
+        let modules = self
+            .modules
+            .into_iter()
+            .map(|m| rehydrator.module(m))
+            .collect::<Vec<_>>();
+        rehydrator.modules = modules.iter().map(OwnedPtr::downgrade).collect();
+        file.contents = self.definitions.into_iter().map(|d| rehydrator.definition(d)).collect();
+        file.modules = modules
+            .into_iter()
+            .map(|m| rehydrator.ast.add_named_element(m))
+            .collect();
+        Ok(())
+    }
+}
+
+/// Checks that a snapshot upholds the invariants which rehydrating it relies on. The parser always upholds these:
+/// - Modules are referred to by the index of a module that's declared in the same file.
+/// - Primitives are referred to by the keyword of a primitive that's defined in the AST.
+/// - Base types and thrown exceptions are unpatched, and enum underlying types are unpatched or primitives.
+struct Validator<'a> {
+    ast: &'a Ast,
+    module_count: usize,
+}
+
+impl Validator<'_> {
+    fn file(&self, file: &CachedFile) -> Result<(), InvalidEntry> {
+        file.definitions
+            .iter()
+            .try_for_each(|definition| self.definition(definition))
+    }
+
+    fn definition(&self, definition: &CachedDefinition) -> Result<(), InvalidEntry> {
+        match definition {
+            CachedDefinition::Struct { entity, fields, .. } => {
+                self.scope(&entity.scope)?;
+                self.fields(fields)
+            }
+            CachedDefinition::Class {
+                entity, base, fields, ..
+            }
+            | CachedDefinition::Exception { entity, base, fields } => {
+                self.scope(&entity.scope)?;
+                base.iter().try_for_each(|base| self.unpatched_type_ref(base))?;
+                self.fields(fields)
+            }
+            CachedDefinition::Interface {
+                entity,
+                bases,
+                operations,
+            } => {
+                self.scope(&entity.scope)?;
+                bases.iter().try_for_each(|base| self.unpatched_type_ref(base))?;
+                operations.iter().try_for_each(|operation| self.operation(operation))
+            }
+            CachedDefinition::Enum {
+                entity,
+                underlying,
+                enumerators,
+                ..
+            } => {
+                self.scope(&entity.scope)?;
+                if let Some(underlying) = underlying {
+                    match &underlying.definition {
+                        CachedTypeRefDefinition::Unpatched(_) | CachedTypeRefDefinition::Primitive(_) => {
+                            self.type_ref(underlying)?;
+                        }
+                        _ => return Err(InvalidEntry),
+                    }
+                }
+                enumerators.iter().try_for_each(|enumerator| {
+                    self.scope(&enumerator.entity.scope)?;
+                    self.fields(&enumerator.fields)
+                })
+            }
+            CachedDefinition::CustomType { entity } => self.scope(&entity.scope),
+            CachedDefinition::TypeAlias { entity, underlying } => {
+                self.scope(&entity.scope)?;
+                self.type_ref(underlying)
+            }
+            CachedDefinition::Constant { entity, data_type, .. } => {
+                self.scope(&entity.scope)?;
+                self.type_ref(data_type)
+            }
+        }
+    }
+
+    fn fields(&self, fields: &[CachedField]) -> Result<(), InvalidEntry> {
+        fields.iter().try_for_each(|field| {
+            self.scope(&field.entity.scope)?;
+            self.type_ref(&field.data_type)
+        })
+    }
+
+    fn operation(&self, operation: &CachedOperation) -> Result<(), InvalidEntry> {
+        self.scope(&operation.entity.scope)?;
+        for parameter in operation.parameters.iter().chain(&operation.return_type) {
+            self.scope(&parameter.entity.scope)?;
+            self.type_ref(&parameter.data_type)?;
+        }
+        match &operation.throws {
+            CachedThrows::Specific(exception) => self.unpatched_type_ref(exception),
+            CachedThrows::None | CachedThrows::AnyException => Ok(()),
+        }
+    }
+
+    /// Checks a type reference which must point to a user defined type, and hence be unpatched.
+    fn unpatched_type_ref(&self, type_ref: &CachedTypeRef) -> Result<(), InvalidEntry> {
+        match &type_ref.definition {
+            CachedTypeRefDefinition::Unpatched(_) => self.type_ref(type_ref),
+            _ => Err(InvalidEntry),
+        }
+    }
+
+    fn type_ref(&self, type_ref: &CachedTypeRef) -> Result<(), InvalidEntry> {
+        self.scope(&type_ref.scope)?;
+        match &type_ref.definition {
+            CachedTypeRefDefinition::Unpatched(_) => Ok(()),
+            CachedTypeRefDefinition::Primitive(kind) => match self.ast.find_node(kind) {
+                Ok(Node::Primitive(_)) => Ok(()),
+                _ => Err(InvalidEntry),
+            },
+            CachedTypeRefDefinition::Sequence(element_type) => self.type_ref(element_type),
+            CachedTypeRefDefinition::Dictionary(key_type, value_type) => {
+                self.type_ref(key_type)?;
+                self.type_ref(value_type)
+            }
+        }
+    }
+
+    fn scope(&self, scope: &CachedScope) -> Result<(), InvalidEntry> {
+        match scope.module {
+            Some(index) if index >= self.module_count => Err(InvalidEntry),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The properties shared by all entities, after they've been rehydrated.
+struct EntityParts {
+    identifier: Identifier,
+    scope: Scope,
+    attributes: Vec<WeakPtr<Attribute>>,
+    comment: Option<DocComment>,
+    span: Span,
+}
+
+/// Rebuilds the elements of a snapshot. The snapshot must have already been checked by a [Validator], which guarantees
+/// that the indexing, lookups, and downcasts performed while rebuilding it can't fail.
+struct Rehydrator<'a> {
+    ast: &'a mut Ast,
+    modules: Vec<WeakPtr<Module>>,
+}
+
+impl Rehydrator<'_> {
+    fn module(&mut self, module_def: CachedModule) -> OwnedPtr<Module> {
+        OwnedPtr::new(Module {
+            identifier: module_def.identifier,
+            attributes: self.attributes(module_def.attributes),
+            comment: module_def.comment.map(|comment| self.doc_comment(comment)),
+            span: module_def.span,
+        })
+    }
+
+    fn definition(&mut self, definition: CachedDefinition) -> Definition {
+        match definition {
+            CachedDefinition::Struct {
+                entity,
+                is_compact,
+                fields,
+            } => {
+                let entity = self.entity(entity);
+                let mut struct_ptr = OwnedPtr::new(Struct {
+                    identifier: entity.identifier,
+                    fields: Vec::new(),
+                    is_compact,
+                    scope: entity.scope,
+                    attributes: entity.attributes,
+                    comment: entity.comment,
+                    span: entity.span,
+                    supported_encodings: None, // Patched by the encoding patcher.
+                });
+                let fields = self.fields(fields, downgrade_as!(struct_ptr, dyn Container<WeakPtr<Field>>));
+                unsafe { struct_ptr.borrow_mut().fields = fields };
+                Definition::Struct(self.ast.add_named_element(struct_ptr))
+            }
+
+            CachedDefinition::Class {
+                entity,
+                compact_id,
+                base,
+                fields,
+            } => {
+                let entity = self.entity(entity);
+                let base = base.map(|base| self.type_ref(base).downcast::<Class>().unwrap());
+                let mut class_ptr = OwnedPtr::new(Class {
+                    identifier: entity.identifier,
+                    fields: Vec::new(),
+                    compact_id,
+                    base,
+                    scope: entity.scope,
+                    attributes: entity.attributes,
+                    comment: entity.comment,
+                    span: entity.span,
+                    supported_encodings: None, // Patched by the encoding patcher.
+                });
+                let fields = self.fields(fields, downgrade_as!(class_ptr, dyn Container<WeakPtr<Field>>));
+                unsafe { class_ptr.borrow_mut().fields = fields };
+                Definition::Class(self.ast.add_named_element(class_ptr))
+            }
+
+            CachedDefinition::Exception { entity, base, fields } => {
+                let entity = self.entity(entity);
+                let base = base.map(|base| self.type_ref(base).downcast::<Exception>().unwrap());
+                let mut exception_ptr = OwnedPtr::new(Exception {
+                    identifier: entity.identifier,
+                    fields: Vec::new(),
+                    base,
+                    scope: entity.scope,
+                    attributes: entity.attributes,
+                    comment: entity.comment,
+                    span: entity.span,
+                    supported_encodings: None, // Patched by the encoding patcher.
+                });
+                let fields = self.fields(fields, downgrade_as!(exception_ptr, dyn Container<WeakPtr<Field>>));
+                unsafe { exception_ptr.borrow_mut().fields = fields };
+                Definition::Exception(self.ast.add_named_element(exception_ptr))
+            }
+
+            CachedDefinition::Interface {
+                entity,
+                bases,
+                operations,
+            } => {
+                let entity = self.entity(entity);
+                let bases = bases
+                    .into_iter()
+                    .map(|base| self.type_ref(base).downcast::<Interface>().unwrap());
+                let bases = bases.collect();
+                let mut interface_ptr = OwnedPtr::new(Interface {
+                    identifier: entity.identifier,
+                    operations: Vec::new(),
+                    bases,
+                    scope: entity.scope,
+                    attributes: entity.attributes,
+                    comment: entity.comment,
+                    span: entity.span,
+                    supported_encodings: None, // Patched by the encoding patcher.
+                });
+                let operations = operations.into_iter().map(|operation| {
+                    let operation_ptr = self.operation(operation, interface_ptr.downgrade());
+                    self.ast.add_named_element(operation_ptr)
+                });
+                let operations = operations.collect();
+                unsafe { interface_ptr.borrow_mut().operations = operations };
+                Definition::Interface(self.ast.add_named_element(interface_ptr))
+            }
+
+            CachedDefinition::Enum {
+                entity,
+                underlying,
+                is_unchecked,
+                enumerators,
+            } => {
+                let entity = self.entity(entity);
+                let underlying = underlying.map(|u| self.type_ref(u).downcast::<Primitive>().unwrap());
+                let mut enum_ptr = OwnedPtr::new(Enum {
+                    identifier: entity.identifier,
+                    enumerators: Vec::new(),
+                    underlying,
+                    is_unchecked,
+                    scope: entity.scope,
+                    attributes: entity.attributes,
+                    comment: entity.comment,
+                    span: entity.span,
+                    supported_encodings: None, // Patched by the encoding patcher.
+                });
+                let enumerators = enumerators.into_iter().map(|enumerator| {
+                    let enumerator_ptr = self.enumerator(enumerator, enum_ptr.downgrade());
+                    self.ast.add_named_element(enumerator_ptr)
+                });
+                let enumerators = enumerators.collect();
+                unsafe { enum_ptr.borrow_mut().enumerators = enumerators };
+                Definition::Enum(self.ast.add_named_element(enum_ptr))
+            }
+
+            CachedDefinition::CustomType { entity } => {
+                let entity = self.entity(entity);
+                let custom_type_ptr = OwnedPtr::new(CustomType {
+                    identifier: entity.identifier,
+                    scope: entity.scope,
+                    attributes: entity.attributes,
+                    comment: entity.comment,
+                    span: entity.span,
+                    supported_encodings: None, // Patched by the encoding patcher.
+                });
+                Definition::CustomType(self.ast.add_named_element(custom_type_ptr))
+            }
+
+            CachedDefinition::TypeAlias { entity, underlying } => {
+                let entity = self.entity(entity);
+                let type_alias_ptr = OwnedPtr::new(TypeAlias {
+                    identifier: entity.identifier,
+                    underlying: self.type_ref(underlying),
+                    scope: entity.scope,
+                    attributes: entity.attributes,
+                    comment: entity.comment,
+                    span: entity.span,
+                    supported_encodings: None, // Patched by the encoding patcher.
+                });
+                Definition::TypeAlias(self.ast.add_named_element(type_alias_ptr))
+            }
+
+            CachedDefinition::Constant {
+                entity,
+                data_type,
+                value,
+            } => {
+                let entity = self.entity(entity);
+                let constant_ptr = OwnedPtr::new(Constant {
+                    identifier: entity.identifier,
+                    data_type: self.type_ref(data_type),
+                    value,
+                    scope: entity.scope,
+                    attributes: entity.attributes,
+                    comment: entity.comment,
+                    span: entity.span,
+                });
+                Definition::Constant(self.ast.add_named_element(constant_ptr))
+            }
+        }
+    }
+
+    fn fields(
+        &mut self,
+        fields: Vec<CachedField>,
+        parent: WeakPtr<dyn Container<WeakPtr<Field>>>,
+    ) -> Vec<WeakPtr<Field>> {
+        let fields = fields.into_iter().map(|field| {
+            let entity = self.entity(field.entity);
+            let field_ptr = OwnedPtr::new(Field {
+                identifier: entity.identifier,
+                data_type: self.type_ref(field.data_type),
+                tag: field.tag,
+                default_value: field.default_value,
+                parent: parent.clone(),
+                scope: entity.scope,
+                attributes: entity.attributes,
+                comment: entity.comment,
+                span: entity.span,
+            });
+            self.ast.add_named_element(field_ptr)
+        });
+        fields.collect()
+    }
+
+    fn operation(&mut self, operation: CachedOperation, parent: WeakPtr<Interface>) -> OwnedPtr<Operation> {
+        let entity = self.entity(operation.entity);
+        let throws = match operation.throws {
+            CachedThrows::None => Throws::None,
+            CachedThrows::Specific(exception) => Throws::Specific(self.type_ref(*exception).downcast().unwrap()),
+            CachedThrows::AnyException => Throws::AnyException,
+        };
+
+        let mut operation_ptr = OwnedPtr::new(Operation {
+            identifier: entity.identifier,
+            return_type: Vec::new(),
+            parameters: Vec::new(),
+            throws,
+            is_idempotent: operation.is_idempotent,
+            encoding: operation.encoding,
+            parent,
+            scope: entity.scope,
+            attributes: entity.attributes,
+            comment: entity.comment,
+            span: entity.span,
+        });
+
+        let parameters = self.parameters(operation.parameters, operation_ptr.downgrade());
+        let return_type = self.parameters(operation.return_type, operation_ptr.downgrade());
+        unsafe {
+            operation_ptr.borrow_mut().parameters = parameters;
+            operation_ptr.borrow_mut().return_type = return_type;
+        }
+        operation_ptr
+    }
+
+    fn parameters(&mut self, parameters: Vec<CachedParameter>, parent: WeakPtr<Operation>) -> Vec<WeakPtr<Parameter>> {
+        let parameters = parameters.into_iter().map(|parameter| {
+            let entity = self.entity(parameter.entity);
+            let parameter_ptr = OwnedPtr::new(Parameter {
+                identifier: entity.identifier,
+                data_type: self.type_ref(parameter.data_type),
+                tag: parameter.tag,
+                is_streamed: parameter.is_streamed,
+                is_returned: parameter.is_returned,
+                parent: parent.clone(),
+                scope: entity.scope,
+                attributes: entity.attributes,
+                comment: entity.comment,
+                span: entity.span,
+            });
+            self.ast.add_named_element(parameter_ptr)
+        });
+        parameters.collect()
+    }
+
+    fn enumerator(&mut self, enumerator: CachedEnumerator, parent: WeakPtr<Enum>) -> OwnedPtr<Enumerator> {
+        let entity = self.entity(enumerator.entity);
+        let mut enumerator_ptr = OwnedPtr::new(Enumerator {
+            identifier: entity.identifier,
+            value: enumerator.value,
+            fields: Vec::new(),
+            parent,
+            scope: entity.scope,
+            attributes: entity.attributes,
+            comment: entity.comment,
+            span: entity.span,
+        });
+
+        let fields = self.fields(
+            enumerator.fields,
+            downgrade_as!(enumerator_ptr, dyn Container<WeakPtr<Field>>),
+        );
+        unsafe { enumerator_ptr.borrow_mut().fields = fields };
+        enumerator_ptr
+    }
+
+    fn entity(&mut self, entity: CachedEntity) -> EntityParts {
+        EntityParts {
+            identifier: entity.identifier,
+            scope: self.scope(entity.scope),
+            attributes: self.attributes(entity.attributes),
+            comment: entity.comment.map(|comment| self.doc_comment(comment)),
+            span: entity.span,
+        }
+    }
+
+    fn scope(&self, scope: CachedScope) -> Scope {
+        Scope {
+            parser_scope: scope.parser_scope,
+            module: scope.module.map(|index| self.modules[index].clone()),
+        }
+    }
+
+    fn type_ref(&mut self, type_ref: CachedTypeRef) -> TypeRef {
+        let definition = match type_ref.definition {
+            CachedTypeRefDefinition::Unpatched(identifier) => TypeRefDefinition::Unpatched(identifier),
+            CachedTypeRefDefinition::Primitive(kind) => {
+                // This unwrap is safe because the validator checked that this primitive is defined in the AST.
+                let primitive_ptr: WeakPtr<Primitive> = self.ast.find_node(&kind).unwrap().try_into().unwrap();
+                TypeRefDefinition::Patched(upcast_weak_as!(primitive_ptr, dyn Type))
+            }
+            CachedTypeRefDefinition::Sequence(element_type) => {
+                let sequence = Sequence {
+                    element_type: self.type_ref(*element_type),
+                };
+                let sequence_ptr = self.ast.add_element(OwnedPtr::new(sequence));
+                TypeRefDefinition::Patched(upcast_weak_as!(sequence_ptr, dyn Type))
+            }
+            CachedTypeRefDefinition::Dictionary(key_type, value_type) => {
+                let dictionary = Dictionary {
+                    key_type: self.type_ref(*key_type),
+                    value_type: self.type_ref(*value_type),
+                };
+                let dictionary_ptr = self.ast.add_element(OwnedPtr::new(dictionary));
+                TypeRefDefinition::Patched(upcast_weak_as!(dictionary_ptr, dyn Type))
+            }
+        };
+
+        TypeRef {
+            definition,
+            is_optional: type_ref.is_optional,
+            scope: self.scope(type_ref.scope),
+            attributes: self.attributes(type_ref.attributes),
+            span: type_ref.span,
+        }
+    }
+
+    fn attributes(&mut self, attributes: Vec<CachedAttribute>) -> Vec<WeakPtr<Attribute>> {
+        let attributes = attributes.into_iter().map(|attribute| {
+            let attribute = Attribute::new(attribute.directive, attribute.args, attribute.span);
+            self.ast.add_element(OwnedPtr::new(attribute))
+        });
+        attributes.collect()
+    }
+
+    fn doc_comment(&self, comment: CachedDocComment) -> DocComment {
+        let params = comment.params.into_iter().map(|param| ParamTag {
+            identifier: param.identifier,
+            message: self.message(param.message),
+            span: param.span,
+        });
+        let returns = comment.returns.into_iter().map(|returns| ReturnsTag {
+            identifier: returns.identifier,
+            message: self.message(returns.message),
+            span: returns.span,
+        });
+        let throws = comment.throws.into_iter().map(|throws| ThrowsTag {
+            thrown_type: throws.thrown_type.map(TypeRefDefinition::Unpatched),
+            message: self.message(throws.message),
+            span: throws.span,
+        });
+        let see = comment.see.into_iter().map(|see| SeeTag {
+            link: TypeRefDefinition::Unpatched(see.link),
+            span: see.span,
+        });
+        let remarks = comment.remarks.into_iter().map(|remarks| RemarksTag {
+            message: self.message(remarks.message),
+            span: remarks.span,
+        });
+        let examples = comment.examples.into_iter().map(|example| ExampleTag {
+            code: example.code,
+            span: example.span,
+        });
+        let since = comment.since.into_iter().map(|since| SinceTag {
+            version: since.version,
+            span: since.span,
+        });
+        let deprecated = comment.deprecated.into_iter().map(|deprecated| DeprecatedTag {
+            message: self.message(deprecated.message),
+            span: deprecated.span,
+        });
+
+        DocComment {
+            overview: comment.overview.map(|overview| Overview {
+                message: self.message(overview.message),
+                span: overview.span,
+            }),
+            params: params.collect(),
+            returns: returns.collect(),
+            throws: throws.collect(),
+            see: see.collect(),
+            remarks: remarks.collect(),
+            examples: examples.collect(),
+            since: since.collect(),
+            deprecated: deprecated.collect(),
+            span: comment.span,
+        }
+    }
+
+    fn message(&self, message: CachedMessage) -> Message {
+        let components = message.into_iter().map(|component| match component {
+            CachedMessageComponent::Text(text) => MessageComponent::Text(text),
+            CachedMessageComponent::Link(link) => MessageComponent::Link(LinkTag {
+                link: TypeRefDefinition::Unpatched(link.link),
+                span: link.span,
+            }),
+            CachedMessageComponent::Code(code) => MessageComponent::Code(InlineCode {
+                code: code.code,
+                span: code.span,
+            }),
+            CachedMessageComponent::Emphasis { text, is_strong, span } => {
+                MessageComponent::Emphasis(Emphasis { text, is_strong, span })
+            }
+            CachedMessageComponent::List { items, span } => MessageComponent::List(BulletList {
+                items: items.into_iter().map(|item| self.message(item)).collect(),
+                span,
+            }),
+            CachedMessageComponent::CodeBlock { language, code, span } => {
+                MessageComponent::CodeBlock(FencedCodeBlock { language, code, span })
+            }
+        });
+        components.collect()
+    }
+}
@@ -5,7 +5,7 @@
 //! While many of these functions could be written directly into the parser rules, we implement them here instead, to
 //! keep the rules focused on grammar instead of implementation details, making the grammar easier to read and modify.
 
-use crate::grammar::{DocComment, Message, MessageComponent, Overview};
+use crate::grammar::{BulletList, DocComment, FencedCodeBlock, Message, MessageComponent, Overview};
 use crate::slice_file::{Location, Span};
 use lalrpop_util::lalrpop_mod;
 
@@ -26,7 +26,19 @@ macro_rules! append_tag_to_comment {
     }};
 }
 
-pub(self) use append_tag_to_comment; // To let LALRPOP use the macro.
+use append_tag_to_comment; // To let LALRPOP use the macro.
+
+/// A single line of a multi-line message, which is grouped together with the other lines by `sanitize_message_lines`.
+enum MessageLine<'a> {
+    /// A line of text. `None` means the line existed but was empty.
+    Text(Option<Message>),
+
+    /// A line that starts with a list marker, along with the span of the list item.
+    ListItem(Option<Message>, Span),
+
+    /// A line that opens or closes a fenced code block, along with any language specified after the fence.
+    CodeFence(&'a str, Span),
+}
 
 // Grammar Rule Functions
 
@@ -48,6 +60,10 @@ fn create_doc_comment(overview: Option<Overview>, start: Location, file: &str) -
         returns: Vec::new(),
         throws: Vec::new(),
         see: Vec::new(),
+        remarks: Vec::new(),
+        examples: Vec::new(),
+        since: Vec::new(),
+        deprecated: Vec::new(),
         span,
     }
 }
@@ -82,15 +98,34 @@ fn construct_section_message(inline_message: Option<Message>, message_lines: Opt
     message_lines
 }
 
-/// Removes any common leading whitespace from the provided lines and returns the result.
-/// Each element in the vector represents one line of the message.
-/// `None` means the line existed but was empty, `Some(message)` means the line had a message.
-fn sanitize_message_lines(lines: Vec<Option<Message>>) -> Message {
-    // First compute the amount of leading whitespace that is common to every line.
+/// Joins the text of a verbatim message back into a single string, removing any trailing newlines.
+/// Verbatim messages are lexed without checking for inline tags or markup, so they only contain text components.
+fn construct_verbatim_text(message: Message) -> String {
+    let mut text = message
+        .into_iter()
+        .map(|component| match component {
+            MessageComponent::Text(text) => text,
+            _ => unreachable!("encountered markup in verbatim message"),
+        })
+        .collect::<String>();
+    text.truncate(text.trim_end_matches('\n').len());
+    text
+}
+
+/// Removes any surrounding whitespace from a version string, and an optional leading ':' (ex: `@since: 1.0`).
+fn construct_version_string(text: &str) -> String {
+    let text = text.trim();
+    text.strip_prefix(':').unwrap_or(text).trim_start().to_owned()
+}
+
+/// Removes any common leading whitespace from the provided lines, then combines them into a single message.
+/// Consecutive list items are grouped into a single list, and lines between code fences are grouped into code blocks.
+fn sanitize_message_lines(lines: Vec<MessageLine>) -> Message {
+    // First compute the amount of leading whitespace that is common to every line of text.
     let mut common_leading_whitespace = usize::MAX;
     for line in &lines {
-        // We only check lines that have a message on them (eg: they're non-empty).
-        if let Some(message) = &line {
+        // We only check lines that have text on them (eg: they're non-empty and aren't list items or code fences).
+        if let MessageLine::Text(Some(message)) = &line {
             // To check the start of the line, we check the first message component.
             // It's safe to unwrap because we know the line was non-empty in this block.
             match message.first().unwrap() {
@@ -100,8 +135,8 @@ fn sanitize_message_lines(lines: Vec<Option<Message>>) -> Message {
                     let whitespace_index = text.find(|c: char| !c.is_whitespace()).unwrap_or_default();
                     common_leading_whitespace = std::cmp::min(whitespace_index, common_leading_whitespace);
                 }
-                MessageComponent::Link(_) => {
-                    // If a line starts with a link, the common leading whitespace must be 0.
+                _ => {
+                    // If a line starts with a link or markup, the common leading whitespace must be 0.
                     // We set this, then exit the loop, since the loop won't find less than 0 whitespace characters.
                     common_leading_whitespace = 0;
                     break;
@@ -110,21 +145,72 @@ fn sanitize_message_lines(lines: Vec<Option<Message>>) -> Message {
         }
     }
 
-    // Now that we know the common leading whitespace, we iterate through the lines again and remove the whitespace.
-    lines
-        .into_iter()
-        .flat_map(|line| match line {
-            // If the message had text, we remove the common leading whitespace and append a newline at the end.
-            Some(mut message) => {
-                if let MessageComponent::Text(text) = message.first_mut().unwrap() {
-                    text.replace_range(..common_leading_whitespace, "");
-                }
+    // Helper function that removes the common leading whitespace from a line of text.
+    let remove_leading_whitespace = |message: &mut Message| {
+        if let MessageComponent::Text(text) = message.first_mut().unwrap() {
+            text.replace_range(..common_leading_whitespace, "");
+        }
+    };
+
+    // Now that we know the common leading whitespace, we iterate through the lines again to construct the message.
+    let mut message = Message::new();
+    let mut lines = lines.into_iter();
+    while let Some(line) = lines.next() {
+        match line {
+            // If the line had text, we remove the common leading whitespace and append a newline at the end.
+            MessageLine::Text(Some(mut line_message)) => {
+                remove_leading_whitespace(&mut line_message);
+                message.append(&mut line_message);
                 message.push(MessageComponent::Text("\n".to_owned()));
-                message
             }
 
-            // If the line was empty, we create a new message that only contains a newline character.
-            None => vec![MessageComponent::Text("\n".to_owned())],
-        })
-        .collect()
+            // If the line was empty, we only append a newline character.
+            MessageLine::Text(None) => message.push(MessageComponent::Text("\n".to_owned())),
+
+            // If the line was a list item, we add it to the list directly before it, or start a new list otherwise.
+            MessageLine::ListItem(item, span) => {
+                let mut item = item.unwrap_or_default();
+                if let Some(MessageComponent::Text(text)) = item.first_mut() {
+                    *text = text.trim_start().to_owned();
+                }
+
+                match message.last_mut() {
+                    Some(MessageComponent::List(list)) => {
+                        list.span.end = span.end;
+                        list.items.push(item);
+                    }
+                    _ => message.push(MessageComponent::List(BulletList {
+                        items: vec![item],
+                        span,
+                    })),
+                }
+            }
+
+            // If the line opened a code block, every line up until the closing fence is part of the code block.
+            // The lexer guarantees that code blocks only contain text. If the closing fence is missing (which the lexer
+            // reports), the code block extends to the end of the message.
+            MessageLine::CodeFence(language, mut span) => {
+                let mut code_lines = Vec::new();
+                for line in lines.by_ref() {
+                    match line {
+                        MessageLine::CodeFence(_, closing_span) => {
+                            span.end = closing_span.end;
+                            break;
+                        }
+                        MessageLine::Text(Some(mut line_message)) => {
+                            remove_leading_whitespace(&mut line_message);
+                            code_lines.push(construct_verbatim_text(line_message));
+                        }
+                        MessageLine::Text(None) => code_lines.push(String::new()),
+                        MessageLine::ListItem(..) => unreachable!("encountered list item in code block"),
+                    }
+                }
+
+                let language = (!language.is_empty()).then(|| language.to_owned());
+                let code = code_lines.join("\n");
+                message.push(MessageComponent::CodeBlock(FencedCodeBlock { language, code, span }));
+            }
+        }
+    }
+    message
 }
@@ -34,6 +34,21 @@ pub struct Lexer<'input> {
 
     /// The current mode of the lexer; controls how the input is tokenized in a context-dependent manner.
     mode: LexerMode,
+
+    /// Whether the lexer is lexing the body of an `@example` tag. Examples are verbatim blocks of code, so while this is
+    /// true, the lexer doesn't check for inline tags. This is reset when the lexer reaches the next block tag.
+    is_verbatim: bool,
+
+    /// Whether the lexer is at the start of a message line, where it checks for list markers and code fences.
+    is_at_line_start: bool,
+
+    /// The location of the fence that opened the fenced code block currently being lexed, if there is one.
+    /// Like examples, the contents of code blocks are verbatim, so the lexer doesn't check them for inline tags.
+    code_block_start: Option<(Location, Location)>,
+
+    /// Errors that the lexer recovered from, by treating unterminated markup as plain text.
+    /// These are still reported, but unlike the errors returned by the lexer, they don't stop the comment from parsing.
+    pub recovered_errors: Vec<Error<'input>>,
 }
 
 impl<'input> Lexer<'input> {
@@ -50,6 +65,10 @@ impl<'input> Lexer<'input> {
             position: 0,
             cursor: Location::default(),
             mode: LexerMode::Message,
+            is_verbatim: false,
+            is_at_line_start: false,
+            code_block_start: None,
+            recovered_errors: Vec::new(),
         };
         lexer.switch_to_next_line(first_line, first_span); // Actually initialize the lexer.
         lexer
@@ -65,10 +84,14 @@ impl<'input> Lexer<'input> {
 
         // If the first non-whitespace character on this line is '@', then this line starts a new tag, and we put the
         // lexer in `BlockTag` mode accordingly. Otherwise, we put the lexer in its 'default' `Message` mode instead.
-        if self.current_line.trim_start().starts_with('@') {
+        // Lines inside of fenced code blocks are always lexed as messages, even if they start with '@'.
+        if self.code_block_start.is_none() && self.current_line.trim_start().starts_with('@') {
             self.mode = LexerMode::BlockTag;
+            self.is_verbatim = false;
+            self.is_at_line_start = false;
         } else {
             self.mode = LexerMode::Message;
+            self.is_at_line_start = true;
         }
     }
 
@@ -124,6 +147,10 @@ impl<'input> Lexer<'input> {
             "throws" => Ok((start_location, TokenKind::ThrowsKeyword, self.cursor)),
             "see" => Ok((start_location, TokenKind::SeeKeyword, self.cursor)),
             "link" => Ok((start_location, TokenKind::LinkKeyword, self.cursor)),
+            "remarks" => Ok((start_location, TokenKind::RemarksKeyword, self.cursor)),
+            "example" => Ok((start_location, TokenKind::ExampleKeyword, self.cursor)),
+            "since" => Ok((start_location, TokenKind::SinceKeyword, self.cursor)),
+            "deprecated" => Ok((start_location, TokenKind::DeprecatedKeyword, self.cursor)),
             "" => Err((start_location, ErrorKind::MissingTag, self.cursor)),
             tag => Err((start_location, ErrorKind::UnknownTag { tag }, self.cursor)),
         };
@@ -136,7 +163,11 @@ impl<'input> Lexer<'input> {
                 TokenKind::ParamKeyword
                 | TokenKind::ReturnsKeyword
                 | TokenKind::ThrowsKeyword
-                | TokenKind::SeeKeyword => !is_inline,
+                | TokenKind::SeeKeyword
+                | TokenKind::RemarksKeyword
+                | TokenKind::ExampleKeyword
+                | TokenKind::SinceKeyword
+                | TokenKind::DeprecatedKeyword => !is_inline,
 
                 // These tags are only valid inline.
                 TokenKind::LinkKeyword => is_inline,
@@ -149,19 +180,182 @@ impl<'input> Lexer<'input> {
             }
         }
 
+        match &token {
+            // The body of an example is verbatim code, so we stop checking for inline tags until the next block tag.
+            Ok((_, TokenKind::ExampleKeyword, _)) => self.is_verbatim = true,
+
+            // The rest of a since tag's line is its version string, which is lexed as raw text.
+            Ok((_, TokenKind::SinceKeyword, _)) => self.mode = LexerMode::Message,
+
+            _ => {}
+        }
+
         // If all the checks were fine, we return the token here.
         token
     }
 
+    /// Consumes characters from the buffer until the provided delimiter is reached, then consumes the delimiter.
+    /// Returns the text that came before the delimiter, or `None` if the delimiter doesn't appear in the buffer.
+    /// If `None` is returned, nothing was consumed from the buffer.
+    fn read_until_delimiter(&mut self, delimiter: &str) -> Option<&'input str> {
+        let start_position = self.position;
+        let length = self.current_line[start_position..].find(delimiter)?;
+
+        self.advance_by(length + delimiter.len()); // Consume the characters up to, and including, the delimiter.
+        Some(&self.current_line[start_position..start_position + length])
+    }
+
+    /// Returns the emphasis delimiter ("*" or "**") if the lexer is at the start of an emphasized span of text.
+    /// Emphasis must open with 1 or 2 asterisks at the start of a word, meaning they're immediately followed by an
+    /// alphanumeric character, and aren't immediately preceded by one. So `*.slice` and `a * b` are just text.
+    fn peek_emphasis_delimiter(&self) -> Option<&'static str> {
+        let remaining = &self.current_line[self.position..];
+        let text = remaining.trim_start_matches('*');
+
+        let is_preceded_by_alphanumeric = self.current_line[..self.position]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_alphanumeric);
+        let is_followed_by_word = text.starts_with(char::is_alphanumeric);
+        if is_preceded_by_alphanumeric || !is_followed_by_word {
+            return None;
+        }
+
+        match remaining.len() - text.len() {
+            1 => Some("*"),
+            2 => Some("**"),
+            _ => None,
+        }
+    }
+
+    /// Returns the length of the emphasized text if the provided delimiter closes it later on the current line.
+    /// Closing delimiters must be at the end of a word, meaning they're immediately preceded by a non-whitespace
+    /// character, and aren't immediately followed by an alphanumeric character.
+    fn find_emphasis_end(&self, delimiter: &str) -> Option<usize> {
+        let remaining = &self.current_line[self.position..];
+        remaining
+            .match_indices(delimiter)
+            .map(|(index, _)| index)
+            .find(|&index| {
+                let is_preceded_by_text = remaining[..index]
+                    .chars()
+                    .next_back()
+                    .is_some_and(|c| !c.is_whitespace());
+                let is_followed_by_alphanumeric = remaining[index + delimiter.len()..]
+                    .chars()
+                    .next()
+                    .is_some_and(char::is_alphanumeric);
+                is_preceded_by_text && !is_followed_by_alphanumeric
+            })
+    }
+
+    /// Consumes the provided number of bytes from the buffer.
+    fn advance_by(&mut self, length: usize) {
+        let end_position = self.position + length;
+        while self.position < end_position {
+            self.advance_buffer();
+        }
+    }
+
+    /// Checks whether the current line is a code fence or list item, and if so, returns the corresponding token.
+    /// This function should only be called when the lexer is at the start of a line in `Message` mode.
+    fn lex_line_start(&mut self) -> Option<Token<'input>> {
+        let line = self.current_line.trim_start();
+
+        // Check if this line is a code fence. Fences are only checked for at the start of a line, and the entire line
+        // is consumed as part of the fence. If we're not already in a code block, this starts one, otherwise it ends it.
+        if line.starts_with("```") {
+            self.skip_whitespace();
+            let start_location = self.cursor;
+            while self.buffer.peek().is_some() {
+                self.advance_buffer(); // Consume the character.
+            }
+
+            let end_location = self.cursor;
+            if self.code_block_start.take().is_none() {
+                self.code_block_start = Some((start_location, end_location));
+            }
+            let language = line.trim_start_matches('`').trim();
+            return Some((start_location, TokenKind::CodeFence(language), end_location));
+        }
+
+        // The contents of code blocks are verbatim, so we don't check them for list items.
+        if self.code_block_start.is_some() {
+            return None;
+        }
+
+        // Check if this line is a list item, ie. that it starts with '-' or '*' followed by whitespace.
+        let mut chars = line.chars();
+        if matches!(chars.next(), Some('-' | '*')) && chars.next().is_some_and(char::is_whitespace) {
+            self.skip_whitespace();
+            let start_location = self.cursor;
+            self.advance_buffer(); // Consume the bullet.
+            return Some((start_location, TokenKind::ListMarker, self.cursor));
+        }
+
+        None
+    }
+
     /// Reads and returns a token from the buffer while the lexer is in `Message` mode.
+    /// If the lexer is at the start of a line, this function first checks for list markers and code fences.
+    /// If the first character in the buffer is a '`' or an emphasis delimiter, this returns a code span or emphasis
+    /// token respectively, or an error if the span isn't closed before the end of the line.
     /// If the first character in the buffer is a '{', this function checks if it's the start of an inline tag.
     /// If it is, this returns a '{' token and switches the lexer to `InlineTag` mode.
-    /// Otherwise, this reads raw text from the buffer and returns a `Text` token. No errors are possible in this
-    /// function, and since it's only called when the buffer is non-empty, it always returns something.
-    fn lex_message(&mut self) -> Token<'input> {
+    /// Otherwise, this reads raw text from the buffer and returns a `Text` token.
+    fn lex_message(&mut self) -> LexerResult<'input> {
         let start_location = self.cursor;
         let start_position = self.position;
 
+        if !self.is_verbatim && std::mem::take(&mut self.is_at_line_start) {
+            if let Some(token) = self.lex_line_start() {
+                return Ok(token);
+            }
+        }
+
+        // Verbatim text can't contain inline tags or markup, so we return the rest of the line as-is.
+        if self.is_verbatim || self.code_block_start.is_some() {
+            while self.buffer.peek().is_some() {
+                self.advance_buffer(); // Consume the character.
+            }
+            let text = &self.current_line[start_position..self.position];
+            return Ok((start_location, TokenKind::Text(text), self.cursor));
+        }
+
+        // Check for the start of an inline code span.
+        // If the code span isn't closed on this line, we report an error, and treat the '`' as plain text.
+        if matches!(self.buffer.peek(), Some('`')) {
+            self.advance_buffer(); // Consume the opening '`' character.
+            if let Some(code) = self.read_until_delimiter("`") {
+                return Ok((start_location, TokenKind::CodeSpan(code), self.cursor));
+            }
+            let error = (start_location, ErrorKind::UnterminatedCodeSpan, self.cursor);
+            self.recovered_errors.push(error);
+            return Ok((start_location, TokenKind::Text("`"), self.cursor));
+        }
+
+        // Check for the start of an emphasized span of text.
+        // If the emphasis isn't closed on this line, we report an error, and treat the delimiter as plain text.
+        if let Some(delimiter) = self.peek_emphasis_delimiter() {
+            self.advance_by(delimiter.len()); // Consume the opening delimiter.
+            let Some(length) = self.find_emphasis_end(delimiter) else {
+                let error = (
+                    start_location,
+                    ErrorKind::UnterminatedEmphasis { delimiter },
+                    self.cursor,
+                );
+                self.recovered_errors.push(error);
+                return Ok((start_location, TokenKind::Text(delimiter), self.cursor));
+            };
+
+            let text = &self.current_line[self.position..self.position + length];
+            self.advance_by(length + delimiter.len()); // Consume the text and the closing delimiter.
+            return match delimiter {
+                "**" => Ok((start_location, TokenKind::StrongEmphasis(text), self.cursor)),
+                _ => Ok((start_location, TokenKind::Emphasis(text), self.cursor)),
+            };
+        }
+
         // Check for the start of an inline tag. This is a '{' token followed by a '@' token (possibly separated by
         // whitespace). If both are present, we switch to `InlineTag` mode and return the '{' we consumed.
         // Otherwise, we fall through into the rest of the function which returns a normal `Text` token.
@@ -171,18 +365,22 @@ impl<'input> Lexer<'input> {
 
             if matches!(self.buffer.peek(), Some('@')) {
                 self.mode = LexerMode::InlineTag;
-                return (start_location, TokenKind::LeftBrace, self.cursor);
+                return Ok((start_location, TokenKind::LeftBrace, self.cursor));
             }
         }
 
-        // Loop while the next character in the buffer is not '{'.
-        while matches!(self.buffer.peek(), Some(c) if *c != '{') {
-            self.advance_buffer(); // Consume the character.
+        // Loop until we hit the end of the buffer, or something which could start an inline tag or markup.
+        loop {
+            match self.buffer.peek().copied() {
+                None | Some('{' | '`') => break,
+                Some('*') if self.peek_emphasis_delimiter().is_some() => break,
+                _ => self.advance_buffer(), // Consume the character.
+            }
         }
 
         // Return the text.
         let text = &self.current_line[start_position..self.position];
-        (start_location, TokenKind::Text(text), self.cursor)
+        Ok((start_location, TokenKind::Text(text), self.cursor))
     }
 
     /// Attempts to read and return a token from the buffer while the lexer is in `BlockTag` or `InlineTag` mode.
@@ -255,7 +453,7 @@ impl<'input> Iterator for Lexer<'input> {
         while self.buffer.peek().is_some() {
             let item = match self.mode {
                 LexerMode::BlockTag | LexerMode::InlineTag => self.lex_tag_component(),
-                LexerMode::Message => Some(Ok(self.lex_message())),
+                LexerMode::Message => Some(self.lex_message()),
                 _ => unreachable!("comment lexer finished with a non-empty buffer!"),
             };
             // If the lexer lexed a token or encountered an error, return it.
@@ -282,6 +480,13 @@ impl<'input> Iterator for Lexer<'input> {
                     self.switch_to_next_line(next_line, next_span);
                 } else {
                     self.mode = LexerMode::Finished;
+
+                    // If the comment ended in the middle of a fenced code block, this means there was no closing fence.
+                    // We report an error, but the rest of the comment is still treated as part of the code block.
+                    if let Some((start, end)) = self.code_block_start.take() {
+                        self.recovered_errors
+                            .push((start, ErrorKind::UnterminatedCodeBlock, end));
+                    }
                 }
                 Some(Ok(newline_token))
             }
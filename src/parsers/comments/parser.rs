@@ -4,6 +4,7 @@ use super::super::common::{has_errors, ParserResult};
 use super::construct_lint_from;
 use super::grammar::lalrpop;
 use super::lexer::Lexer;
+use super::ParseError;
 use crate::diagnostics::Diagnostic;
 use crate::grammar::DocComment;
 use crate::slice_file::Span;
@@ -13,7 +14,16 @@ macro_rules! implement_parse_function {
     ($function_name:ident, $underlying_parser:ident, $return_type:ty $(,)?) => {
         #[allow(clippy::result_unit_err)]
         pub fn $function_name(mut self, input: Vec<(&str, Span)>) -> ParserResult<$return_type> {
-            match lalrpop::$underlying_parser::new().parse(&mut self, Lexer::new(input)) {
+            let mut lexer = Lexer::new(input);
+            let result = lalrpop::$underlying_parser::new().parse(&mut self, &mut lexer);
+
+            // Report any errors the lexer recovered from. These don't stop the comment from being parsed.
+            for error in lexer.recovered_errors {
+                let lint = construct_lint_from(ParseError::User { error }, self.file_name).set_scope(self.identifier);
+                self.diagnostics.push(lint);
+            }
+
+            match result {
                 Err(parse_error) => {
                     let lint = construct_lint_from(parse_error, self.file_name).set_scope(self.identifier);
                     self.diagnostics.push(lint);
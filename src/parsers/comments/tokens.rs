@@ -19,14 +19,33 @@ pub enum TokenKind<'input> {
     /// lines that continue another section, or after a ':' character on lines that start a new section.
     Text(&'input str),
 
+    /// The contents of an inline code span, without its surrounding backticks. Ex: `` `foo` `` is lexed as "foo".
+    CodeSpan(&'input str),
+
+    /// The contents of an emphasized span of text, without its surrounding asterisks. Ex: `*foo*` is lexed as "foo".
+    Emphasis(&'input str),
+
+    /// The contents of a strongly emphasized span of text. Ex: `**foo**` is lexed as "foo".
+    StrongEmphasis(&'input str),
+
+    /// A bullet at the start of a line, which marks the line as a list item. Either "- " or "* ".
+    ListMarker,
+
+    /// A line which opens or closes a fenced code block. Holds any text following the fence (the code's language).
+    CodeFence(&'input str), // "```"
+
     Newline, // "\n"
 
     // Tag keywords
-    ParamKeyword,   // "@param"
-    ReturnsKeyword, // "@returns"
-    ThrowsKeyword,  // "@throws"
-    SeeKeyword,     // "@see"
-    LinkKeyword,    // "@link"
+    ParamKeyword,      // "@param"
+    ReturnsKeyword,    // "@returns"
+    ThrowsKeyword,     // "@throws"
+    SeeKeyword,        // "@see"
+    LinkKeyword,       // "@link"
+    RemarksKeyword,    // "@remarks"
+    ExampleKeyword,    // "@example"
+    SinceKeyword,      // "@since"
+    DeprecatedKeyword, // "@deprecated"
 
     // Symbols
     LeftBrace,   // "{"
@@ -54,6 +73,17 @@ pub enum ErrorKind<'input> {
     /// Ex: `{@link MyLink`, there's no closing '}' on this line.
     UnterminatedInlineTag,
 
+    /// Returned when an inline code span is missing its closing backtick. Note that code spans cannot span multiple lines.
+    /// Ex: `` `foo() `` there's no closing '`' on this line.
+    UnterminatedCodeSpan,
+
+    /// Returned when emphasized text is missing its closing delimiter. Note that emphasis cannot span multiple lines.
+    /// Ex: `*foo`, there's no closing '*' on this line.
+    UnterminatedEmphasis { delimiter: &'input str },
+
+    /// Returned when a fenced code block is never closed. Ex: the comment ends without a closing "```" line.
+    UnterminatedCodeBlock,
+
     /// Returned when a tag is used in the incorrect context.
     /// Some tags can only be used inline, and others can only be used to start a new section.
     /// Ex: `{@param MyParam}`, param tags can't be used inline, and must be at the start of a new section.
@@ -67,6 +97,9 @@ impl fmt::Display for ErrorKind<'_> {
             Self::UnknownTag { tag } => write!(f, "unknown doc comment tag '{tag}'"),
             Self::MissingTag => f.write_str("missing doc comment tag"),
             Self::UnterminatedInlineTag => f.write_str("missing a closing '}' on an inline doc comment tag"),
+            Self::UnterminatedCodeSpan => f.write_str("missing a closing '`' on an inline code span"),
+            Self::UnterminatedEmphasis { delimiter } => write!(f, "missing a closing '{delimiter}' on emphasized text"),
+            Self::UnterminatedCodeBlock => f.write_str("missing a closing '```' on a fenced code block"),
             Self::IncorrectContextForTag { tag, is_inline } => f.write_fmt(format_args!(
                 "doc comment tag '{tag}' cannot be used {}",
                 if *is_inline { "inline" } else { "to start a block" },
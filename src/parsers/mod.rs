@@ -2,11 +2,18 @@
 
 //! TODO write a comment about how parsing works in Slice.
 
-// We only export the parsers and keep all the other logic private.
+// We only export the parsers and the parse cache, and keep all the other logic private.
+pub use self::cache::ParseCache;
 pub use self::comments::parser::CommentParser;
 pub use self::preprocessor::parser::Preprocessor;
 pub use self::slice::parser::Parser;
 
+// The formatter works directly on the token stream, so it also needs access to the Slice lexer.
+pub(crate) use self::common::SourceBlock;
+pub(crate) use self::slice::lexer::Lexer as SliceLexer;
+pub(crate) use self::slice::tokens::TokenKind as SliceTokenKind;
+
+mod cache;
 mod comments;
 mod common;
 mod preprocessor;
@@ -16,13 +23,28 @@ use crate::ast::Ast;
 use crate::compilation_state::CompilationState;
 use crate::diagnostics::{Diagnostic, Error};
 use crate::slice_file::SliceFile;
-use std::collections::HashSet;
+use std::collections::HashMap;
 
-pub fn parse_files(state: &mut CompilationState, symbols: &HashSet<String>) {
+pub fn parse_files(
+    state: &mut CompilationState,
+    symbols: &HashMap<String, Option<i64>>,
+    parse_cache: Option<&ParseCache>,
+) {
     for file in state.files.values_mut() {
+        // If the file is unchanged since it was cached, use its cached contents instead of parsing it again.
+        if parse_cache.is_some_and(|cache| cache.load(file, &mut state.ast, symbols)) {
+            continue;
+        }
+
         // Attempt to parse the file.
         let mut diagnostics = Vec::new();
         parse_file(file, &mut state.ast, &mut diagnostics, symbols.clone());
+        state.parsed_file_count += 1;
+
+        // Only files without any diagnostics are cached, so that loading them never needs to report anything.
+        if let Some(cache) = parse_cache.filter(|_| diagnostics.is_empty()) {
+            cache.store(file, symbols);
+        }
 
         // Forward any diagnostics that were emitted during parsing to the diagnostic reporter.
         for diagnostic in diagnostics {
@@ -31,27 +53,37 @@ pub fn parse_files(state: &mut CompilationState, symbols: &HashSet<String>) {
     }
 }
 
-fn parse_file(file: &mut SliceFile, ast: &mut Ast, diagnostics: &mut Vec<Diagnostic>, mut symbols: HashSet<String>) {
+pub(crate) fn parse_file(
+    file: &mut SliceFile,
+    ast: &mut Ast,
+    diagnostics: &mut Vec<Diagnostic>,
+    mut symbols: HashMap<String, Option<i64>>,
+) {
     // Pre-process the file's raw text.
     let preprocessor = Preprocessor::new(&file.relative_path, &mut symbols, diagnostics);
     let Ok(preprocessed_text) = preprocessor.parse_slice_file(file.raw_text.as_str()) else { return };
 
     // Parse the preprocessed text.
     let parser = Parser::new(&file.relative_path, ast, diagnostics);
-    let Ok((mode, attributes, module, definitions)) = parser.parse_slice_file(preprocessed_text) else { return };
+    let Ok((mode, attributes, imports, modules, definitions)) = parser.parse_slice_file(preprocessed_text) else {
+        return;
+    };
 
-    // Issue a syntax error if the user had definitions but forgot to declare a module.
-    if !definitions.is_empty() && module.is_none() {
+    // Issue a syntax error if the user had definitions outside of a module, since they forgot to declare one.
+    let unscoped_definition = definitions.iter().find(|d| d.borrow().get_raw_scope().module.is_none());
+    if let Some(definition) = unscoped_definition {
         let diagnostic = Diagnostic::new(Error::Syntax {
             // TODO improve this message, see: #348
             message: "module declaration is required".to_owned(),
-        });
+        })
+        .set_span(definition.borrow().span());
         diagnostics.push(diagnostic);
     }
 
     // Store the parsed data in the `SliceFile` it was parsed from.
     file.mode = mode;
-    file.module = module.map(|m| ast.add_named_element(m));
+    file.modules = modules.into_iter().map(|m| ast.add_named_element(m)).collect();
     file.attributes = attributes;
+    file.imports = imports;
     file.contents = definitions;
 }
@@ -9,7 +9,10 @@ use super::super::common::SourceBlock;
 use super::parser::Preprocessor;
 use super::tokens::{Error, TokenKind};
 use super::Location;
+use crate::diagnostics::{self, Diagnostic, Lint};
+use crate::slice_file::Span;
 use lalrpop_util::{lalrpop_mod, ErrorRecovery};
+use std::collections::HashMap;
 
 // Place the code generated by LALRPOP into a submodule named 'lalrpop'.
 lalrpop_mod!(
@@ -20,32 +23,204 @@ lalrpop_mod!(
 
 pub type Recovery<'a> = ErrorRecovery<Location, TokenKind<'a>, Error>;
 
+/// An item produced by a block of preprocessed input: either a block of source code, or a diagnostic from an
+/// `#error` or `#warning` directive or from evaluating a directive's expression. Diagnostics are stored alongside the
+/// source blocks instead of being reported immediately, so that only the directives in active conditional branches are
+/// reported.
+pub enum BlockItem<'a> {
+    Source(SourceBlock<'a>),
+    Diagnostic(Diagnostic),
+}
+
+/// The value of an operand in a preprocessor expression.
+///
+/// Expressions aren't evaluated while they're being parsed. Instead, each directive's expression is evaluated once it's
+/// fully parsed, so the right-hand side of `&&` and `||` is only evaluated when it's needed, and so diagnostics can be
+/// discarded if the directive is in an inactive conditional branch.
+pub enum Value<'a> {
+    Integer(i64),
+
+    /// A preprocessor symbol. When used as a boolean, it evaluates to whether the symbol is defined.
+    /// When used as an integer, it evaluates to the value the symbol was defined with, or to 0 if it isn't defined.
+    Symbol(&'a str),
+
+    Not(Box<Operand<'a>>),
+    And(Box<Operand<'a>>, Box<Operand<'a>>),
+    Or(Box<Operand<'a>>, Box<Operand<'a>>),
+    Comparison(Box<Operand<'a>>, ComparisonOperator, Box<Operand<'a>>),
+}
+
+/// An operand in a preprocessor expression, along with the span it covers, for reporting type errors.
+pub struct Operand<'a> {
+    pub value: Value<'a>,
+    pub span: Span,
+}
+
+impl<'a> Operand<'a> {
+    /// Returns this operand's boolean value. If the operand isn't a boolean, this reports an error and returns false.
+    fn as_bool(&self, symbols: &HashMap<String, Option<i64>>, diagnostics: &mut Vec<Diagnostic>) -> bool {
+        match &self.value {
+            Value::Symbol(symbol) => symbols.contains_key(*symbol),
+            Value::Not(operand) => !operand.as_bool(symbols, diagnostics),
+            Value::And(lhs, rhs) => lhs.as_bool(symbols, diagnostics) && rhs.as_bool(symbols, diagnostics),
+            Value::Or(lhs, rhs) => lhs.as_bool(symbols, diagnostics) || rhs.as_bool(symbols, diagnostics),
+            Value::Comparison(lhs, operator, rhs) => evaluate_comparison(lhs, *operator, rhs, symbols, diagnostics),
+            Value::Integer(_) => {
+                report_type_mismatch(diagnostics, "boolean", "integer", &self.span);
+                false
+            }
+        }
+    }
+
+    /// Returns this operand's integer value. If the operand isn't an integer, or is a symbol that was defined without a
+    /// value, this reports an error and returns `None`. Undefined symbols evaluate to 0.
+    fn as_integer(&self, symbols: &HashMap<String, Option<i64>>, diagnostics: &mut Vec<Diagnostic>) -> Option<i64> {
+        match &self.value {
+            Value::Integer(value) => Some(*value),
+            Value::Symbol(symbol) => match symbols.get(*symbol) {
+                Some(Some(value)) => Some(*value),
+                Some(None) => {
+                    let error = diagnostics::Error::SymbolHasNoValue {
+                        symbol: symbol.to_string(),
+                    };
+                    diagnostics.push(Diagnostic::new(error).set_span(&self.span));
+                    None
+                }
+                None => Some(0),
+            },
+            _ => {
+                report_type_mismatch(diagnostics, "integer", "boolean", &self.span);
+                None
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum ComparisonOperator {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
 // Grammar Rule Functions
 
 /// Evaluates an if/elif/else statement and returns the source block contained by the first true conditional.
 /// If none of the conditions are true, and an else block is present, its source block is returned instead.
-/// If none of the conditions are true, and no else block is present, this function returns [None].
+/// If none of the conditions are true, and no else block is present, the returned block is empty.
 ///
-/// The `if` and `elif` blocks are passed in as tuples of their values (true or false) and their source blocks.
+/// The `if` and `elif` blocks are passed in as tuples of their conditions and their source blocks.
 /// Since multiple (or zero) elif blocks can be present, they are passed as a [Vec] (in order).
 /// Since there can only be 0 or 1 else block, it is passed as an [Option].
+///
+/// Any diagnostics from evaluating the conditions are placed before the returned block. Conditions after the first
+/// true one are never reached, so their diagnostics are discarded.
 fn evaluate_if_statement<'a>(
-    if_block: (bool, Vec<SourceBlock<'a>>),
-    elif_blocks: Vec<(bool, Vec<SourceBlock<'a>>)>,
-    else_block: Option<Vec<SourceBlock<'a>>>,
-) -> Vec<SourceBlock<'a>> {
-    // If the if-statement was true, return its block's content.
-    if if_block.0 {
-        return if_block.1;
+    if_block: (Condition, Vec<BlockItem<'a>>),
+    elif_blocks: Vec<(Condition, Vec<BlockItem<'a>>)>,
+    else_block: Option<Vec<BlockItem<'a>>>,
+) -> Vec<BlockItem<'a>> {
+    let mut items = Vec::new();
+    for ((value, diagnostics), block) in std::iter::once(if_block).chain(elif_blocks) {
+        items.extend(diagnostics.into_iter().map(BlockItem::Diagnostic));
+        if value {
+            items.extend(block);
+            return items;
+        }
     }
-    // Check the elif statements in order. If one is true, return its block's content.
-    for elif_block in elif_blocks {
-        if elif_block.0 {
-            return elif_block.1;
+    // Otherwise, we return the content of the else block if it was present.
+    items.extend(else_block.unwrap_or_default());
+    items
+}
+
+/// The value of a conditional directive's expression, along with any diagnostics that evaluating it produced.
+type Condition = (bool, Vec<Diagnostic>);
+
+/// Evaluates the expression of an `#if` or `#elif` directive, using the symbols which are currently defined.
+fn evaluate_condition(preprocessor: &Preprocessor, expression: Operand) -> Condition {
+    let mut diagnostics = Vec::new();
+    let value = expression.as_bool(preprocessor.defined_symbols, &mut diagnostics);
+    (value, diagnostics)
+}
+
+/// Evaluates a comparison between two integer operands. If either operand isn't an integer, an error is reported and
+/// the comparison evaluates to false.
+fn evaluate_comparison(
+    lhs: &Operand,
+    operator: ComparisonOperator,
+    rhs: &Operand,
+    symbols: &HashMap<String, Option<i64>>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> bool {
+    let (Some(lhs), Some(rhs)) = (
+        lhs.as_integer(symbols, diagnostics),
+        rhs.as_integer(symbols, diagnostics),
+    ) else {
+        return false;
+    };
+
+    match operator {
+        ComparisonOperator::Equal => lhs == rhs,
+        ComparisonOperator::NotEqual => lhs != rhs,
+        ComparisonOperator::LessThan => lhs < rhs,
+        ComparisonOperator::LessThanOrEqual => lhs <= rhs,
+        ComparisonOperator::GreaterThan => lhs > rhs,
+        ComparisonOperator::GreaterThanOrEqual => lhs >= rhs,
+    }
+}
+
+fn report_type_mismatch(diagnostics: &mut Vec<Diagnostic>, expected: &str, actual: &str, span: &Span) {
+    let error = diagnostics::Error::PreprocessorTypeMismatch {
+        expected: expected.to_owned(),
+        actual: actual.to_owned(),
+    };
+    diagnostics.push(Diagnostic::new(error).set_span(span));
+}
+
+/// Reports the diagnostics of any `#error` and `#warning` directives that survived preprocessing, and returns the
+/// remaining source blocks (in order).
+fn report_directive_diagnostics<'a>(
+    preprocessor: &mut Preprocessor,
+    items: impl Iterator<Item = BlockItem<'a>>,
+) -> std::vec::IntoIter<SourceBlock<'a>> {
+    let mut source_blocks = Vec::new();
+    for item in items {
+        match item {
+            BlockItem::Source(source_block) => source_blocks.push(source_block),
+            BlockItem::Diagnostic(diagnostic) => preprocessor.diagnostics.push(diagnostic),
+        }
+    }
+    source_blocks.into_iter()
+}
+
+fn construct_error_directive(message: &str, span: Span) -> Diagnostic {
+    let error = diagnostics::Error::ErrorDirective {
+        message: unescape_message(message),
+    };
+    Diagnostic::new(error).set_span(&span)
+}
+
+fn construct_warning_directive(message: &str, span: Span) -> Diagnostic {
+    let lint = Lint::WarningDirective {
+        message: unescape_message(message),
+    };
+    Diagnostic::new(lint).set_span(&span)
+}
+
+/// Processes the escape sequences in a directive's message. A backslash always escapes the character following it.
+fn unescape_message(message: &str) -> String {
+    let mut unescaped = String::with_capacity(message.len());
+    let mut chars = message.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescaped.extend(chars.next()),
+            _ => unescaped.push(c),
         }
     }
-    // Otherwise, we return the content of the else block if it was present, if not, we return an empty vector.
-    else_block.unwrap_or_default()
+    unescaped
 }
 
 fn recover_from_error<T: Default>(preprocessor: &mut Preprocessor, recovery: Recovery) -> T {
@@ -90,6 +90,67 @@ impl<'input> Lexer<'input> {
         &self.input[start_position..self.position]
     }
 
+    /// Reads and consumes an integer literal (with an optional leading '-') from the buffer, and returns a token
+    /// containing its value. If the literal doesn't have any digits, or can't fit in an `i64`, this returns an error.
+    fn read_integer_literal(&mut self, start_location: Location) -> LexerResult<'input> {
+        let start_position = self.position;
+
+        if matches!(self.buffer.peek(), Some('-')) {
+            self.advance_buffer(); // Consume the '-' character.
+            if !matches!(self.buffer.peek(), Some(c) if c.is_ascii_digit()) {
+                let error = ErrorKind::UnknownSymbol {
+                    symbol: "-".to_owned(),
+                    suggestion: None,
+                };
+                return Err((start_location, error, self.cursor));
+            }
+        }
+
+        // Loop while the next character in the buffer is a digit.
+        while matches!(self.buffer.peek(), Some(c) if c.is_ascii_digit()) {
+            self.advance_buffer(); // Consume the character.
+        }
+
+        let literal = &self.input[start_position..self.position];
+        match literal.parse::<i64>() {
+            Ok(value) => Ok((start_location, TokenKind::IntegerLiteral(value), self.cursor)),
+            Err(_) => {
+                let error = ErrorKind::IntegerLiteralOutOfRange {
+                    literal: literal.to_owned(),
+                };
+                Err((start_location, error, self.cursor))
+            }
+        }
+    }
+
+    /// Reads and consumes a string literal from the buffer, and returns a token containing its content.
+    /// This function assumes the opening quote has already been consumed. String literals cannot span multiple lines,
+    /// so if end-of-line is reached before the closing quote, this returns an error instead.
+    fn read_string_literal(&mut self, start_location: Location) -> LexerResult<'input> {
+        let start_position = self.position;
+
+        loop {
+            match self.buffer.peek() {
+                Some('"') => {
+                    let content = &self.input[start_position..self.position];
+                    self.advance_buffer(); // Consume the closing quote.
+                    return Ok((start_location, TokenKind::StringLiteral(content), self.cursor));
+                }
+                Some('\\') => {
+                    // Consume the backslash and the character it escapes, so escaped quotes don't end the literal.
+                    self.advance_buffer();
+                    if !matches!(self.buffer.peek(), Some('\n') | None) {
+                        self.advance_buffer();
+                    }
+                }
+                Some('\n') | None => {
+                    return Err((start_location, ErrorKind::UnterminatedStringLiteral, self.cursor));
+                }
+                Some(_) => self.advance_buffer(),
+            }
+        }
+    }
+
     /// Constructs and returns a preprocessor token representing a block of source code.
     /// This function assumes that the lexer's cursor is at the end of the token being created.
     fn create_source_block_token(
@@ -120,7 +181,43 @@ impl<'input> Lexer<'input> {
         match c {
             '(' => Some(self.return_simple_token(TokenKind::LeftParenthesis, start_location)),
             ')' => Some(self.return_simple_token(TokenKind::RightParenthesis, start_location)),
-            '!' => Some(self.return_simple_token(TokenKind::Not, start_location)),
+            '!' => {
+                self.advance_buffer(); // Consume the '!' character.
+                if matches!(self.buffer.peek(), Some('=')) {
+                    Some(self.return_simple_token(TokenKind::NotEqual, start_location))
+                } else {
+                    Some(Ok((start_location, TokenKind::Not, self.cursor)))
+                }
+            }
+            '=' => {
+                self.advance_buffer(); // Consume the '=' character.
+                                       // Ensure the next character is also an '=' (since the whole token should be "==").
+                if matches!(self.buffer.peek(), Some('=')) {
+                    Some(self.return_simple_token(TokenKind::Equal, start_location))
+                } else {
+                    let error = ErrorKind::UnknownSymbol {
+                        symbol: "=".to_owned(),
+                        suggestion: Some("==".to_owned()),
+                    };
+                    Some(Err((start_location, error, self.cursor)))
+                }
+            }
+            '<' => {
+                self.advance_buffer(); // Consume the '<' character.
+                if matches!(self.buffer.peek(), Some('=')) {
+                    Some(self.return_simple_token(TokenKind::LessThanOrEqual, start_location))
+                } else {
+                    Some(Ok((start_location, TokenKind::LessThan, self.cursor)))
+                }
+            }
+            '>' => {
+                self.advance_buffer(); // Consume the '>' character.
+                if matches!(self.buffer.peek(), Some('=')) {
+                    Some(self.return_simple_token(TokenKind::GreaterThanOrEqual, start_location))
+                } else {
+                    Some(Ok((start_location, TokenKind::GreaterThan, self.cursor)))
+                }
+            }
             '&' => {
                 self.advance_buffer(); // Consume the '&' character.
                                        // Ensure the next character is also an '&' (since the whole token should be "&&").
@@ -158,6 +255,8 @@ impl<'input> Lexer<'input> {
                     "elif" => Some(Ok((start_location, TokenKind::ElifKeyword, self.cursor))),
                     "else" => Some(Ok((start_location, TokenKind::ElseKeyword, self.cursor))),
                     "endif" => Some(Ok((start_location, TokenKind::EndifKeyword, self.cursor))),
+                    "error" => Some(Ok((start_location, TokenKind::ErrorKeyword, self.cursor))),
+                    "warning" => Some(Ok((start_location, TokenKind::WarningKeyword, self.cursor))),
                     "" => Some(Err((start_location, ErrorKind::MissingDirective, self.cursor))),
                     keyword => {
                         let error = ErrorKind::UnknownDirective {
@@ -167,6 +266,10 @@ impl<'input> Lexer<'input> {
                     }
                 }
             }
+            '"' => {
+                self.advance_buffer(); // Consume the opening quote.
+                Some(self.read_string_literal(start_location))
+            }
             '/' => {
                 self.advance_buffer(); // Consume the '/' character.
 
@@ -185,6 +288,7 @@ impl<'input> Lexer<'input> {
                     }
                 }
             }
+            ch if ch.is_ascii_digit() || ch == '-' => Some(self.read_integer_literal(start_location)),
             ch if ch.is_ascii_alphabetic() || ch == '_' => {
                 let identifier = self.read_identifier();
                 Some(Ok((start_location, TokenKind::Identifier(identifier), self.cursor)))
@@ -5,7 +5,7 @@ use super::construct_error_from;
 use super::grammar::lalrpop;
 use super::lexer::Lexer;
 use crate::diagnostics::Diagnostic;
-use std::collections::HashSet;
+use std::collections::HashMap;
 
 /// Helper macro for generating parsing functions.
 macro_rules! implement_parse_function {
@@ -29,7 +29,7 @@ macro_rules! implement_parse_function {
 
 pub struct Preprocessor<'a> {
     pub file_name: &'a str,
-    pub(super) defined_symbols: &'a mut HashSet<String>,
+    pub(super) defined_symbols: &'a mut HashMap<String, Option<i64>>,
     pub(super) diagnostics: &'a mut Vec<Diagnostic>,
 }
 
@@ -42,7 +42,7 @@ impl<'a> Preprocessor<'a> {
 
     pub fn new(
         file_name: &'a str,
-        defined_symbols: &'a mut HashSet<String>,
+        defined_symbols: &'a mut HashMap<String, Option<i64>>,
         diagnostics: &'a mut Vec<Diagnostic>,
     ) -> Self {
         Preprocessor {
@@ -13,13 +13,21 @@ pub type Error = (Location, ErrorKind, Location);
 #[derive(Clone, Debug)]
 pub enum TokenKind<'input> {
     /// An identifier for a preprocessor variable, which may either be defined (true) or undefined (false).
+    /// Symbols can optionally be defined with an integer value, which is used when comparing them.
     Identifier(&'input str), // "[_a-zA-Z][_a-zA-Z0-9]*"
 
+    /// An integer literal, used as the value of a preprocessor symbol, or as an operand in a comparison.
+    IntegerLiteral(i64), // "-?[0-9]+"
+
     /// A block of contiguous Slice source code (as opposed to a preprocessor directive).
     /// A Slice file is comprised of lines of preprocessor directives with blocks of source code between them.
     /// The preprocessor preserves these blocks untouched, and performs no analysis or parsing of them.
     SourceBlock(SourceBlock<'input>),
 
+    /// A string literal, used as the message of an `#error` or `#warning` directive.
+    /// The surrounding quotes are not included, and escape sequences are left unprocessed.
+    StringLiteral(&'input str), // "\"[^\"\n]*\""
+
     // Directive keywords
     DefineKeyword,   // "#\s*define"
     UndefineKeyword, // "#\s*undef"
@@ -27,6 +35,8 @@ pub enum TokenKind<'input> {
     ElifKeyword,     // "#\s*elif"
     ElseKeyword,     // "#\s*else"
     EndifKeyword,    // "#\s*endif"
+    ErrorKeyword,    // "#\s*error"
+    WarningKeyword,  // "#\s*warning"
 
     DirectiveEnd,
 
@@ -35,6 +45,14 @@ pub enum TokenKind<'input> {
     And, // "&&"
     Or,  // "||"
 
+    // Comparison operators
+    Equal,              // "=="
+    NotEqual,           // "!="
+    LessThan,           // "<"
+    LessThanOrEqual,    // "<="
+    GreaterThan,        // ">"
+    GreaterThanOrEqual, // ">="
+
     // Brackets
     LeftParenthesis,  // "("
     RightParenthesis, // ")"
@@ -56,6 +74,14 @@ pub enum ErrorKind {
     /// Returned when a '#' isn't followed by a directive identifier (ignoring whitespace).
     /// Ex: `#`, nothing follows after the '#'.
     MissingDirective,
+
+    /// Returned when an integer literal is too large to be stored in an `i64`.
+    /// Ex: `#define Foo 99999999999999999999`, the value is larger than `i64::MAX`.
+    IntegerLiteralOutOfRange { literal: String },
+
+    /// Returned when a string literal isn't closed before the end of the line.
+    /// Ex: `#error "foo`, the string literal is missing its closing quote.
+    UnterminatedStringLiteral,
 }
 
 impl fmt::Display for ErrorKind {
@@ -67,6 +93,8 @@ impl fmt::Display for ErrorKind {
             },
             Self::UnknownDirective { keyword } => write!(f, "unknown preprocessor directive: '{keyword}'"),
             Self::MissingDirective => f.write_str("missing preprocessor directive"),
+            Self::IntegerLiteralOutOfRange { literal } => write!(f, "integer literal '{literal}' is out of range"),
+            Self::UnterminatedStringLiteral => f.write_str("unterminated string literal"),
         }
     }
 }
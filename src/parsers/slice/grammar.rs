@@ -8,6 +8,7 @@
 use super::parser::Parser;
 use crate::ast::node::Node;
 use crate::diagnostics::{Diagnostic, Error};
+use crate::grammar::attributes::{Flags, Unparsed};
 use crate::grammar::*;
 use crate::parsers::CommentParser;
 use crate::slice_file::Span;
@@ -60,9 +61,9 @@ type RawDocComment<'a> = Vec<(&'a str, Span)>;
 
 fn handle_file_compilation_mode(
     parser: &mut Parser,
-    (old_mode, attributes): (Option<FileCompilationMode>, Vec<WeakPtr<Attribute>>),
+    (old_mode, attributes, imports): (Option<FileCompilationMode>, Vec<WeakPtr<Attribute>>, Vec<Import>),
     mode: FileCompilationMode,
-) -> (Option<FileCompilationMode>, Vec<WeakPtr<Attribute>>) {
+) -> (Option<FileCompilationMode>, Vec<WeakPtr<Attribute>>, Vec<Import>) {
     // Compilation mode can only be set once per file.
     if let Some(old_file_mode) = old_mode {
         let old_span = old_file_mode.span();
@@ -72,7 +73,7 @@ fn handle_file_compilation_mode(
         parser.diagnostics.push(diagnostic);
     }
     parser.compilation_mode = mode.version;
-    (Some(mode), attributes)
+    (Some(mode), attributes, imports)
 }
 
 fn construct_file_compilation_mode(parser: &mut Parser, i: Identifier, span: Span) -> FileCompilationMode {
@@ -90,28 +91,47 @@ fn construct_file_compilation_mode(parser: &mut Parser, i: Identifier, span: Spa
     FileCompilationMode { version, span }
 }
 
+/// Constructs a module and makes it the parser's current scope, returning the module and the scope it replaced.
+///
+/// File-level modules (`module Foo`) replace the current scope entirely, but block modules (`module Foo { ... }`)
+/// are nested within the current scope, so the previous scope can be restored at the end of the block.
 fn construct_module(
     parser: &mut Parser,
     (raw_comment, attributes): (RawDocComment, Vec<WeakPtr<Attribute>>),
-    identifier: Identifier,
+    mut identifier: Identifier,
     span: Span,
-) -> OwnedPtr<Module> {
-    if !raw_comment.is_empty() {
-        let error = Error::Syntax {
-            message: "doc comments cannot be applied to modules".to_owned(),
-        };
-        parser.diagnostics.push(Diagnostic::new(error).set_span(&span));
+    is_block: bool,
+) -> (OwnedPtr<Module>, Scope) {
+    // Block modules are nested within any module they're declared in, so we prefix their identifiers.
+    if is_block {
+        identifier.value = get_scoped_identifier(&identifier.value, &parser.current_scope.parser_scope);
     }
 
+    // A module's identifier is already fully scoped, so we parse its comment without the parser's current scope.
+    let comment = parse_scoped_doc_comment(parser, identifier.value.clone(), raw_comment);
     let module_ptr = OwnedPtr::new(Module {
         identifier,
         attributes,
+        comment,
         span,
     });
 
-    parser.current_scope.module = Some(module_ptr.downgrade());
-    parser.current_scope.parser_scope = module_ptr.borrow().nested_module_identifier().to_owned();
-    module_ptr
+    let scope = Scope {
+        parser_scope: module_ptr.borrow().nested_module_identifier().to_owned(),
+        module: Some(module_ptr.downgrade()),
+    };
+    let previous_scope = std::mem::replace(&mut parser.current_scope, scope);
+    (module_ptr, previous_scope)
+}
+
+/// Appends the modules and definitions of a module block onto the modules and definitions that precede it.
+fn merge_module_contents(
+    mut contents: (Vec<OwnedPtr<Module>>, Vec<Definition>),
+    block_contents: (Vec<OwnedPtr<Module>>, Vec<Definition>),
+) -> (Vec<OwnedPtr<Module>>, Vec<Definition>) {
+    contents.0.extend(block_contents.0);
+    contents.1.extend(block_contents.1);
+    contents
 }
 
 fn construct_struct(
@@ -204,6 +224,7 @@ pub fn construct_field(
     identifier: Identifier,
     tag: Option<Integer<u32>>,
     data_type: TypeRef,
+    default_value: Option<Literal>,
     span: Span,
 ) -> OwnedPtr<Field> {
     let comment = parse_doc_comment(parser, &identifier.value, raw_comment);
@@ -211,6 +232,7 @@ pub fn construct_field(
         identifier,
         data_type,
         tag,
+        default_value,
         parent: WeakPtr::create_uninitialized(), // Patched by its container.
         scope: parser.current_scope.clone(),
         attributes,
@@ -302,20 +324,7 @@ fn construct_parameter(
     span: Span,
     is_returned: bool,
 ) -> OwnedPtr<Parameter> {
-    if !raw_comment.is_empty() {
-        let kind = match is_returned {
-            true => "return member",
-            false => "parameter",
-        };
-        let diagnostic = Diagnostic::new(Error::Syntax {
-            message: format!("doc comments cannot be applied to {kind}s"),
-        })
-        .set_span(&span)
-        .add_note("try using an '@param' tag on the operation it belongs to instead", None)
-        .add_note(format!("Ex: @param {}: {}", &identifier.value, raw_comment[0].0), None);
-        parser.diagnostics.push(diagnostic);
-    }
-
+    let comment = parse_doc_comment(parser, &identifier.value, raw_comment);
     OwnedPtr::new(Parameter {
         identifier,
         data_type,
@@ -325,6 +334,7 @@ fn construct_parameter(
         parent: WeakPtr::create_uninitialized(), // Patched by its container.
         scope: parser.current_scope.clone(),
         attributes,
+        comment,
         span,
     })
 }
@@ -351,11 +361,12 @@ fn construct_single_return_type(
         parent: WeakPtr::create_uninitialized(), // Patched by its container.
         scope: parser.current_scope.clone(),
         attributes: Vec::new(),
+        comment: None,
         span,
     })]
 }
 
-fn check_return_tuple(parser: &mut Parser, return_tuple: &Vec<OwnedPtr<Parameter>>, span: Span) {
+fn check_return_tuple(parser: &mut Parser, return_tuple: &[OwnedPtr<Parameter>], span: Span) {
     if return_tuple.len() < 2 {
         let diagnostic = Diagnostic::new(Error::ReturnTuplesMustContainAtLeastTwoElements).set_span(&span);
         parser.diagnostics.push(diagnostic);
@@ -368,12 +379,21 @@ fn construct_enum(
     is_unchecked: bool,
     identifier: Identifier,
     underlying_type: Option<TypeRef>,
-    enumerators: Vec<OwnedPtr<Enumerator>>,
+    mut enumerators: Vec<OwnedPtr<Enumerator>>,
     span: Span,
 ) -> OwnedPtr<Enum> {
     let underlying = underlying_type.map(|type_ref| type_ref.downcast::<Primitive>().unwrap());
     let comment = parse_doc_comment(parser, &identifier.value, raw_comment);
 
+    // Attributes haven't been patched yet, so we check for the `flags` attribute by its directive.
+    let is_flags = attributes.iter().any(|attribute| {
+        let unparsed = attribute.borrow().downcast::<Unparsed>();
+        unparsed.is_some_and(|unparsed| unparsed.directive == Flags::directive())
+    });
+    if is_flags {
+        compute_implicit_flag_values(&mut enumerators);
+    }
+
     let mut enum_ptr = OwnedPtr::new(Enum {
         identifier,
         enumerators: Vec::new(),
@@ -395,10 +415,30 @@ fn construct_enum(
     enum_ptr
 }
 
+// Implicit enumerator values in flags enums are successive powers of two, instead of successive integers.
+// The first implicit value is 1, and each implicit value after that is the next power of two after the previous value.
+fn compute_implicit_flag_values(enumerators: &mut [OwnedPtr<Enumerator>]) {
+    let mut last_value = None;
+    for enumerator_ptr in enumerators {
+        let enumerator = unsafe { enumerator_ptr.borrow_mut() };
+        if let EnumeratorValue::Implicit(value) = &mut enumerator.value {
+            *value = match last_value {
+                Some(last) if last > 0 => ((last as u128) + 1)
+                    .checked_next_power_of_two()
+                    .and_then(|next| i128::try_from(next).ok())
+                    .unwrap_or(i128::MAX), // Out of bounds values are reported by the enum validator.
+                _ => 1,
+            };
+        }
+        last_value = Some(enumerator.value());
+    }
+}
+
 fn construct_enumerator(
     parser: &mut Parser,
     (raw_comment, attributes): (RawDocComment, Vec<WeakPtr<Attribute>>),
     identifier: Identifier,
+    fields: Option<Vec<OwnedPtr<Field>>>,
     enumerator_value: Option<Integer<i128>>,
     span: Span,
 ) -> OwnedPtr<Enumerator> {
@@ -412,9 +452,10 @@ fn construct_enumerator(
         None => EnumeratorValue::Implicit(parser.last_enumerator_value.map_or(0, |x| x.wrapping_add(1))),
     };
 
-    let enumerator = OwnedPtr::new(Enumerator {
+    let mut enumerator_ptr = OwnedPtr::new(Enumerator {
         identifier,
         value,
+        fields: Vec::new(),
         parent: WeakPtr::create_uninitialized(), // Patched by its container.
         scope: parser.current_scope.clone(),
         attributes,
@@ -422,8 +463,12 @@ fn construct_enumerator(
         span,
     });
 
-    parser.last_enumerator_value = Some(enumerator.borrow().value());
-    enumerator
+    // Add any associated fields to the enumerator.
+    let fields = fields.unwrap_or_default();
+    set_fields_for!(enumerator_ptr, fields, parser);
+
+    parser.last_enumerator_value = Some(enumerator_ptr.borrow().value());
+    enumerator_ptr
 }
 
 fn construct_custom_type(
@@ -462,6 +507,26 @@ fn construct_type_alias(
     })
 }
 
+fn construct_constant(
+    parser: &mut Parser,
+    (raw_comment, attributes): (RawDocComment, Vec<WeakPtr<Attribute>>),
+    identifier: Identifier,
+    data_type: TypeRef,
+    value: Literal,
+    span: Span,
+) -> OwnedPtr<Constant> {
+    let comment = parse_doc_comment(parser, &identifier.value, raw_comment);
+    OwnedPtr::new(Constant {
+        identifier,
+        data_type,
+        value,
+        scope: parser.current_scope.clone(),
+        attributes,
+        comment,
+        span,
+    })
+}
+
 fn construct_type_ref(
     parser: &Parser,
     attributes: Vec<WeakPtr<Attribute>>,
@@ -503,7 +568,7 @@ fn construct_unpatched_type_ref_definition(mut identifier: Identifier) -> TypeRe
 fn construct_attribute(
     parser: &mut Parser,
     directive: Identifier,
-    arguments: Option<Vec<String>>,
+    arguments: Option<Vec<AttributeArgument>>,
     span: Span,
 ) -> WeakPtr<Attribute> {
     let attribute = Attribute::new(directive.value, arguments.unwrap_or_default(), span);
@@ -537,6 +602,72 @@ fn try_parse_integer(parser: &mut Parser, s: &str, span: Span) -> Integer<i128>
     Integer { value, span }
 }
 
+fn try_parse_float(parser: &mut Parser, s: &str, is_negative: bool, span: Span) -> Literal {
+    // Remove any underscores from the float literal before trying to parse it.
+    let sanitized = s.replace('_', "");
+
+    let value = match sanitized.parse::<f64>() {
+        Ok(x) if is_negative => -x,
+        Ok(x) => x,
+        Err(_) => {
+            let error = Error::Syntax {
+                message: format!("'{s}' is not a valid floating point literal"),
+            };
+            parser.diagnostics.push(Diagnostic::new(error).set_span(&span));
+            0.0 // Dummy value
+        }
+    };
+
+    Literal {
+        value: LiteralValue::Float(value),
+        span,
+    }
+}
+
+/// Returns the value of a string literal with all of its escape sequences processed.
+/// Unknown escape sequences are reported as errors, and then treated as if the '\' wasn't present.
+fn parse_string_literal(parser: &mut Parser, s: &str, span: &Span) -> String {
+    let mut value = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+
+        // The lexer guarantees that string literals never end with an unescaped '\'.
+        match chars.next() {
+            Some('n') => value.push('\n'),
+            Some('r') => value.push('\r'),
+            Some('t') => value.push('\t'),
+            Some('0') => value.push('\0'),
+            Some(escaped @ ('\\' | '"')) => value.push(escaped),
+            Some(other) => {
+                let error = Error::Syntax {
+                    message: format!("unknown escape sequence '\\{other}' in string literal"),
+                };
+                parser.diagnostics.push(Diagnostic::new(error).set_span(span));
+                value.push(other);
+            }
+            None => {}
+        }
+    }
+    value
+}
+
+fn construct_identifier_literal(identifier: Identifier) -> Literal {
+    // `true` and `false` aren't keywords, so we check for them here, and treat anything else as a normal identifier.
+    let value = match identifier.value.as_str() {
+        "true" => LiteralValue::Bool(true),
+        "false" => LiteralValue::Bool(false),
+        _ => LiteralValue::Identifier(identifier.value),
+    };
+    Literal {
+        value,
+        span: identifier.span,
+    }
+}
+
 fn parse_tag_value(parser: &mut Parser, i: Integer<i128>) -> Integer<u32> {
     // Verify that the provided integer is a valid tag id.
     if !RangeInclusive::new(0, i32::MAX as i128).contains(&i.value) {
@@ -564,11 +695,19 @@ fn parse_compact_id_value(parser: &mut Parser, i: Integer<i128>) -> Integer<u32>
 }
 
 fn parse_doc_comment(parser: &mut Parser, identifier: &str, raw_comment: RawDocComment) -> Option<DocComment> {
+    let scoped_identifier = get_scoped_identifier(identifier, &parser.current_scope.parser_scope);
+    parse_scoped_doc_comment(parser, scoped_identifier, raw_comment)
+}
+
+fn parse_scoped_doc_comment(
+    parser: &mut Parser,
+    scoped_identifier: String,
+    raw_comment: RawDocComment,
+) -> Option<DocComment> {
     if raw_comment.is_empty() {
         // If the doc comment had 0 lines, that just means there is no doc comment.
         None
     } else {
-        let scoped_identifier = get_scoped_identifier(identifier, &parser.current_scope.parser_scope);
         let comment_parser = CommentParser::new(parser.file_name, &scoped_identifier, parser.diagnostics);
         comment_parser.parse_doc_comment(raw_comment).ok()
     }
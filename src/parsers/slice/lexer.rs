@@ -36,9 +36,14 @@ where
     /// it's value can jump forward when switching to a new source block, making it unreliable for indexing.
     cursor: Location,
 
-    /// This flag stores whether the lexer is currently lexing the inside of an attribute.
-    /// It is set to true upon encountering an '[' character, and false upon an ']' character.
-    attribute_mode: bool,
+    /// Stores how many brackets deep the lexer is, with any value greater than 0 meaning that the lexer is currently
+    /// lexing the inside of an attribute. It is incremented upon encountering an '[' character, and decremented upon
+    /// an ']' character. Brackets nested inside of an attribute are used for lists in attribute arguments.
+    bracket_depth: usize,
+
+    /// If true, the lexer returns non-doc comments as [`LineComment`](TokenKind::LineComment) and
+    /// [`BlockComment`](TokenKind::BlockComment) tokens, instead of ignoring them.
+    keep_trivia: bool,
 }
 
 impl<'input, T> Lexer<'input, T>
@@ -60,7 +65,18 @@ where
             current_block,
             buffer,
             cursor: start_location,
-            attribute_mode: false,
+            bracket_depth: 0,
+            keep_trivia: false,
+        }
+    }
+
+    /// Creates a lexer which returns non-doc comments as tokens, instead of ignoring them.
+    /// These tokens aren't understood by the parser; this is meant for tools that work directly on the token stream,
+    /// like the [formatter](crate::formatter), which must preserve the comments in its input.
+    pub fn with_trivia(input: T) -> Self {
+        Lexer {
+            keep_trivia: true,
+            ..Lexer::new(input)
         }
     }
 
@@ -118,6 +134,36 @@ where
         &self.current_block.content[start_position..end_position]
     }
 
+    /// Reads, consumes, and returns a numeric literal from the buffer.
+    /// If the literal contains a fractional part, a float literal is returned, otherwise an integer literal is.
+    ///
+    /// This function expects the lexer's cursor to be immediately before the literal's first digit.
+    fn read_numeric_literal(&mut self) -> TokenKind<'input> {
+        let start_position = self.get_position();
+        self.read_alphanumeric();
+
+        // Only treat the literal as a float if the '.' is immediately followed by a digit.
+        let mut lookahead = self.buffer.clone();
+        let is_float = matches!(lookahead.next(), Some((_, '.')))
+            && matches!(lookahead.next(), Some((_, c)) if c.is_ascii_digit());
+        if !is_float {
+            let end_position = self.get_position();
+            return TokenKind::IntegerLiteral(&self.current_block.content[start_position..end_position]);
+        }
+
+        self.advance_buffer(); // Consume the '.' character.
+        let fraction = self.read_alphanumeric();
+
+        // If the fractional part ends with an exponent marker, check for a signed exponent.
+        if fraction.ends_with(['e', 'E']) && matches!(self.buffer.peek(), Some((_, '+' | '-'))) {
+            self.advance_buffer(); // Consume the sign.
+            self.read_alphanumeric();
+        }
+
+        let end_position = self.get_position();
+        TokenKind::FloatLiteral(&self.current_block.content[start_position..end_position])
+    }
+
     /// Reads, consumes, and returns a string literal from the buffer.
     /// String literals are any characters contained within a pair of un-escaped double-quotes.
     /// The returned string doesn't include the opening and closing quotation marks, just the content between them.
@@ -184,7 +230,7 @@ where
 
     /// Checks if an identifier corresponds to a Slice keyword. If it does,
     /// return the keyword's token. Otherwise, return an `[TokenKind::Identifier]` token.
-    fn check_if_keyword(identifier: &str) -> TokenKind {
+    fn check_if_keyword(identifier: &str) -> TokenKind<'_> {
         debug_assert!(identifier.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'));
         debug_assert!(!identifier.is_empty());
 
@@ -197,6 +243,7 @@ where
             "enum" => TokenKind::EnumKeyword,
             "custom" => TokenKind::CustomKeyword,
             "typealias" => TokenKind::TypeAliasKeyword,
+            "const" => TokenKind::ConstKeyword,
             "sequence" => TokenKind::SequenceKeyword,
             "dictionary" => TokenKind::DictionaryKeyword,
             "bool" => TokenKind::BoolKeyword,
@@ -219,6 +266,7 @@ where
             "AnyException" => TokenKind::AnyExceptionKeyword,
             "compact" => TokenKind::CompactKeyword,
             "idempotent" => TokenKind::IdempotentKeyword,
+            "import" => TokenKind::ImportKeyword,
             "mode" => TokenKind::ModeKeyword,
             "stream" => TokenKind::StreamKeyword,
             "tag" => TokenKind::TagKeyword,
@@ -245,12 +293,14 @@ where
             '(' => self.return_simple_token(TokenKind::LeftParenthesis, start_location),
             ')' => self.return_simple_token(TokenKind::RightParenthesis, start_location),
             '[' => {
-                // Set the 'attribute_mode' flag since this must be the start of an attribute.
-                self.attribute_mode = true;
+                // Brackets can only start an attribute when we aren't already inside of one.
+                let is_attribute_start = self.bracket_depth == 0;
+                self.bracket_depth += 1;
                 self.advance_buffer(); // Consume the '[' character.
 
                 // Check if the next character is also '['.
-                if matches!(self.buffer.peek(), Some((_, '['))) {
+                if is_attribute_start && matches!(self.buffer.peek(), Some((_, '['))) {
+                    self.bracket_depth += 1;
                     self.advance_buffer(); // Consume the second '[' character.
                     Some(Ok((start_location, TokenKind::DoubleLeftBracket, self.cursor)))
                 } else {
@@ -258,12 +308,14 @@ where
                 }
             }
             ']' => {
-                // Clear the 'attribute_mode' flag since this must be the end of an attribute.
-                self.attribute_mode = false;
+                // Brackets can only end an attribute when we aren't inside of a nested list.
+                let is_attribute_end = self.bracket_depth <= 2;
+                self.bracket_depth = self.bracket_depth.saturating_sub(1);
                 self.advance_buffer(); // Consume the ']' character.
 
                 // Check if the next character is also ']'.
-                if matches!(self.buffer.peek(), Some((_, ']'))) {
+                if is_attribute_end && matches!(self.buffer.peek(), Some((_, ']'))) {
+                    self.bracket_depth = self.bracket_depth.saturating_sub(1);
                     self.advance_buffer(); // Consume the second ']' character.
                     Some(Ok((start_location, TokenKind::DoubleRightBracket, self.cursor)))
                 } else {
@@ -305,6 +357,7 @@ where
                 })
             }
             '/' => {
+                let start_position = self.get_position();
                 self.advance_buffer(); // Consume the '/' character.
 
                 match self.buffer.peek() {
@@ -324,9 +377,13 @@ where
 
                         let content_start_loc = self.cursor;
                         let comment = self.read_line_comment();
-                        match is_doc_comment {
-                            true => Some(Ok((content_start_loc, TokenKind::DocComment(comment), self.cursor))),
-                            false => None, // Non-doc comments are ignored.
+                        if is_doc_comment {
+                            Some(Ok((content_start_loc, TokenKind::DocComment(comment), self.cursor)))
+                        } else if self.keep_trivia {
+                            let comment = &self.current_block.content[start_position..self.get_position()];
+                            Some(Ok((start_location, TokenKind::LineComment(comment), self.cursor)))
+                        } else {
+                            None // Non-doc comments are ignored.
                         }
                     }
 
@@ -334,7 +391,11 @@ where
                     Some((_, '*')) => {
                         self.advance_buffer(); // Consume the '*'.
                         match self.consume_block_comment() {
-                            Ok(_) => None, // Block comments are always ignored.
+                            Ok(_) if self.keep_trivia => {
+                                let comment = &self.current_block.content[start_position..self.get_position()];
+                                Some(Ok((start_location, TokenKind::BlockComment(comment), self.cursor)))
+                            }
+                            Ok(_) => None, // Block comments are ignored unless we're keeping trivia.
                             Err(err) => Some(Err((start_location, err, self.cursor))),
                         }
                     }
@@ -365,7 +426,7 @@ where
                 }
             }
             _ if c.is_ascii_alphabetic() || c == '_' => {
-                let token = if self.attribute_mode {
+                let token = if self.bracket_depth > 0 {
                     // If we're lexing an attribute, return the identifier as-is, without checking if it's a keyword.
                     TokenKind::Identifier(self.read_alphanumeric())
                 } else {
@@ -374,8 +435,8 @@ where
                 Some(Ok((start_location, token, self.cursor)))
             }
             _ if c.is_ascii_digit() => {
-                let integer = self.read_alphanumeric();
-                Some(Ok((start_location, TokenKind::IntegerLiteral(integer), self.cursor)))
+                let literal = self.read_numeric_literal();
+                Some(Ok((start_location, literal, self.cursor)))
             }
             _ if c.is_whitespace() => {
                 self.skip_whitespace();
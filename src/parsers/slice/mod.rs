@@ -54,6 +54,7 @@ fn clean_message(expected: &[String]) -> String {
             "identifier" => "identifier".to_owned(),
             "string_literal" => "string literal".to_owned(),
             "integer_literal" => "integer literal".to_owned(),
+            "float_literal" => "float literal".to_owned(),
             "doc_comment" => "doc comment".to_owned(),
 
             // Definition keywords
@@ -65,6 +66,7 @@ fn clean_message(expected: &[String]) -> String {
             "enum_keyword" => tokens::TokenKind::EnumKeyword.to_string(),
             "custom_keyword" => tokens::TokenKind::CustomKeyword.to_string(),
             "type_alias_keyword" => tokens::TokenKind::TypeAliasKeyword.to_string(),
+            "const_keyword" => tokens::TokenKind::ConstKeyword.to_string(),
 
             // Collection keywords
             "sequence_keyword" => tokens::TokenKind::SequenceKeyword.to_string(),
@@ -93,6 +95,7 @@ fn clean_message(expected: &[String]) -> String {
             "any_exception_keyword" => tokens::TokenKind::AnyExceptionKeyword.to_string(),
             "compact_keyword" => tokens::TokenKind::CompactKeyword.to_string(),
             "idempotent_keyword" => tokens::TokenKind::IdempotentKeyword.to_string(),
+            "import_keyword" => tokens::TokenKind::ImportKeyword.to_string(),
             "mode_keyword" => tokens::TokenKind::ModeKeyword.to_string(),
             "stream_keyword" => tokens::TokenKind::StreamKeyword.to_string(),
             "tag_keyword" => tokens::TokenKind::TagKeyword.to_string(),
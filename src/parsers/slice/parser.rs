@@ -48,7 +48,8 @@ impl<'a> Parser<'a> {
         (
             Option<FileCompilationMode>,
             Vec<WeakPtr<Attribute>>,
-            Option<OwnedPtr<Module>>,
+            Vec<Import>,
+            Vec<OwnedPtr<Module>>,
             Vec<Definition>,
         ),
     );
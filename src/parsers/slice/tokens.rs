@@ -21,6 +21,10 @@ pub enum TokenKind<'input> {
     /// We allow alphanumeric characters to support hex literals.
     IntegerLiteral(&'input str), // "[0-9][_a-zA-Z0-9]*"
 
+    /// A floating point literal consists of an integer part, a '.' character, and a fractional part.
+    /// The fractional part can optionally end with an exponent, which may be signed.
+    FloatLiteral(&'input str), // "[0-9][_a-zA-Z0-9]*\.[0-9][_a-zA-Z0-9]*([+-][_a-zA-Z0-9]*)?"
+
     /// A string literal consists of any non-newline characters contained within a pair of unescaped double-quotes.
     /// Note that the value doesn't contain the enclosing quotation marks, only the characters in between them.
     StringLiteral(&'input str),
@@ -29,6 +33,22 @@ pub enum TokenKind<'input> {
     /// Note that the value doesn't contain the slashes or the newline, only the characters in between them.
     DocComment(&'input str),
 
+    /// Line comments are preceded by 2 forward slashes ("//") and continue until end of line.
+    /// Note that the value contains the slashes, but not the newline.
+    ///
+    /// These tokens are only returned by lexers which keep trivia (see [`Lexer::with_trivia`]).
+    ///
+    /// [`Lexer::with_trivia`]: super::lexer::Lexer::with_trivia
+    LineComment(&'input str),
+
+    /// Block comments are any characters contained within a pair of "/*" and "*/" delimiters.
+    /// Note that the value contains the delimiters, and can span multiple lines.
+    ///
+    /// These tokens are only returned by lexers which keep trivia (see [`Lexer::with_trivia`]).
+    ///
+    /// [`Lexer::with_trivia`]: super::lexer::Lexer::with_trivia
+    BlockComment(&'input str),
+
     // Definition keywords
     ModuleKeyword,    // "module"
     StructKeyword,    // "struct"
@@ -38,6 +58,7 @@ pub enum TokenKind<'input> {
     EnumKeyword,      // "enum"
     CustomKeyword,    // "custom"
     TypeAliasKeyword, // "typealias"
+    ConstKeyword,     // "const"
 
     // Collection keywords
     SequenceKeyword,   // "sequence"
@@ -66,6 +87,7 @@ pub enum TokenKind<'input> {
     AnyExceptionKeyword, // "AnyException"
     CompactKeyword,      // "compact"
     IdempotentKeyword,   // "idempotent"
+    ImportKeyword,       // "import"
     ModeKeyword,         // "mode"
     StreamKeyword,       // "stream"
     TagKeyword,          // "tag"
@@ -99,8 +121,11 @@ impl fmt::Display for TokenKind<'_> {
         f.write_str(match self {
             Self::Identifier(input) => input,
             Self::IntegerLiteral(input) => input,
+            Self::FloatLiteral(input) => input,
             Self::StringLiteral(input) => input,
             Self::DocComment(input) => input,
+            Self::LineComment(input) => input,
+            Self::BlockComment(input) => input,
 
             // Keywords
             Self::ModuleKeyword => "module",
@@ -111,6 +136,7 @@ impl fmt::Display for TokenKind<'_> {
             Self::EnumKeyword => "enum",
             Self::CustomKeyword => "custom",
             Self::TypeAliasKeyword => "typealias",
+            Self::ConstKeyword => "const",
             Self::SequenceKeyword => "sequence",
             Self::DictionaryKeyword => "dictionary",
             Self::BoolKeyword => "bool",
@@ -133,6 +159,7 @@ impl fmt::Display for TokenKind<'_> {
             Self::AnyExceptionKeyword => "AnyException",
             Self::CompactKeyword => "compact",
             Self::IdempotentKeyword => "idempotent",
+            Self::ImportKeyword => "import",
             Self::ModeKeyword => "mode",
             Self::StreamKeyword => "stream",
             Self::TagKeyword => "tag",
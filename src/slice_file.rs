@@ -3,7 +3,7 @@
 use crate::grammar::*;
 use crate::utils::ptr_util::WeakPtr;
 use console::style;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Write};
 
 const EXPANDED_TAB: &str = "    ";
@@ -12,7 +12,7 @@ const SPACE: &str = " ";
 /// Stores the row and column numbers of a location in a Slice file.
 /// These values are indexed starting at 1 instead of 0 for human readability.
 /// Ex: (1,1) is the start of a file: the first column in the first row.
-#[derive(Serialize, Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Location {
     pub row: usize,
     pub col: usize,
@@ -33,7 +33,7 @@ impl Default for Location {
     }
 }
 
-#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Span {
     pub start: Location,
     pub end: Location,
@@ -54,8 +54,10 @@ pub struct SliceFile {
     pub raw_text: String,
 
     pub mode: Option<FileCompilationMode>,
-    pub module: Option<WeakPtr<Module>>,
+    /// The modules declared in this file, in the order they were declared.
+    pub modules: Vec<WeakPtr<Module>>,
     pub attributes: Vec<WeakPtr<Attribute>>,
+    pub imports: Vec<Import>,
     pub contents: Vec<Definition>,
 
     pub is_source: bool,
@@ -76,8 +78,9 @@ impl SliceFile {
             relative_path,
             raw_text,
             mode: None,
-            module: None,
+            modules: Vec::new(),
             attributes: Vec::new(),
+            imports: Vec::new(),
             contents: Vec::new(),
             is_source,
         }
@@ -147,6 +150,61 @@ impl SliceFile {
 
         formatted_snippet + &line_prefix
     }
+
+    /// Retrieves a formatted snippet that shows the lines covered by `span`, both before (marked with '-') and after
+    /// (marked with '+') the text covered by `span` is replaced with `replacement`.
+    pub(crate) fn get_suggestion_snippet(&self, span: &Span, replacement: &str) -> String {
+        let (start, end) = (span.start, span.end);
+        debug_assert!(start <= end);
+
+        // The number of columns that should be reserved for displaying line numbers to the left of snippets.
+        let line_number_prefix_length = end.row.to_string().len() + 1;
+        let line_prefix = style(format!("{:<line_number_prefix_length$}|", ""))
+            .blue()
+            .bold()
+            .to_string();
+
+        // The lines of text that are affected by the replacement.
+        let original_lines = self
+            .raw_text
+            .lines()
+            .skip(start.row - 1)
+            .take(end.row - start.row + 1)
+            .collect::<Vec<_>>();
+
+        // Construct the replaced text from: the start of the first line, the replacement, and the end of the last line.
+        let first_line = original_lines.first().copied().unwrap_or_default();
+        let last_line = original_lines.last().copied().unwrap_or_default();
+        let before = first_line.chars().take(start.col - 1).collect::<String>();
+        let after = last_line.chars().skip(end.col - 1).collect::<String>();
+        let replaced_text = before + replacement + &after;
+
+        // The formatted snippet.
+        let mut formatted_snippet = line_prefix.clone() + "\n";
+
+        for (i, line) in original_lines.iter().enumerate() {
+            let prefix = style(format!("{:<line_number_prefix_length$}-", start.row + i))
+                .red()
+                .bold();
+            let space_separated_line = line.replace('\t', EXPANDED_TAB);
+            writeln!(formatted_snippet, "{prefix} {space_separated_line}").expect("failed to write snippet");
+        }
+
+        // If the replacement removed everything except whitespace, we don't show the (effectively empty) result.
+        for (i, line) in replaced_text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+        {
+            let prefix = style(format!("{:<line_number_prefix_length$}+", start.row + i))
+                .green()
+                .bold();
+            let space_separated_line = line.replace('\t', EXPANDED_TAB);
+            writeln!(formatted_snippet, "{prefix} {space_separated_line}").expect("failed to write snippet");
+        }
+
+        formatted_snippet + &line_prefix
+    }
 }
 
 implement_Attributable_for!(SliceFile);
@@ -6,6 +6,9 @@ use clap::{Parser, ValueEnum};
 
 // Note: clap uses the doc-comments of fields to populate the '--help' output of slicec-xxx.
 //       boolean flags automatically default to false, and strings automatically default to empty.
+//
+// Note: '-D' is already used for defining preprocessor symbols, so '--deny' has no short form.
+// The 'Lint levels' section of the README documents how the lint flags interact with each other and with attributes.
 
 /// This struct is responsible for parsing the command line options common to all slice compilers.
 /// The option parsing capabilities are generated on the struct by the `clap` macro.
@@ -20,14 +23,35 @@ pub struct SliceOptions {
     #[arg(short = 'R', value_name="REFERENCE", num_args = 1, action = Append)]
     pub references: Vec<String>,
 
-    /// Define a preprocessor symbol.
-    #[arg(short = 'D', value_name="SYMBOL", num_args = 1, action = Append)]
+    /// Define a preprocessor symbol, optionally with an integer value: 'SYMBOL' or 'SYMBOL=VALUE'.
+    #[arg(short = 'D', value_name="SYMBOL[=VALUE]", num_args = 1, action = Append)]
     pub defined_symbols: Vec<String>,
 
     /// Instruct the compiler to allow the specified lint.
+    /// Attributes in Slice files take precedence over this option.
     #[arg(short = 'A', long = "allow", value_name="LINT_NAME", value_parser = Lint::ALLOWABLE_LINT_IDENTIFIERS, num_args = 1, action = Append)]
     pub allowed_lints: Vec<String>,
 
+    /// Instruct the compiler to emit warnings for the specified lint, even if it's allowed by default.
+    /// Attributes in Slice files take precedence over this option.
+    #[arg(short = 'W', long = "warn", value_name="LINT_NAME", value_parser = Lint::ALLOWABLE_LINT_IDENTIFIERS, num_args = 1, action = Append)]
+    pub warned_lints: Vec<String>,
+
+    /// Instruct the compiler to emit errors for the specified lint, causing compilation to fail if it's triggered.
+    /// Attributes in Slice files take precedence over this option.
+    /// This option has no short form, since '-D' defines preprocessor symbols.
+    #[arg(long = "deny", value_name="LINT_NAME", value_parser = Lint::ALLOWABLE_LINT_IDENTIFIERS, num_args = 1, action = Append)]
+    pub denied_lints: Vec<String>,
+
+    /// Instruct the compiler to emit any lints that would be warnings as errors instead.
+    /// This is applied after all other options and attributes.
+    #[arg(long)]
+    pub warnings_as_errors: bool,
+
+    /// Only allow files to use definitions from themselves, and from the files and definitions they import.
+    #[arg(long)]
+    pub strict_imports: bool,
+
     /// Validate input files without generating code for them.
     #[arg(long)]
     pub dry_run: bool,
@@ -36,6 +60,14 @@ pub struct SliceOptions {
     #[arg(short = 'O', long)]
     pub output_dir: Option<String>,
 
+    /// Write the definitions of the compiled files as JSON to the specified path (see `slicec::ast_json`).
+    #[arg(long, value_name = "PATH")]
+    pub emit_ast_json: Option<String>,
+
+    /// Cache parsed files in the specified directory, so unchanged files don't need to be parsed again.
+    #[arg(long, value_name = "DIRECTORY")]
+    pub parse_cache: Option<String>,
+
     /// Set the output format for emitted errors.
     #[arg(value_enum, default_value_t = DiagnosticFormat::Human, long, ignore_case = true)]
     pub diagnostic_format: DiagnosticFormat,
@@ -50,9 +82,11 @@ pub struct SliceOptions {
 /// # Variants
 /// * Human - Any emitted diagnostics will be printed to the console with an easily readable format.
 /// * Json - Any emitted diagnostics will be serialized as JSON objects and printed to the console.
+/// * Sarif - Any emitted diagnostics will be serialized as a single SARIF 2.1.0 log and printed to the console.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
 pub enum DiagnosticFormat {
     #[default]
     Human,
     Json,
+    Sarif,
 }
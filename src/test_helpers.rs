@@ -23,8 +23,9 @@ pub fn diagnostics_from_compilation_state(compilation_state: CompilationState) -
 /// - It has the correct message.
 /// - If a span was expected, that it has the correct span.
 /// - If notes are expected, we check that all the notes have correct messages and spans.
+/// - If suggestions are expected, we check that all the suggestions have correct messages, spans, and replacements.
 ///
-/// If the expected diagnostics don't include spans or notes, this function doesn't check them.
+/// If the expected diagnostics don't include spans, notes, or suggestions, this function doesn't check them.
 /// This is useful for the majority of tests that aren't explicitly testing spans or notes.
 pub fn check_diagnostics<const L: usize>(diagnostics: Vec<Diagnostic>, expected: [impl Into<Diagnostic>; L]) {
     // Check that the correct number of diagnostics were emitted.
@@ -106,6 +107,35 @@ pub fn check_diagnostics<const L: usize>(diagnostics: Vec<Diagnostic>, expected:
             }
         }
 
+        // If suggestions were provided, check that they match.
+        if !expect.suggestions().is_empty() {
+            let expected_suggestions = expect.suggestions();
+            let emitted_suggestions = diagnostic.suggestions();
+            if expected_suggestions.len() != emitted_suggestions.len() {
+                eprintln!(
+                    "Expected {} suggestions, but got {}.",
+                    expected_suggestions.len(),
+                    emitted_suggestions.len()
+                );
+                eprintln!("The emitted suggestions were:");
+                for suggestion in emitted_suggestions {
+                    eprintln!("\t{suggestion:?}");
+                }
+                failed = true;
+            } else {
+                for (expected, emitted) in expected_suggestions.iter().zip(emitted_suggestions) {
+                    let expected_fields = (&expected.message, &expected.span, &expected.replacement);
+                    let emitted_fields = (&emitted.message, &emitted.span, &emitted.replacement);
+                    if expected_fields != emitted_fields {
+                        eprintln!("suggestions didn't match:");
+                        eprintln!("\texpected: \"{expected:?}\"");
+                        eprintln!("\t but got: \"{emitted:?}\"");
+                        failed = true;
+                    }
+                }
+            }
+        }
+
         // If the checks failed, panic to signal a test failure.
         if failed {
             eprintln!();
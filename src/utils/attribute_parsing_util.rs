@@ -1,12 +1,12 @@
 // Copyright (c) ZeroC, Inc.
 
-use crate::diagnostics::{Diagnostic, DiagnosticReporter, Error};
-use crate::grammar::AttributeKind;
+use crate::diagnostics::{Diagnostic, DiagnosticReporter, Error, Lint};
+use crate::grammar::{AttributeArgument, AttributeKind};
 use crate::slice_file::Span;
 
 /// Reports an error if the provided list of arguments is empty.
 pub fn check_that_arguments_were_provided(
-    arguments: &Vec<String>,
+    arguments: &[AttributeArgument],
     directive: &str,
     span: &Span,
     diagnostic_reporter: &mut DiagnosticReporter,
@@ -22,7 +22,7 @@ pub fn check_that_arguments_were_provided(
 
 /// Reports an error if the provided list of arguments is non-empty.
 pub fn check_that_no_arguments_were_provided(
-    arguments: &Vec<String>,
+    arguments: &[AttributeArgument],
     directive: &str,
     span: &Span,
     diagnostic_reporter: &mut DiagnosticReporter,
@@ -38,7 +38,7 @@ pub fn check_that_no_arguments_were_provided(
 
 /// Reports an error if the provided list of arguments has more than 1 element.
 pub fn check_that_at_most_one_argument_was_provided(
-    arguments: &Vec<String>,
+    arguments: &[AttributeArgument],
     directive: &str,
     span: &Span,
     diagnostic_reporter: &mut DiagnosticReporter,
@@ -54,7 +54,7 @@ pub fn check_that_at_most_one_argument_was_provided(
 
 /// Reports an error if the provided list of arguments doesn't have exactly 1 element.
 pub fn check_that_exactly_one_argument_was_provided(
-    arguments: &Vec<String>,
+    arguments: &[AttributeArgument],
     directive: &str,
     span: &Span,
     diagnostic_reporter: &mut DiagnosticReporter,
@@ -82,3 +82,52 @@ pub fn report_unexpected_attribute(
 
     diagnostic.report(diagnostic_reporter);
 }
+
+/// Parses the arguments of a lint-configuring attribute (`allow`, `warn`, or `deny`) into a list of lint names.
+/// Reports an error if no arguments were provided, or for each argument that isn't the name of a configurable lint.
+pub fn parse_lint_arguments(
+    arguments: &[AttributeArgument],
+    directive: &str,
+    span: &Span,
+    diagnostic_reporter: &mut DiagnosticReporter,
+) -> Vec<String> {
+    check_that_arguments_were_provided(arguments, directive, span, diagnostic_reporter);
+
+    let mut lints = Vec::new();
+    for arg in arguments {
+        let lint = arg.as_str().unwrap_or_default();
+        let mut is_valid = Lint::ALLOWABLE_LINT_IDENTIFIERS.contains(&lint);
+
+        // The `DuplicateFile` lint can't be configured by attributes because it's a command-line specific lint.
+        if lint == "DuplicateFile" {
+            is_valid = false;
+        }
+
+        // Emit an error if the argument wasn't valid.
+        if !is_valid {
+            // TODO we should emit a link to the lint page when we write it!
+            let mut error = Diagnostic::new(Error::ArgumentNotSupported {
+                argument: arg.to_string(),
+                directive: directive.to_owned(),
+            })
+            .set_span(&arg.span);
+
+            // Check if the argument only differs in case from a valid one.
+            let suggestion = Lint::ALLOWABLE_LINT_IDENTIFIERS
+                .iter()
+                .find(|identifier| identifier.eq_ignore_ascii_case(lint));
+            if let Some(identifier) = suggestion {
+                let message = format!("attribute arguments are case sensitive, perhaps you meant '{identifier}'?");
+                error = error.add_note(message, None).add_suggestion(
+                    format!("replace with '{identifier}'"),
+                    &arg.span,
+                    *identifier,
+                );
+            }
+
+            error.report(diagnostic_reporter);
+        }
+        lints.push(arg.to_string());
+    }
+    lints
+}
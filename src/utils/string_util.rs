@@ -4,3 +4,22 @@
 pub fn indefinite_article(s: &str) -> String {
     in_definite::get_a_or_an(s).to_lowercase()
 }
+
+/// Returns the Levenshtein distance between the provided strings: the minimum number of single character insertions,
+/// deletions, or substitutions required to turn one string into the other.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+
+    // We only keep a single row of the distance matrix, since each row only depends on the row before it.
+    let mut distances = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut previous_diagonal = distances[0];
+        distances[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution_cost = previous_diagonal + usize::from(a_char != *b_char);
+            previous_diagonal = distances[j + 1];
+            distances[j + 1] = substitution_cost.min(distances[j] + 1).min(previous_diagonal + 1);
+        }
+    }
+    distances[b.len()]
+}
@@ -1,10 +1,63 @@
 // Copyright (c) ZeroC, Inc.
 
 use crate::diagnostics::{Diagnostic, DiagnosticReporter, Lint};
+use crate::grammar::attributes::Deprecated;
 use crate::grammar::*;
 
 pub fn validate_common_doc_comments(commentable: &dyn Commentable, diagnostic_reporter: &mut DiagnosticReporter) {
     only_operations_can_throw(commentable, diagnostic_reporter);
+    deprecated_tag_matches_attribute(commentable, diagnostic_reporter);
+    since_tag_is_not_repeated(commentable, diagnostic_reporter);
+    has_doc_comment(commentable, diagnostic_reporter);
+}
+
+pub fn validate_operation_doc_comments(operation: &Operation, diagnostic_reporter: &mut DiagnosticReporter) {
+    parameters_are_documented(operation, diagnostic_reporter);
+    return_members_are_documented(operation, diagnostic_reporter);
+    thrown_exception_is_documented(operation, diagnostic_reporter);
+}
+
+pub fn validate_parameter_doc_comments(parameter: &Parameter, diagnostic_reporter: &mut DiagnosticReporter) {
+    inline_comment_does_not_conflict_with_operation_tags(parameter, diagnostic_reporter);
+}
+
+fn inline_comment_does_not_conflict_with_operation_tags(
+    parameter: &Parameter,
+    diagnostic_reporter: &mut DiagnosticReporter,
+) {
+    let (Some(comment), Some(operation_comment)) = (parameter.comment(), parameter.parent().comment()) else {
+        return;
+    };
+
+    // Parameters are documented by `@param` tags, and return members are documented by `@returns` tags.
+    let (tag_name, conflicting_tag) = if parameter.is_returned {
+        let tag = operation_comment
+            .returns
+            .iter()
+            .find(|tag| matches!(&tag.identifier, Some(i) if i.value == parameter.identifier()));
+        ("@returns", tag.map(|tag| tag.span()))
+    } else {
+        let tag = operation_comment
+            .params
+            .iter()
+            .find(|tag| tag.identifier.value == parameter.identifier());
+        ("@param", tag.map(|tag| tag.span()))
+    };
+
+    if let Some(tag_span) = conflicting_tag {
+        Diagnostic::new(Lint::IncorrectDocComment {
+            message: format!(
+                "{} '{}' has a doc comment, but is also documented by a '{tag_name}' tag on its operation",
+                parameter.kind(),
+                parameter.identifier(),
+            ),
+        })
+        .set_span(comment.span())
+        .add_note(format!("the '{tag_name}' tag is here:"), Some(tag_span))
+        .add_note("parameters should only be documented in one place", None)
+        .set_scope(parameter.parser_scoped_identifier())
+        .report(diagnostic_reporter);
+    }
 }
 
 fn only_operations_can_throw(commentable: &dyn Commentable, diagnostic_reporter: &mut DiagnosticReporter) {
@@ -26,3 +79,135 @@ fn only_operations_can_throw(commentable: &dyn Commentable, diagnostic_reporter:
         }
     }
 }
+
+fn deprecated_tag_matches_attribute(commentable: &dyn Commentable, diagnostic_reporter: &mut DiagnosticReporter) {
+    let Some(comment) = commentable.comment() else { return };
+    let has_attribute = commentable.has_attribute::<Deprecated>();
+
+    match comment.deprecated.first() {
+        Some(deprecated_tag) if !has_attribute => {
+            Diagnostic::new(Lint::IncorrectDocComment {
+                message: format!(
+                    "doc comment indicates that {} '{}' is deprecated, but it doesn't have a 'deprecated' attribute",
+                    commentable.kind(),
+                    commentable.identifier(),
+                ),
+            })
+            .set_span(deprecated_tag.span())
+            .add_note(
+                "consider adding a 'deprecated' attribute, or removing the '@deprecated' tag",
+                None,
+            )
+            .set_scope(commentable.parser_scoped_identifier())
+            .report(diagnostic_reporter);
+        }
+        None if has_attribute => {
+            Diagnostic::new(Lint::IncorrectDocComment {
+                message: format!(
+                    "{} '{}' has a 'deprecated' attribute, but its doc comment is missing a '@deprecated' tag",
+                    commentable.kind(),
+                    commentable.identifier(),
+                ),
+            })
+            .set_span(comment.span())
+            .set_scope(commentable.parser_scoped_identifier())
+            .report(diagnostic_reporter);
+        }
+        _ => {}
+    }
+}
+
+fn since_tag_is_not_repeated(commentable: &dyn Commentable, diagnostic_reporter: &mut DiagnosticReporter) {
+    let Some(comment) = commentable.comment() else { return };
+    let Some((original, duplicates)) = comment.since.split_first() else { return };
+
+    for since_tag in duplicates {
+        Diagnostic::new(Lint::IncorrectDocComment {
+            message: format!(
+                "doc comment for {} '{}' has multiple '@since' tags",
+                commentable.kind(),
+                commentable.identifier(),
+            ),
+        })
+        .set_span(since_tag.span())
+        .add_note("the first '@since' tag is here:", Some(original.span()))
+        .set_scope(commentable.parser_scoped_identifier())
+        .report(diagnostic_reporter);
+    }
+}
+
+fn has_doc_comment(commentable: &dyn Commentable, diagnostic_reporter: &mut DiagnosticReporter) {
+    // Modules can be declared in many places, so we don't require them to be documented.
+    // Parameters and return members are checked separately, since they can also be documented by their operation.
+    let exempt_kinds = ["module", "parameter", "return element"];
+    if commentable.comment().is_none() && !exempt_kinds.contains(&commentable.kind()) {
+        Diagnostic::new(Lint::MissingDocComment {
+            kind: commentable.kind().to_owned(),
+            identifier: commentable.identifier().to_owned(),
+        })
+        .set_span(commentable.span())
+        .set_scope(commentable.parser_scoped_identifier())
+        .report(diagnostic_reporter);
+    }
+}
+
+fn parameters_are_documented(operation: &Operation, diagnostic_reporter: &mut DiagnosticReporter) {
+    let param_tags = operation.comment().map_or(&[][..], |comment| &comment.params);
+
+    for parameter in operation.parameters() {
+        let has_param_tag = param_tags
+            .iter()
+            .any(|tag| tag.identifier.value == parameter.identifier());
+        if !has_param_tag && parameter.comment().is_none() {
+            Diagnostic::new(Lint::MissingParamDoc {
+                identifier: parameter.identifier().to_owned(),
+                operation: operation.identifier().to_owned(),
+            })
+            .set_span(parameter.span())
+            .set_scope(operation.parser_scoped_identifier())
+            .report(diagnostic_reporter);
+        }
+    }
+}
+
+fn return_members_are_documented(operation: &Operation, diagnostic_reporter: &mut DiagnosticReporter) {
+    let return_members = operation.return_members();
+    if return_members.is_empty() {
+        return;
+    }
+
+    // Return values can either be documented by `@returns` tags, or by doc comments on each of the return members.
+    let has_returns_tag = operation.comment().is_some_and(|comment| !comment.returns.is_empty());
+    let are_members_documented = return_members.iter().all(|member| member.comment().is_some());
+    if !has_returns_tag && !are_members_documented {
+        Diagnostic::new(Lint::MissingReturnsDoc {
+            operation: operation.identifier().to_owned(),
+        })
+        .set_span(operation.span())
+        .set_scope(operation.parser_scoped_identifier())
+        .report(diagnostic_reporter);
+    }
+}
+
+fn thrown_exception_is_documented(operation: &Operation, diagnostic_reporter: &mut DiagnosticReporter) {
+    let Throws::Specific(exception_ref) = &operation.throws else { return };
+    let exception = exception_ref.definition();
+
+    // A `@throws` tag without a type documents whatever exception the operation throws.
+    let throws_tags = operation.comment().map_or(&[][..], |comment| &comment.throws);
+    let is_documented = throws_tags.iter().any(|tag| match tag.thrown_type() {
+        Some(Ok(thrown_type)) => thrown_type.parser_scoped_identifier() == exception.parser_scoped_identifier(),
+        Some(Err(_)) => false,
+        None => true,
+    });
+
+    if !is_documented {
+        Diagnostic::new(Lint::UndocumentedThrows {
+            operation: operation.identifier().to_owned(),
+            exception: exception.identifier().to_owned(),
+        })
+        .set_span(exception_ref.span())
+        .set_scope(operation.parser_scoped_identifier())
+        .report(diagnostic_reporter);
+    }
+}
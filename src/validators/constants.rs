@@ -0,0 +1,35 @@
+// Copyright (c) ZeroC, Inc.
+
+use super::literals::validate_literal;
+use crate::diagnostics::{Diagnostic, DiagnosticReporter, Error};
+use crate::grammar::*;
+
+pub fn validate_constant(constant: &Constant, diagnostic_reporter: &mut DiagnosticReporter) {
+    // Only check the constant's value if its type is valid, otherwise we'd just report the same problem twice.
+    if has_supported_type(constant, diagnostic_reporter) {
+        validate_literal(&constant.value, &constant.data_type, diagnostic_reporter);
+    }
+}
+
+/// Validate that the constant's type is a non-optional primitive (other than `AnyClass`) or enum.
+fn has_supported_type(constant: &Constant, diagnostic_reporter: &mut DiagnosticReporter) -> bool {
+    let data_type = &constant.data_type;
+    let is_supported = match data_type.concrete_type() {
+        Types::Primitive(primitive) => *primitive != Primitive::AnyClass,
+        Types::Enum(_) => true,
+        _ => false,
+    };
+
+    if is_supported && !data_type.is_optional {
+        return true;
+    }
+
+    Diagnostic::new(Error::ConstantTypeNotSupported {
+        identifier: constant.identifier().to_owned(),
+        kind: data_type.type_string(),
+    })
+    .set_span(data_type.span())
+    .add_note("constants must have a non-optional primitive or enum type", None)
+    .report(diagnostic_reporter);
+    false
+}
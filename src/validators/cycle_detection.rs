@@ -3,8 +3,7 @@
 use crate::ast::node::Node;
 use crate::ast::Ast;
 use crate::diagnostics::{Diagnostic, DiagnosticReporter, Error};
-use crate::grammar::{Container, Field, Member, Types};
-use crate::utils::ptr_util::WeakPtr;
+use crate::grammar::{Entity, Enumerator, Field, Member, Type, Types};
 
 pub(super) fn detect_cycles(ast: &Ast, diagnostic_reporter: &mut DiagnosticReporter) {
     let mut cycle_detector = CycleDetector {
@@ -15,10 +14,11 @@ pub(super) fn detect_cycles(ast: &Ast, diagnostic_reporter: &mut DiagnosticRepor
     for node in ast.as_slice() {
         cycle_detector.dependency_stack.clear(); // Make sure the detector is cleared between checks.
         match node {
-            // We only check structs and exceptions since these are the only types that can cause infinite cycles.
-            // Classes can safely contain cycles since they use reference semantics.
-            Node::Struct(struct_def) => cycle_detector.check_for_cycles(struct_def.borrow()),
-            Node::Exception(exception_def) => cycle_detector.check_for_cycles(exception_def.borrow()),
+            // We only check structs, exceptions, and enums since these are the only types that can cause infinite
+            // cycles. Classes can safely contain cycles since they use reference semantics.
+            Node::Struct(struct_def) => cycle_detector.check_type_for_cycles(struct_def.borrow()),
+            Node::Exception(exception_def) => cycle_detector.check_type_for_cycles(exception_def.borrow()),
+            Node::Enum(enum_def) => cycle_detector.check_type_for_cycles(enum_def.borrow()),
             _ => false,
         };
     }
@@ -33,7 +33,20 @@ struct CycleDetector<'a> {
 }
 
 impl CycleDetector<'_> {
-    fn check_for_cycles(&mut self, container: &impl Container<WeakPtr<Field>>) -> bool {
+    fn check_type_for_cycles(&mut self, type_def: &dyn Type) -> bool {
+        match type_def.concrete_type() {
+            Types::Struct(struct_def) => self.check_for_cycles(struct_def, struct_def.fields()),
+            Types::Exception(exception_def) => self.check_for_cycles(exception_def, exception_def.fields()),
+            Types::Enum(enum_def) => {
+                // Enums can only be cyclic through the fields associated with their enumerators.
+                let fields = enum_def.enumerators().into_iter().flat_map(Enumerator::fields);
+                self.check_for_cycles(enum_def, fields.collect())
+            }
+            _ => false,
+        }
+    }
+
+    fn check_for_cycles(&mut self, container: &dyn Entity, fields: Vec<&Field>) -> bool {
         let type_id = container.module_scoped_identifier();
 
         if self.dependency_stack.first() == Some(&type_id) {
@@ -51,12 +64,8 @@ impl CycleDetector<'_> {
         } else {
             // If this container's identifier isn't in the stack, we check its fields for cycles.
             self.dependency_stack.push(type_id);
-            for field in container.contents() {
-                let cycle_was_found = match field.borrow().data_type().concrete_type() {
-                    Types::Struct(struct_def) => self.check_for_cycles(struct_def),
-                    Types::Exception(exception_def) => self.check_for_cycles(exception_def),
-                    _ => false,
-                };
+            for field in fields {
+                let cycle_was_found = self.check_type_for_cycles(field.data_type().definition());
 
                 // If a cycle was found, stop searching and return immediately.
                 if cycle_was_found {
@@ -51,7 +51,8 @@ fn check_dictionary_key_type(type_ref: &TypeRef) -> Option<Diagnostic> {
         Types::Class(_) => false,
         Types::Exception(_) => false,
         Types::Interface(_) => false,
-        Types::Enum(_) => true,
+        // Enums with associated fields cannot be used for dictionary keys.
+        Types::Enum(enum_def) => !enum_def.has_enumerators_with_fields(),
         Types::CustomType(_) => true,
         Types::Sequence(_) => false,
         Types::Dictionary(_) => false,
@@ -11,6 +11,8 @@ pub fn validate_enum(enum_def: &Enum, diagnostic_reporter: &mut DiagnosticReport
     enumerator_values_are_unique(enum_def, diagnostic_reporter);
     underlying_type_cannot_be_optional(enum_def, diagnostic_reporter);
     nonempty_if_checked(enum_def, diagnostic_reporter);
+    enumerators_with_fields_have_no_underlying_type(enum_def, diagnostic_reporter);
+    flags_values_are_powers_of_two(enum_def, diagnostic_reporter);
 }
 
 /// Validate that the enumerators are within the bounds of the specified underlying type.
@@ -67,18 +69,16 @@ fn backing_type_bounds(enum_def: &Enum, diagnostic_reporter: &mut DiagnosticRepo
 
 /// Validate that the backing type (if present) is an integral type.
 fn allowed_underlying_types(enum_def: &Enum, diagnostic_reporter: &mut DiagnosticReporter) {
-    match &enum_def.underlying {
-        Some(underlying_type) => {
-            if !underlying_type.is_integral() {
-                Diagnostic::new(Error::EnumUnderlyingTypeNotSupported {
-                    enum_identifier: enum_def.identifier().to_owned(),
-                    kind: Some(underlying_type.definition().kind().to_owned()),
-                })
-                .set_span(enum_def.span())
-                .report(diagnostic_reporter);
-            }
+    // If there's no underlying type, the default is varint32 for Slice2 which is integral.
+    if let Some(underlying_type) = &enum_def.underlying {
+        if !underlying_type.is_integral() {
+            Diagnostic::new(Error::EnumUnderlyingTypeNotSupported {
+                enum_identifier: enum_def.identifier().to_owned(),
+                kind: Some(underlying_type.definition().kind().to_owned()),
+            })
+            .set_span(enum_def.span())
+            .report(diagnostic_reporter);
         }
-        None => (), // No underlying type, the default is varint32 for Slice2 which is integral.
     }
 }
 
@@ -127,3 +127,41 @@ fn nonempty_if_checked(enum_def: &Enum, diagnostic_reporter: &mut DiagnosticRepo
         .report(diagnostic_reporter);
     }
 }
+
+/// Validate that enumerators can only have associated fields if their enum doesn't have an underlying type.
+fn enumerators_with_fields_have_no_underlying_type(enum_def: &Enum, diagnostic_reporter: &mut DiagnosticReporter) {
+    if enum_def.underlying.is_some() {
+        for enumerator in enum_def.enumerators().into_iter().filter(|e| e.has_fields()) {
+            Diagnostic::new(Error::EnumeratorCannotContainFields {
+                enumerator_identifier: enumerator.identifier().to_owned(),
+            })
+            .set_span(enumerator.span())
+            .add_note(
+                format!("the underlying type of '{}' is specified here:", enum_def.identifier()),
+                enum_def.underlying.as_ref().map(|type_ref| type_ref.span()),
+            )
+            .report(diagnostic_reporter);
+        }
+    }
+}
+
+/// Validate that the explicit enumerator values of a flags enum are either zero or a power of two.
+fn flags_values_are_powers_of_two(enum_def: &Enum, diagnostic_reporter: &mut DiagnosticReporter) {
+    if !enum_def.is_flags() {
+        return;
+    }
+
+    for enumerator in enum_def.enumerators() {
+        if let EnumeratorValue::Explicit(integer) = &enumerator.value {
+            let value = integer.value;
+            if value != 0 && !(value > 0 && (value as u128).is_power_of_two()) {
+                Diagnostic::new(Error::FlagsEnumeratorValueNotPowerOfTwo {
+                    enumerator_identifier: enumerator.identifier().to_owned(),
+                    value,
+                })
+                .set_span(enumerator.span())
+                .report(diagnostic_reporter);
+            }
+        }
+    }
+}
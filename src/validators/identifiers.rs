@@ -1,8 +1,9 @@
 // Copyright (c) ZeroC, Inc.
 
 use crate::ast::Ast;
-use crate::diagnostics::{Diagnostic, DiagnosticReporter, Error};
+use crate::diagnostics::{Diagnostic, DiagnosticReporter, Error, Lint};
 use crate::grammar::*;
+use convert_case::{Case, Casing};
 use std::collections::HashMap;
 
 pub fn validate_inherited_identifiers(
@@ -13,6 +14,55 @@ pub fn validate_inherited_identifiers(
     check_for_shadowing(symbols, inherited_symbols, diagnostic_reporter);
 }
 
+/// Checks that the provided symbol's identifier follows the Slice naming conventions for its kind of element.
+/// Modules, types, and enumerators should be PascalCase, and operations, parameters, and fields should be camelCase.
+///
+/// Only the shape of identifiers is checked: PascalCase identifiers must start with an uppercase letter, camelCase
+/// identifiers must start with a lowercase letter, and neither can contain underscores. So acronyms like `UUID` or
+/// `getURL` are accepted, even though they aren't what a strict case conversion would produce.
+pub fn validate_naming_convention(symbol: &dyn NamedSymbol, diagnostic_reporter: &mut DiagnosticReporter) {
+    let (case, case_name) = match symbol.kind() {
+        "operation" | "parameter" | "return element" | "field" => (Case::Camel, "camelCase"),
+        _ => (Case::Pascal, "PascalCase"),
+    };
+
+    // Nested modules store their entire identifier (ex: `Foo::Bar`), so we check each segment separately.
+    let identifier = symbol.raw_identifier();
+    if identifier.value.split("::").all(|segment| has_shape_of(segment, case)) {
+        return;
+    }
+
+    // Only convert the segments which are violations, so the suggestion doesn't alter any conventional segments.
+    let segments = identifier
+        .value
+        .split("::")
+        .map(|segment| match has_shape_of(segment, case) {
+            true => segment.to_owned(),
+            false => segment.to_case(case),
+        });
+    let converted = segments.collect::<Vec<_>>().join("::");
+
+    Diagnostic::new(Lint::NamingConvention {
+        kind: symbol.kind().to_owned(),
+        identifier: identifier.value.clone(),
+        case: case_name.to_owned(),
+    })
+    .set_span(identifier.span())
+    .set_scope(symbol.parser_scoped_identifier())
+    .add_suggestion(format!("rename to '{converted}'"), identifier.span(), &converted)
+    .report(diagnostic_reporter);
+}
+
+/// Returns true if the provided identifier starts with a letter of the correct case for the provided [Case], and
+/// doesn't contain any underscores. Only [`Case::Pascal`] and [`Case::Camel`] are supported.
+fn has_shape_of(identifier: &str, case: Case) -> bool {
+    let starts_correctly = match case {
+        Case::Pascal => identifier.starts_with(|c: char| c.is_ascii_uppercase()),
+        _ => identifier.starts_with(|c: char| c.is_ascii_lowercase()),
+    };
+    starts_correctly && !identifier.contains('_')
+}
+
 fn check_for_shadowing(
     symbols: Vec<&impl NamedSymbol>,
     inherited_symbols: Vec<&impl NamedSymbol>,
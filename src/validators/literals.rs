@@ -0,0 +1,83 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::diagnostics::{Diagnostic, DiagnosticReporter, Error};
+use crate::grammar::*;
+
+/// Validate that the provided literal is a legal value for the provided type.
+pub fn validate_literal(literal: &Literal, type_ref: &TypeRef, diagnostic_reporter: &mut DiagnosticReporter) {
+    match (type_ref.concrete_type(), &literal.value) {
+        (Types::Primitive(Primitive::Bool), LiteralValue::Bool(_)) => {}
+        (Types::Primitive(Primitive::String), LiteralValue::String(_)) => {}
+        // Every integer literal is within the bounds of both floating point types, so we only check float literals.
+        (Types::Primitive(Primitive::Float32 | Primitive::Float64), LiteralValue::Integer(_)) => {}
+        (Types::Primitive(primitive @ (Primitive::Float32 | Primitive::Float64)), LiteralValue::Float(value)) => {
+            check_float_bounds(literal, *value, primitive, diagnostic_reporter);
+        }
+        (Types::Primitive(primitive), LiteralValue::Integer(value)) if primitive.is_integral() => {
+            check_numeric_bounds(literal, *value, primitive, diagnostic_reporter);
+        }
+        (Types::Enum(enum_def), LiteralValue::Identifier(identifier)) => {
+            if literal.as_enumerator(enum_def).is_none() {
+                Diagnostic::new(Error::DoesNotExist {
+                    identifier: identifier.clone(),
+                })
+                .set_span(literal.span())
+                .add_note(
+                    format!("'{}' is not an enumerator of '{}'", identifier, enum_def.identifier()),
+                    Some(enum_def.span()),
+                )
+                .report(diagnostic_reporter);
+            }
+        }
+        _ => {
+            Diagnostic::new(Error::LiteralTypeMismatch {
+                expected: type_ref.type_string(),
+                actual: literal.kind().to_owned(),
+            })
+            .set_span(literal.span())
+            .report(diagnostic_reporter);
+        }
+    }
+}
+
+/// Validate that an integer literal is within the bounds of the numeric type it's being used as.
+fn check_numeric_bounds(
+    literal: &Literal,
+    value: i128,
+    primitive: &Primitive,
+    diagnostic_reporter: &mut DiagnosticReporter,
+) {
+    let (min, max) = primitive.numeric_bounds().unwrap();
+    if value < min || value > max {
+        Diagnostic::new(Error::LiteralValueOutOfBounds {
+            value,
+            kind: primitive.kind().to_owned(),
+            min,
+            max,
+        })
+        .set_span(literal.span())
+        .report(diagnostic_reporter);
+    }
+}
+
+/// Validate that a float literal is finite, and within the bounds of the floating point type it's being used as.
+fn check_float_bounds(
+    literal: &Literal,
+    value: f64,
+    primitive: &Primitive,
+    diagnostic_reporter: &mut DiagnosticReporter,
+) {
+    let max = match primitive {
+        Primitive::Float32 => f32::MAX as f64,
+        _ => f64::MAX,
+    };
+    if !(-max..=max).contains(&value) {
+        Diagnostic::new(Error::FloatLiteralOutOfBounds {
+            value,
+            kind: primitive.kind().to_owned(),
+            max,
+        })
+        .set_span(literal.span())
+        .report(diagnostic_reporter);
+    }
+}
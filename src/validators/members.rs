@@ -1,12 +1,35 @@
 // Copyright (c) ZeroC, Inc.
 
+use super::literals::validate_literal;
 use crate::diagnostics::{Diagnostic, DiagnosticReporter, Error};
 use crate::grammar::*;
+use crate::slice_file::Span;
 
 pub fn validate_members(members: Vec<&impl Member>, diagnostic_reporter: &mut DiagnosticReporter) {
     tags_have_optional_types(members.clone(), diagnostic_reporter);
     tagged_members_cannot_use_classes(members.clone(), diagnostic_reporter);
     tags_are_unique(members.clone(), diagnostic_reporter);
+    default_values_are_valid(members, diagnostic_reporter);
+}
+
+/// Validates that default values are only given to members whose types support them, and are legal for those types.
+fn default_values_are_valid(members: Vec<&impl Member>, diagnostic_reporter: &mut DiagnosticReporter) {
+    for member in members {
+        let Some(default_value) = member.default_value() else { continue };
+
+        // Classes and custom types are mapped by the language mappings, so there's no literal we can give them.
+        let data_type = member.data_type();
+        if data_type.is_class_type() || matches!(data_type.concrete_type(), Types::CustomType(_)) {
+            Diagnostic::new(Error::DefaultValueNotSupported {
+                identifier: member.identifier().to_owned(),
+                kind: data_type.type_string(),
+            })
+            .set_span(default_value.span())
+            .report(diagnostic_reporter);
+        } else {
+            validate_literal(default_value, data_type, diagnostic_reporter);
+        }
+    }
 }
 
 /// Validates that the tags are unique.
@@ -41,10 +64,15 @@ fn tags_have_optional_types(members: Vec<&impl Member>, diagnostic_reporter: &mu
     // Validate that tagged members are optional.
     for member in tagged_members {
         if !member.data_type().is_optional {
+            // Suggest inserting a '?' directly after the member's type.
+            let type_span = member.data_type().span();
+            let insertion_span = Span::new(type_span.end, type_span.end, &type_span.file);
+
             Diagnostic::new(Error::TaggedMemberMustBeOptional {
                 identifier: member.identifier().to_owned(),
             })
             .set_span(member.span())
+            .add_suggestion("make the type optional", &insertion_span, "?")
             .report(diagnostic_reporter);
         }
     }
@@ -2,15 +2,18 @@
 
 mod attribute;
 mod comments;
+mod constants;
 mod cycle_detection;
 mod dictionary;
 mod enums;
 mod identifiers;
+mod literals;
 mod members;
 mod operations;
 mod parameters;
 mod structs;
 mod type_aliases;
+mod unused;
 
 use crate::compilation_state::CompilationState;
 use crate::diagnostics::DiagnosticReporter;
@@ -19,10 +22,11 @@ use crate::slice_file::SliceFile;
 use crate::visitor::Visitor;
 
 use attribute::validate_attributes;
-use comments::validate_common_doc_comments;
+use comments::{validate_common_doc_comments, validate_operation_doc_comments, validate_parameter_doc_comments};
+use constants::validate_constant;
 use dictionary::validate_dictionary;
 use enums::validate_enum;
-use identifiers::validate_inherited_identifiers;
+use identifiers::{validate_inherited_identifiers, validate_naming_convention};
 use members::validate_members;
 use operations::validate_operation;
 use parameters::validate_parameters;
@@ -48,6 +52,14 @@ pub(crate) fn validate_ast(compilation_state: &mut CompilationState) {
     for slice_file in compilation_state.files.values() {
         slice_file.visit_with(&mut validator);
     }
+
+    // Check for any types that are defined in source files but are never referenced.
+    unused::check_for_unused_definitions(
+        &compilation_state.ast,
+        &compilation_state.files,
+        &compilation_state.referenced_definitions,
+        diagnostic_reporter,
+    );
 }
 
 struct ValidatorVisitor<'a> {
@@ -66,12 +78,15 @@ impl<'a> Visitor for ValidatorVisitor<'a> {
     }
 
     fn visit_module(&mut self, module_def: &Module) {
+        validate_common_doc_comments(module_def, self.diagnostic_reporter);
         validate_attributes(module_def, self.diagnostic_reporter);
+        validate_naming_convention(module_def, self.diagnostic_reporter);
     }
 
     fn visit_class(&mut self, class: &Class) {
         validate_common_doc_comments(class, self.diagnostic_reporter);
         validate_attributes(class, self.diagnostic_reporter);
+        validate_naming_convention(class, self.diagnostic_reporter);
 
         validate_members(class.fields(), self.diagnostic_reporter);
 
@@ -81,6 +96,7 @@ impl<'a> Visitor for ValidatorVisitor<'a> {
     fn visit_enum(&mut self, enum_def: &Enum) {
         validate_common_doc_comments(enum_def, self.diagnostic_reporter);
         validate_attributes(enum_def, self.diagnostic_reporter);
+        validate_naming_convention(enum_def, self.diagnostic_reporter);
 
         validate_enum(enum_def, self.diagnostic_reporter);
     }
@@ -88,16 +104,21 @@ impl<'a> Visitor for ValidatorVisitor<'a> {
     fn visit_custom_type(&mut self, custom_type: &CustomType) {
         validate_common_doc_comments(custom_type, self.diagnostic_reporter);
         validate_attributes(custom_type, self.diagnostic_reporter);
+        validate_naming_convention(custom_type, self.diagnostic_reporter);
     }
 
     fn visit_enumerator(&mut self, enumerator: &Enumerator) {
         validate_common_doc_comments(enumerator, self.diagnostic_reporter);
         validate_attributes(enumerator, self.diagnostic_reporter);
+        validate_naming_convention(enumerator, self.diagnostic_reporter);
+
+        validate_members(enumerator.fields(), self.diagnostic_reporter);
     }
 
     fn visit_exception(&mut self, exception: &Exception) {
         validate_common_doc_comments(exception, self.diagnostic_reporter);
         validate_attributes(exception, self.diagnostic_reporter);
+        validate_naming_convention(exception, self.diagnostic_reporter);
 
         validate_members(exception.fields(), self.diagnostic_reporter);
 
@@ -111,6 +132,7 @@ impl<'a> Visitor for ValidatorVisitor<'a> {
     fn visit_interface(&mut self, interface: &Interface) {
         validate_common_doc_comments(interface, self.diagnostic_reporter);
         validate_attributes(interface, self.diagnostic_reporter);
+        validate_naming_convention(interface, self.diagnostic_reporter);
 
         validate_inherited_identifiers(
             interface.operations(),
@@ -121,7 +143,9 @@ impl<'a> Visitor for ValidatorVisitor<'a> {
 
     fn visit_operation(&mut self, operation: &Operation) {
         validate_common_doc_comments(operation, self.diagnostic_reporter);
+        validate_operation_doc_comments(operation, self.diagnostic_reporter);
         validate_attributes(operation, self.diagnostic_reporter);
+        validate_naming_convention(operation, self.diagnostic_reporter);
 
         validate_operation(operation, self.diagnostic_reporter);
 
@@ -133,12 +157,16 @@ impl<'a> Visitor for ValidatorVisitor<'a> {
     }
 
     fn visit_parameter(&mut self, parameter: &Parameter) {
+        validate_common_doc_comments(parameter, self.diagnostic_reporter);
+        validate_parameter_doc_comments(parameter, self.diagnostic_reporter);
         validate_attributes(parameter, self.diagnostic_reporter);
+        validate_naming_convention(parameter, self.diagnostic_reporter);
     }
 
     fn visit_struct(&mut self, struct_def: &Struct) {
         validate_common_doc_comments(struct_def, self.diagnostic_reporter);
         validate_attributes(struct_def, self.diagnostic_reporter);
+        validate_naming_convention(struct_def, self.diagnostic_reporter);
 
         validate_struct(struct_def, self.diagnostic_reporter);
 
@@ -148,15 +176,24 @@ impl<'a> Visitor for ValidatorVisitor<'a> {
     fn visit_field(&mut self, field: &Field) {
         validate_common_doc_comments(field, self.diagnostic_reporter);
         validate_attributes(field, self.diagnostic_reporter);
+        validate_naming_convention(field, self.diagnostic_reporter);
     }
 
     fn visit_type_alias(&mut self, type_alias: &TypeAlias) {
         validate_common_doc_comments(type_alias, self.diagnostic_reporter);
         validate_attributes(type_alias, self.diagnostic_reporter);
+        validate_naming_convention(type_alias, self.diagnostic_reporter);
 
         validate_type_alias(type_alias, self.diagnostic_reporter);
     }
 
+    fn visit_constant(&mut self, constant: &Constant) {
+        validate_common_doc_comments(constant, self.diagnostic_reporter);
+        validate_attributes(constant, self.diagnostic_reporter);
+
+        validate_constant(constant, self.diagnostic_reporter);
+    }
+
     fn visit_type_ref(&mut self, type_ref: &TypeRef) {
         validate_attributes(type_ref, self.diagnostic_reporter);
 
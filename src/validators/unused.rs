@@ -0,0 +1,44 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::ast::node::Node;
+use crate::ast::Ast;
+use crate::diagnostics::{Diagnostic, DiagnosticReporter, Lint};
+use crate::grammar::*;
+use crate::slice_file::SliceFile;
+use std::collections::{HashMap, HashSet};
+
+/// Reports a lint for each type that's defined in a source file, but is never referenced.
+///
+/// Interfaces are never reported, since they're the entry points of a Slice API, and nothing is expected to reference
+/// them. Any other type can be exempted with `[allow(Unused)]`. Types defined in reference files are ignored.
+pub fn check_for_unused_definitions(
+    ast: &Ast,
+    files: &HashMap<String, SliceFile>,
+    referenced_definitions: &HashSet<String>,
+    diagnostic_reporter: &mut DiagnosticReporter,
+) {
+    for node in ast.as_slice() {
+        let definition: &dyn Entity = match node {
+            Node::Struct(struct_ptr) => struct_ptr.borrow(),
+            Node::Class(class_ptr) => class_ptr.borrow(),
+            Node::Exception(exception_ptr) => exception_ptr.borrow(),
+            Node::Enum(enum_ptr) => enum_ptr.borrow(),
+            Node::CustomType(custom_type_ptr) => custom_type_ptr.borrow(),
+            Node::TypeAlias(type_alias_ptr) => type_alias_ptr.borrow(),
+            _ => continue,
+        };
+
+        // Only report definitions from source files, since reference files are expected to have unused definitions.
+        let is_source = files.get(&definition.span().file).is_some_and(|file| file.is_source);
+        let identifier = definition.parser_scoped_identifier();
+        if is_source && !referenced_definitions.contains(&identifier) {
+            Diagnostic::new(Lint::Unused {
+                kind: definition.kind().to_owned(),
+                identifier: definition.identifier().to_owned(),
+            })
+            .set_span(definition.raw_identifier().span())
+            .set_scope(identifier)
+            .report(diagnostic_reporter);
+        }
+    }
+}
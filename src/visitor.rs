@@ -75,6 +75,11 @@ pub trait Visitor {
     /// This shouldn't be called by users. To visit a type alias, use `[TypeAlias::visit_with]`.
     fn visit_type_alias(&mut self, type_alias: &TypeAlias);
 
+    /// This function is called by the visitor when it visits a [Constant],
+    ///
+    /// This shouldn't be called by users. To visit a constant, use `[Constant::visit_with]`.
+    fn visit_constant(&mut self, constant: &Constant);
+
     /// This function is called by the visitor when it visits a [Field],
     ///
     /// This shouldn't be called by users. To visit a field, use `[Field::visit_with]`.
@@ -100,29 +105,43 @@ pub trait Visitor {
 impl SliceFile {
     /// Visits the [SliceFile] with the provided `visitor`.
     ///
-    /// This function first calls `visitor.visit_file`, then if the file contains a module declaration it calls
-    /// `visitor.visit_module`, and finally it recursively visits any definitions defined in the file.
+    /// This function first calls `visitor.visit_file`, then for each module declared in the file (in order), it calls
+    /// `visitor.visit_module` and recursively visits any definitions that were defined in that module.
     pub fn visit_with(&self, visitor: &mut impl Visitor) {
         visitor.visit_file(self);
 
-        if let Some(module_def) = &self.module {
+        for module_def in &self.modules {
             module_def.borrow().visit_with(visitor);
-        }
 
-        for definition in &self.contents {
-            match definition {
-                Definition::Struct(struct_def) => struct_def.borrow().visit_with(visitor),
-                Definition::Class(class_def) => class_def.borrow().visit_with(visitor),
-                Definition::Exception(exception_def) => exception_def.borrow().visit_with(visitor),
-                Definition::Interface(interface_def) => interface_def.borrow().visit_with(visitor),
-                Definition::Enum(enum_def) => enum_def.borrow().visit_with(visitor),
-                Definition::CustomType(custom_type) => custom_type.borrow().visit_with(visitor),
-                Definition::TypeAlias(type_alias) => type_alias.borrow().visit_with(visitor),
+            let is_in_module = |definition: &&Definition| match &definition.borrow().get_raw_scope().module {
+                Some(module_ptr) => *module_ptr == module_def.borrow(),
+                None => false,
+            };
+            for definition in self.contents.iter().filter(is_in_module) {
+                definition.visit_with(visitor);
             }
         }
     }
 }
 
+impl Definition {
+    /// Visits the [Definition] with the provided `visitor`.
+    ///
+    /// This function delegates to the `visit_with` function of the underlying definition.
+    pub fn visit_with(&self, visitor: &mut impl Visitor) {
+        match self {
+            Definition::Struct(struct_def) => struct_def.borrow().visit_with(visitor),
+            Definition::Class(class_def) => class_def.borrow().visit_with(visitor),
+            Definition::Exception(exception_def) => exception_def.borrow().visit_with(visitor),
+            Definition::Interface(interface_def) => interface_def.borrow().visit_with(visitor),
+            Definition::Enum(enum_def) => enum_def.borrow().visit_with(visitor),
+            Definition::CustomType(custom_type) => custom_type.borrow().visit_with(visitor),
+            Definition::TypeAlias(type_alias) => type_alias.borrow().visit_with(visitor),
+            Definition::Constant(constant) => constant.borrow().visit_with(visitor),
+        }
+    }
+}
+
 impl Module {
     /// Visits the [Module] with the provided `visitor`.
     ///
@@ -232,6 +251,16 @@ impl TypeAlias {
     }
 }
 
+impl Constant {
+    /// Visits the [Constant] with the provided `visitor`.
+    ///
+    /// This function delegates to `visitor.visit_constant`.
+    pub fn visit_with(&self, visitor: &mut impl Visitor) {
+        visitor.visit_constant(self);
+        self.data_type.visit_with(visitor);
+    }
+}
+
 impl Field {
     /// Visits the [Field] with the provided `visitor`.
     ///
@@ -255,9 +284,13 @@ impl Parameter {
 impl Enumerator {
     /// Visits the [Enumerator] with the provided `visitor`.
     ///
-    /// This function delegates to `visitor.visit_enumerator`.
+    /// This function first calls `visitor.visit_enumerator`, then recursively visits
+    /// any fields associated with the enumerator.
     pub fn visit_with(&self, visitor: &mut impl Visitor) {
         visitor.visit_enumerator(self);
+        for field in &self.fields {
+            field.borrow().visit_with(visitor);
+        }
     }
 }
 
@@ -266,9 +299,14 @@ impl TypeRef {
     ///
     /// This function first calls `visitor.visit_type_ref`, then if the type being referenced is a sequence or
     /// dictionary, it recursively calls itself on their underlying element, key, and value types.
+    ///
+    /// Unpatched type references (which are only present in ASTs with errors) aren't recursed into.
     pub fn visit_with(&self, visitor: &mut impl Visitor) {
         visitor.visit_type_ref(self);
-        match self.concrete_type() {
+        let TypeRefDefinition::Patched(definition) = &self.definition else {
+            return;
+        };
+        match definition.borrow().concrete_type() {
             Types::Sequence(sequence_ref) => sequence_ref.element_type.visit_with(visitor),
             Types::Dictionary(dictionary_ref) => {
                 dictionary_ref.key_type.visit_with(visitor);
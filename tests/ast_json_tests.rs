@@ -0,0 +1,243 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+mod ast_json {
+
+    use crate::test_helpers::*;
+    use serde_json::{json, Value};
+    use slicec::ast_json::{ast_to_json, FORMAT_VERSION, JSON_SCHEMA};
+    use slicec::compile_from_options;
+    use slicec::slice_options::SliceOptions;
+    use test_case::test_case;
+
+    fn to_json(slice: &str) -> Value {
+        let compilation_state = parse(slice, None);
+        assert!(!compilation_state.diagnostic_reporter.has_errors());
+        ast_to_json(&compilation_state)
+    }
+
+    #[test]
+    fn documents_are_versioned() {
+        // Act
+        let json = to_json("module Test");
+
+        // Assert
+        assert_eq!(json["format_version"], json!(FORMAT_VERSION));
+        assert_eq!(json["slicec_version"], json!(env!("CARGO_PKG_VERSION")));
+        assert_eq!(json["files"][0]["path"], "string-0");
+        assert_eq!(json["files"][0]["mode"], "Slice2");
+        assert_eq!(json["files"][0]["modules"][0]["identifier"], "Test");
+    }
+
+    #[test]
+    fn published_schema_matches_format_version() {
+        // Act
+        let schema: Value = serde_json::from_str(JSON_SCHEMA).unwrap();
+
+        // Assert
+        assert_eq!(schema["properties"]["format_version"]["const"], json!(FORMAT_VERSION));
+    }
+
+    #[test]
+    fn entities_are_serialized_with_their_members() {
+        // Arrange
+        let slice = "
+            module Test
+            struct S {
+                a: int32
+                tag(3) b: sequence<string>?
+            }
+            typealias Map = dictionary<string, S>
+            exception E {}
+            interface I {
+                idempotent op(m: Map) -> stream bool throws E
+            }
+        ";
+
+        // Act
+        let json = to_json(slice);
+
+        // Assert
+        let definitions = &json["files"][0]["definitions"];
+        let struct_def = &definitions[0];
+        assert_eq!(struct_def["kind"], "struct");
+        assert_eq!(struct_def["scoped_identifier"], "Test::S");
+        assert_eq!(struct_def["module"], "Test");
+        assert_eq!(struct_def["supported_encodings"], json!(["Slice2"]));
+        assert_eq!(struct_def["fields"][0]["data_type"]["identifier"], "int32");
+        assert_eq!(struct_def["fields"][0]["tag"], Value::Null);
+        assert_eq!(struct_def["fields"][1]["tag"], 3);
+        assert_eq!(struct_def["fields"][1]["data_type"]["kind"], "sequence");
+        assert_eq!(struct_def["fields"][1]["data_type"]["is_optional"], true);
+        assert_eq!(
+            struct_def["fields"][1]["data_type"]["element_type"]["identifier"],
+            "string"
+        );
+
+        let operation = &definitions[3]["operations"][0];
+        assert_eq!(operation["kind"], "operation");
+        assert_eq!(operation["scoped_identifier"], "Test::I::op");
+        assert_eq!(operation["is_idempotent"], true);
+        assert_eq!(operation["throws"]["kind"], "specific");
+        assert_eq!(operation["throws"]["exception"]["identifier"], "Test::E");
+        assert_eq!(operation["return_members"][0]["is_streamed"], true);
+
+        // Type aliases are resolved to the type they alias.
+        let parameter_type = &operation["parameters"][0]["data_type"];
+        assert_eq!(parameter_type["kind"], "dictionary");
+        assert_eq!(parameter_type["value_type"]["kind"], "struct");
+        assert_eq!(parameter_type["value_type"]["identifier"], "Test::S");
+    }
+
+    #[test]
+    fn enumerator_values_are_serialized() {
+        // Arrange
+        let slice = "
+            module Test
+            enum E : int64 { A = -9223372036854775808, B = 5 }
+            unchecked enum U : varuint62 { C = 4611686018427387903 }
+        ";
+
+        // Act
+        let json = to_json(slice);
+
+        // Assert
+        let definitions = &json["files"][0]["definitions"];
+        assert_eq!(definitions[0]["underlying"]["identifier"], "int64");
+        assert_eq!(definitions[0]["enumerators"][0]["value"], json!(i64::MIN));
+        assert_eq!(definitions[0]["enumerators"][1]["value"], 5);
+        assert_eq!(definitions[1]["is_unchecked"], true);
+        assert_eq!(
+            definitions[1]["enumerators"][0]["value"],
+            json!(4611686018427387903_i64)
+        );
+    }
+
+    #[test]
+    fn attributes_keep_their_arguments() {
+        // Arrange
+        let slice = "
+            module Test
+            [allow(Deprecated, BrokenDocLink)]
+            [cs::attribute(\"a\")]
+            struct S {}
+        ";
+
+        // Act
+        let json = to_json(slice);
+
+        // Assert
+        let attributes = &json["files"][0]["definitions"][0]["attributes"];
+        assert_eq!(attributes[0]["directive"], "allow");
+        assert_eq!(attributes[0]["args"][0]["kind"], "identifier");
+        assert_eq!(attributes[0]["args"][0]["value"], "Deprecated");
+        assert_eq!(attributes[0]["args"][1]["value"], "BrokenDocLink");
+        assert_eq!(attributes[1]["directive"], "cs::attribute");
+        assert_eq!(attributes[1]["args"][0]["kind"], "string");
+        assert_eq!(attributes[1]["args"][0]["value"], "a");
+    }
+
+    #[test]
+    fn doc_comments_hold_resolved_links() {
+        // Arrange
+        let slice = "
+            module Test
+            /// Holds a {@link S} and a {@link Missing}.
+            /// @see I
+            struct S {}
+            interface I {}
+        ";
+
+        // Act
+        let json = to_json(slice);
+
+        // Assert
+        let comment = &json["files"][0]["definitions"][0]["doc_comment"];
+        let overview = &comment["overview"];
+        assert_eq!(overview[0], json!({ "kind": "text", "text": "Holds a " }));
+        assert_eq!(overview[1]["kind"], "link");
+        assert_eq!(
+            overview[1]["link"],
+            json!({ "is_resolved": true, "identifier": "Test::S" })
+        );
+        assert_eq!(
+            overview[3]["link"],
+            json!({ "is_resolved": false, "identifier": "Missing" })
+        );
+        assert_eq!(comment["see"][0]["link"]["identifier"], "Test::I");
+        assert_eq!(json["files"][0]["definitions"][1]["doc_comment"], Value::Null);
+    }
+
+    #[test]
+    fn emit_ast_json_option_writes_file() {
+        // Arrange
+        let path = std::env::temp_dir().join(format!("slicec-ast-json-{}.json", std::process::id()));
+        let options = SliceOptions {
+            sources: vec!["tests/files/test.slice".to_owned()],
+            emit_ast_json: Some(path.display().to_string()),
+            ..Default::default()
+        };
+
+        // Act
+        let state = compile_from_options(&options, |_| {}, |_| {});
+
+        // Assert
+        assert!(!state.diagnostic_reporter.has_errors());
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let json: Value = serde_json::from_str(&contents).unwrap();
+        assert_eq!(json, ast_to_json(&state));
+    }
+
+    #[test_case(vec!["Deprecated".to_owned()], false; "denied lint")]
+    #[test_case(Vec::new(), true; "warnings as errors")]
+    fn emit_ast_json_skips_lints_raised_to_errors(denied_lints: Vec<String>, warnings_as_errors: bool) {
+        // Arrange
+        let directory = std::env::temp_dir().join(format!(
+            "slicec-ast-json-lints-{warnings_as_errors}-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&directory).unwrap();
+        let source = directory.join("deprecated.slice");
+        std::fs::write(&source, "module Test\n[deprecated] struct A {}\nstruct B { a: A }").unwrap();
+        let path = directory.join("ast.json");
+        let options = SliceOptions {
+            sources: vec![source.display().to_string()],
+            emit_ast_json: Some(path.display().to_string()),
+            denied_lints,
+            warnings_as_errors,
+            ..Default::default()
+        };
+
+        // Act
+        let state = compile_from_options(&options, |_| {}, |_| {});
+
+        // Assert
+        assert!(!path.exists());
+        assert_eq!(state.into_exit_code(), 1);
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn emit_ast_json_reports_unwritable_paths() {
+        // Arrange
+        let options = SliceOptions {
+            sources: vec!["tests/files/test.slice".to_owned()],
+            emit_ast_json: Some("tests/files/missing-directory/ast.json".to_owned()),
+            ..Default::default()
+        };
+
+        // Act
+        let state = compile_from_options(&options, |_| {}, |_| {});
+
+        // Assert
+        let diagnostics = diagnostics_from_compilation_state(state);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(
+            diagnostics[0].message().starts_with("unable to write"),
+            "{}",
+            diagnostics[0].message()
+        );
+    }
+}
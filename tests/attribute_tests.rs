@@ -6,6 +6,7 @@ mod attributes {
     use crate::test_helpers::*;
     use slicec::diagnostics::{Diagnostic, Error, Lint};
     use slicec::grammar::attributes::*;
+    use slicec::slice_file::Span;
 
     mod allow {
         use super::*;
@@ -50,6 +51,28 @@ mod attributes {
             check_diagnostics(diagnostics, [expected]);
         }
 
+        #[test]
+        fn allow_with_incorrectly_cased_argument() {
+            // Arrange
+            let slice = "[[allow(deprecated)]]";
+
+            // Act
+            let diagnostics = parse_for_diagnostics(slice);
+
+            // Assert
+            let span = Span::new((1, 9).into(), (1, 19).into(), "string-0");
+            let expected = Diagnostic::new(Error::ArgumentNotSupported {
+                argument: "deprecated".to_owned(),
+                directive: "allow".to_owned(),
+            })
+            .add_note(
+                "attribute arguments are case sensitive, perhaps you meant 'Deprecated'?",
+                None,
+            )
+            .add_suggestion("replace with 'Deprecated'", &span, "Deprecated");
+            check_diagnostics(diagnostics, [expected]);
+        }
+
         #[test_case("All"; "all")]
         #[test_case("IncorrectDocComment"; "specific")]
         fn allow_with_valid_arguments(argument: &str) {
@@ -97,6 +120,7 @@ mod attributes {
 
                 /// {{@link fake}}
                 /// @throws
+                /// @deprecated: test
                 [deprecated(\"test\")]
                 struct S {{}}
 
@@ -443,6 +467,7 @@ mod attributes {
                 argument: "Foo".to_owned(),
                 directive: "compress".to_owned(),
             })
+            .set_span(&Span::new((5, 31).into(), (5, 34).into(), "string-0"))
             .add_note("'Args' and 'Return' are the only valid arguments", None);
 
             check_diagnostics(diagnostics, [expected]);
@@ -497,6 +522,128 @@ mod attributes {
             check_diagnostics(diagnostics, [expected]);
         }
 
+        #[test]
+        fn flags() {
+            // Arrange
+            let slice = "
+                module Test
+
+                [flags]
+                enum E : uint8 {
+                    None = 0
+                    A
+                    B
+                    C = 16
+                    D
+                }
+            ";
+
+            // Act
+            let ast = parse_for_ast(slice);
+
+            // Assert
+            let enum_def = ast.find_element::<Enum>("Test::E").unwrap();
+            assert!(enum_def.is_flags());
+
+            let values = enum_def.enumerators().iter().map(|e| e.value()).collect::<Vec<_>>();
+            assert_eq!(values, vec![0, 1, 2, 16, 32]);
+        }
+
+        #[test]
+        fn flags_implicit_values_start_at_one() {
+            // Arrange
+            let slice = "
+                module Test
+
+                [flags]
+                enum E : uint32 { A, B, C }
+            ";
+
+            // Act
+            let ast = parse_for_ast(slice);
+
+            // Assert
+            let enum_def = ast.find_element::<Enum>("Test::E").unwrap();
+            let values = enum_def.enumerators().iter().map(|e| e.value()).collect::<Vec<_>>();
+            assert_eq!(values, vec![1, 2, 4]);
+        }
+
+        #[test]
+        fn flags_values_must_be_powers_of_two() {
+            // Arrange
+            let slice = "
+                module Test
+
+                [flags]
+                enum E : uint8 {
+                    Read = 1
+                    Write = 3
+                }
+            ";
+
+            // Act
+            let diagnostics = parse_for_diagnostics(slice);
+
+            // Assert
+            let expected = Diagnostic::new(Error::FlagsEnumeratorValueNotPowerOfTwo {
+                enumerator_identifier: "Write".to_owned(),
+                value: 3,
+            })
+            .set_span(&Span::new((7, 21).into(), (7, 30).into(), "string-0"));
+
+            check_diagnostics(diagnostics, [expected]);
+        }
+
+        #[test_case("enum E : int8 { A }"; "signed enums")]
+        #[test_case("enum E { A(a: int8) }"; "enums without underlying types")]
+        #[test_case("struct E {}"; "structs")]
+        fn flags_only_works_on_unsigned_enums(definition: &str) {
+            // Arrange
+            let slice = format!(
+                "
+                    module Test
+
+                    [flags]
+                    {definition}
+                "
+            );
+
+            // Act
+            let diagnostics = parse_for_diagnostics(slice);
+
+            // Assert
+            let expected = Diagnostic::new(Error::UnexpectedAttribute {
+                attribute: "flags".to_owned(),
+            })
+            .add_note(
+                "the flags attribute can only be applied to enums with unsigned underlying types",
+                None,
+            );
+
+            check_diagnostics(diagnostics, [expected]);
+        }
+
+        #[test]
+        fn flags_with_arguments_fails() {
+            // Arrange
+            let slice = "
+                module Test
+
+                [flags(A)]
+                enum E : uint8 { A }
+            ";
+
+            // Act
+            let diagnostics = parse_for_diagnostics(slice);
+
+            // Assert
+            let expected = Diagnostic::new(Error::TooManyArguments {
+                expected: "flags".to_owned(),
+            });
+
+            check_diagnostics(diagnostics, [expected]);
+        }
+
         #[test]
         fn non_repeatable_attributes_error() {
             // Arrange
@@ -632,7 +779,11 @@ mod attributes {
             let unparsed_attribute = operation.find_attribute::<Unparsed>().unwrap();
             assert_eq!(unparsed_attribute.directive, "foo::bar");
 
-            let arguments = unparsed_attribute.args.iter().map(String::as_str).collect::<Vec<_>>();
+            let arguments = unparsed_attribute
+                .args
+                .iter()
+                .map(|arg| arg.as_str().unwrap())
+                .collect::<Vec<_>>();
             assert_eq!(arguments, vec!["a", "b", "c"]);
         }
 
@@ -660,10 +811,150 @@ mod attributes {
             let operation = ast.find_element::<Operation>("Test::I::op").unwrap();
 
             let unparsed_attribute = operation.find_attribute::<Unparsed>().unwrap();
-            let arguments = unparsed_attribute.args.iter().map(String::as_str).collect::<Vec<_>>();
+            let arguments = unparsed_attribute
+                .args
+                .iter()
+                .map(|arg| arg.as_str().unwrap())
+                .collect::<Vec<_>>();
             assert_eq!(arguments, expected);
         }
 
+        #[test]
+        fn attribute_arguments_are_typed() {
+            // Arrange
+            let slice = r#"
+                module Test
+
+                [foo::bar(Name, "a string", -5, true)]
+                struct S {}
+            "#;
+
+            // Act
+            let ast = parse_for_ast(slice);
+
+            // Assert
+            let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+            let args = &struct_def.find_attribute::<Unparsed>().unwrap().args;
+            assert!(matches!(&args[0].value, ArgumentValue::Identifier(s) if s == "Name"));
+            assert!(matches!(&args[1].value, ArgumentValue::String(s) if s == "a string"));
+            assert_eq!(args[2].as_integer(), Some(-5));
+            assert_eq!(args[3].as_bool(), Some(true));
+        }
+
+        #[test_case(r#""a \"quoted\" word""#, r#"a "quoted" word"#; "escaped quotes")]
+        #[test_case(r#""C:\\path""#, r"C:\path"; "escaped backslash")]
+        #[test_case(r#""tab\there""#, "tab\there"; "escaped tab")]
+        fn string_arguments_process_escape_sequences(input: &str, expected: &str) {
+            // Arrange
+            let slice = format!(
+                "
+                    module Test
+
+                    [foo::bar({input})]
+                    struct S {{}}
+                "
+            );
+
+            // Act
+            let ast = parse_for_ast(slice);
+
+            // Assert
+            let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+            let args = &struct_def.find_attribute::<Unparsed>().unwrap().args;
+            assert_eq!(args[0].as_str(), Some(expected));
+        }
+
+        #[test]
+        fn attribute_arguments_can_be_lists_and_key_value_pairs() {
+            // Arrange
+            let slice = "
+                module Test
+
+                [foo::bar(levels = [1, [2, 3]], [], name = Other)]
+                struct S {}
+            ";
+
+            // Act
+            let ast = parse_for_ast(slice);
+
+            // Assert
+            let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+            let args = &struct_def.find_attribute::<Unparsed>().unwrap().args;
+            assert_eq!(args.len(), 3);
+
+            let (key, value) = args[0].as_key_value().unwrap();
+            assert_eq!(key, "levels");
+            let levels = value.as_list().unwrap();
+            assert_eq!(levels[0].as_integer(), Some(1));
+            assert_eq!(levels[1].as_list().unwrap().len(), 2);
+            assert_eq!(args[0].to_string(), "levels = [1, [2, 3]]");
+
+            assert!(args[1].as_list().unwrap().is_empty());
+
+            let (key, value) = args[2].as_key_value().unwrap();
+            assert_eq!(key, "name");
+            assert_eq!(value.as_str(), Some("Other"));
+        }
+
+        #[test]
+        fn nested_lists_can_be_used_in_file_attributes() {
+            // Arrange
+            let slice = "
+                [[foo::bar([[1], [2]])]]
+                module Test
+            ";
+
+            // Act
+            let compilation_state = parse(slice, None);
+
+            // Assert
+            let file = compilation_state.files.values().next().unwrap();
+            let attribute = file.attributes[0].borrow().downcast::<Unparsed>().unwrap();
+            assert_eq!(attribute.args[0].to_string(), "[[1], [2]]");
+        }
+
+        #[test]
+        fn attribute_arguments_have_spans() {
+            // Arrange
+            let slice = "
+                module Test
+
+                [foo::bar(a, [b, c])]
+                struct S {}
+            ";
+
+            // Act
+            let ast = parse_for_ast(slice);
+
+            // Assert
+            let struct_def = ast.find_element::<Struct>("Test::S").unwrap();
+            let args = &struct_def.find_attribute::<Unparsed>().unwrap().args;
+            assert_eq!(args[0].span.start, (4, 27).into());
+            assert_eq!(args[0].span.end, (4, 28).into());
+            assert_eq!(args[1].span.start, (4, 30).into());
+            assert_eq!(args[1].span.end, (4, 36).into());
+        }
+
+        #[test]
+        fn string_arguments_reject_unknown_escape_sequences() {
+            // Arrange
+            let slice = r#"
+                module Test
+
+                [foo::bar("\q")]
+                struct S {}
+            "#;
+
+            // Act
+            let diagnostics = parse_for_diagnostics(slice);
+
+            // Assert
+            let expected = Diagnostic::new(Error::Syntax {
+                message: r"unknown escape sequence '\q' in string literal".to_owned(),
+            });
+            check_diagnostics(diagnostics, [expected]);
+        }
+
         #[test_case("a, \""; "quoted argument with unterminated string literal")]
         #[test_case("a, )"; "missing argument")]
         #[test_case("fizz buzz"; "unquoted argument with spaces")]
@@ -713,9 +1004,9 @@ mod attributes {
 
             assert_eq!(parent_attributes.len(), 2);
             assert_eq!(parent_attributes[0].directive, "test::attribute");
-            assert_eq!(parent_attributes[0].args, vec!["S".to_owned()]);
+            assert_eq!(parent_attributes[0].args[0].as_str(), Some("S"));
             assert_eq!(parent_attributes[1].directive, "test::attribute");
-            assert_eq!(parent_attributes[1].args, vec!["I".to_owned()]);
+            assert_eq!(parent_attributes[1].args[0].as_str(), Some("I"));
         }
 
         #[test_case("foo"; "plain_attribute")]
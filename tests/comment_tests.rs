@@ -5,7 +5,7 @@ mod test_helpers;
 mod comments {
 
     use crate::test_helpers::*;
-    use slicec::diagnostics::{Diagnostic, Error, Lint};
+    use slicec::diagnostics::{Diagnostic, Lint};
     use slicec::grammar::*;
     use test_case::test_case;
 
@@ -149,25 +149,29 @@ mod comments {
     }
 
     #[test]
-    fn doc_comments_not_supported_on_modules() {
+    fn doc_comments_on_modules() {
         // Arrange
         let slice = "
-            /// This is a module comment.
+            /// This is a module comment. See {@link S}.
             module tests
+
+            struct S {}
         ";
 
         // Act
-        let diagnostics = parse_for_diagnostics(slice);
+        let ast = parse_for_ast(slice);
 
         // Assert
-        let expected = Diagnostic::new(Error::Syntax {
-            message: "doc comments cannot be applied to modules".to_owned(),
-        });
-        check_diagnostics(diagnostics, [expected]);
+        let module_def = ast.find_element::<Module>("tests").unwrap();
+        let overview = module_def.comment().unwrap().overview.as_ref().unwrap();
+        let MessageComponent::Text(text) = &overview.message[0] else { panic!() };
+        assert_eq!(text, "This is a module comment. See ");
+        let MessageComponent::Link(link) = &overview.message[1] else { panic!() };
+        assert_eq!(link.linked_entity().unwrap().parser_scoped_identifier(), "tests::S");
     }
 
     #[test]
-    fn doc_comment_not_supported_on_params_and_returns() {
+    fn doc_comments_on_params_and_returns() {
         // Arrange
         let slice = "
                 module tests
@@ -185,19 +189,94 @@ mod comments {
                 }
             ";
 
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let param = ast.find_element::<Parameter>("tests::I::testOp::testParam").unwrap();
+        let overview = param.comment().unwrap().overview.as_ref().unwrap();
+        let MessageComponent::Text(text) = &overview.message[0] else { panic!() };
+        assert_eq!(text, "comment on param");
+
+        let return_member = ast.find_element::<Parameter>("tests::I::testOpTwo::foo").unwrap();
+        let overview = return_member.comment().unwrap().overview.as_ref().unwrap();
+        let MessageComponent::Text(text) = &overview.message[0] else { panic!() };
+        assert_eq!(text, "comment on return");
+
+        let other = ast.find_element::<Parameter>("tests::I::testOpTwo::bar").unwrap();
+        assert!(other.comment().is_none());
+    }
+
+    #[test]
+    fn doc_comment_links_on_params_are_resolved() {
+        // Arrange
+        let slice = "
+            module tests
+
+            interface I {
+                op(
+                    /// See {@link Missing}.
+                    p: string,
+                )
+            }
+        ";
+
         // Act
         let diagnostics = parse_for_diagnostics(slice);
 
         // Assert
-        let expected = [
-            Diagnostic::new(Error::Syntax {
-                message: "doc comments cannot be applied to parameters".to_owned(),
-            }),
-            Diagnostic::new(Error::Syntax {
-                message: "doc comments cannot be applied to return members".to_owned(),
-            }),
-        ];
-        check_diagnostics(diagnostics, expected);
+        let expected = Diagnostic::new(Lint::BrokenDocLink {
+            message: "no element named 'Missing' exists in scope".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn broken_doc_links_suggest_similarly_named_elements() {
+        // Arrange
+        let slice = "
+            module tests
+
+            /// See {@link Shap}.
+            struct Shape {}
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::BrokenDocLink {
+            message: "no element named 'Shap' exists in scope".to_owned(),
+        })
+        .add_note("did you mean 'Shape'?", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("@param p: Also a comment.", "op(/// A comment.\n p: string)", "parameter 'p'", "@param"; "param")]
+    #[test_case("@returns r: Also a comment.", "op() -> (/// A comment.\n r: string, s: string)", "return element 'r'", "@returns"; "returns")]
+    fn inline_comments_cannot_conflict_with_operation_tags(tag: &str, operation: &str, kind: &str, tag_name: &str) {
+        // Arrange
+        let slice = format!(
+            "
+            module tests
+
+            interface I {{
+                /// {tag}
+                {operation}
+            }}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::IncorrectDocComment {
+            message: format!("{kind} has a doc comment, but is also documented by a '{tag_name}' tag on its operation"),
+        })
+        .add_note(format!("the '{tag_name}' tag is here:"), None)
+        .add_note("parameters should only be documented in one place", None);
+        check_diagnostics(diagnostics, [expected]);
     }
 
     #[test]
@@ -412,6 +491,320 @@ mod comments {
         assert_eq!(link_identifier.span.end, (5, 31).into());
     }
 
+    #[test]
+    fn doc_comments_remarks_and_deprecated() {
+        // Arrange
+        let slice = "
+            module tests
+
+            /// @remarks: Prefer {@link Other} where possible.
+            /// @deprecated: Use {@link Other} instead.
+            [deprecated]
+            struct S {}
+
+            struct Other {}
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let comment = ast.find_element::<Struct>("tests::S").unwrap().comment().unwrap();
+        assert_eq!(comment.remarks.len(), 1);
+        assert_eq!(comment.deprecated.len(), 1);
+
+        let MessageComponent::Text(text) = &comment.remarks[0].message[0] else { panic!() };
+        assert_eq!(text, "Prefer ");
+        let MessageComponent::Link(link) = &comment.remarks[0].message[1] else { panic!() };
+        assert_eq!(link.linked_entity().unwrap().identifier(), "Other");
+
+        let MessageComponent::Text(text) = &comment.deprecated[0].message[0] else { panic!() };
+        assert_eq!(text, "Use ");
+        let MessageComponent::Link(link) = &comment.deprecated[0].message[1] else { panic!() };
+        assert_eq!(link.linked_entity().unwrap().identifier(), "Other");
+    }
+
+    #[test]
+    fn doc_comments_example_is_verbatim() {
+        // Arrange
+        let slice = "
+            module tests
+
+            /// @example
+            ///     var s = new S();
+            ///     s.Call({@link Other});
+            struct S {}
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let examples = &ast
+            .find_element::<Struct>("tests::S")
+            .unwrap()
+            .comment()
+            .unwrap()
+            .examples;
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].code, "var s = new S();\ns.Call({@link Other});");
+    }
+
+    #[test_case("@since 1.2.0"; "plain")]
+    #[test_case("@since: 1.2.0 "; "with colon")]
+    fn doc_comments_since(tag: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                module tests
+
+                /// {tag}
+                struct S {{}}
+            "
+        );
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let since_tags = &ast.find_element::<Struct>("tests::S").unwrap().comment().unwrap().since;
+        assert_eq!(since_tags.len(), 1);
+        assert_eq!(since_tags[0].version, "1.2.0");
+    }
+
+    #[test]
+    fn doc_comments_since_can_only_appear_once() {
+        // Arrange
+        let slice = "
+            module tests
+
+            /// @since 1.0
+            /// @since 2.0
+            struct S {}
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::IncorrectDocComment {
+            message: "doc comment for struct 'S' has multiple '@since' tags".to_owned(),
+        })
+        .add_note("the first '@since' tag is here:", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn doc_comments_deprecated_tag_requires_attribute() {
+        // Arrange
+        let slice = "
+            module tests
+
+            /// @deprecated: Don't use this.
+            struct S {}
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::IncorrectDocComment {
+            message:
+                "doc comment indicates that struct 'S' is deprecated, but it doesn't have a 'deprecated' attribute"
+                    .to_owned(),
+        })
+        .add_note(
+            "consider adding a 'deprecated' attribute, or removing the '@deprecated' tag",
+            None,
+        );
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn doc_comments_deprecated_attribute_requires_tag() {
+        // Arrange
+        let slice = "
+            module tests
+
+            /// A struct.
+            [deprecated]
+            struct S {}
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::IncorrectDocComment {
+            message: "struct 'S' has a 'deprecated' attribute, but its doc comment is missing a '@deprecated' tag"
+                .to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn doc_comments_inline_markup() {
+        // Arrange
+        let slice = "
+            module tests
+
+            /// Call `run()` *once*, it's **expensive**. 2*3 * 4 is just text.
+            struct S {}
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let comment = ast.find_element::<Struct>("tests::S").unwrap().comment().unwrap();
+        let message = &comment.overview.as_ref().unwrap().message;
+        assert_eq!(message.len(), 8);
+
+        let MessageComponent::Code(code) = &message[1] else { panic!() };
+        assert_eq!(code.code, "run()");
+        assert_eq!(code.span.start, (4, 22).into());
+        assert_eq!(code.span.end, (4, 29).into());
+
+        let MessageComponent::Emphasis(emphasis) = &message[3] else { panic!() };
+        assert_eq!(emphasis.text, "once");
+        assert!(!emphasis.is_strong);
+
+        let MessageComponent::Emphasis(strong) = &message[5] else { panic!() };
+        assert_eq!(strong.text, "expensive");
+        assert!(strong.is_strong);
+
+        let MessageComponent::Text(text) = &message[6] else { panic!() };
+        assert_eq!(text, ". 2*3 * 4 is just text.");
+    }
+
+    #[test]
+    fn doc_comments_bullet_lists() {
+        // Arrange
+        let slice = "
+            module tests
+
+            /// Either:
+            /// - a {@link S}
+            /// * or `nothing`
+            ///
+            /// - another list
+            struct S {}
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let comment = ast.find_element::<Struct>("tests::S").unwrap().comment().unwrap();
+        let message = &comment.overview.as_ref().unwrap().message;
+        assert_eq!(message.len(), 5);
+
+        let MessageComponent::List(list) = &message[2] else { panic!() };
+        assert_eq!(list.items.len(), 2);
+        assert_eq!(list.span.start, (5, 17).into());
+        assert_eq!(list.span.end, (6, 31).into());
+
+        let MessageComponent::Text(text) = &list.items[0][0] else { panic!() };
+        assert_eq!(text, "a ");
+        let MessageComponent::Link(link) = &list.items[0][1] else { panic!() };
+        assert_eq!(link.linked_entity().unwrap().identifier(), "S");
+        let MessageComponent::Code(code) = &list.items[1][1] else { panic!() };
+        assert_eq!(code.code, "nothing");
+
+        let MessageComponent::List(other_list) = &message[4] else { panic!() };
+        assert_eq!(other_list.items.len(), 1);
+    }
+
+    #[test]
+    fn doc_comments_fenced_code_blocks() {
+        // Arrange
+        let slice = "
+            module tests
+
+            /// For example:
+            /// ```csharp
+            /// @Annotated
+            ///     var s = new S { Value = *p };
+            ///
+            /// ```
+            struct S {}
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let comment = ast.find_element::<Struct>("tests::S").unwrap().comment().unwrap();
+        let message = &comment.overview.as_ref().unwrap().message;
+        assert_eq!(message.len(), 3);
+
+        let MessageComponent::CodeBlock(code_block) = &message[2] else { panic!() };
+        assert_eq!(code_block.language.as_deref(), Some("csharp"));
+        assert_eq!(code_block.code, "@Annotated\n    var s = new S { Value = *p };\n");
+        assert_eq!(code_block.span.start, (5, 17).into());
+        assert_eq!(code_block.span.end, (9, 20).into());
+    }
+
+    #[test_case("Call `run().", "missing a closing '`' on an inline code span"; "code span")]
+    #[test_case("Call *run().", "missing a closing '*' on emphasized text"; "emphasis")]
+    #[test_case("Call **run()*.", "missing a closing '**' on emphasized text"; "strong emphasis")]
+    #[test_case("```\n/// run()", "missing a closing '```' on a fenced code block"; "code block")]
+    fn unterminated_markup_is_reported(comment: &str, message: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                module tests
+
+                /// {comment}
+                struct S {{}}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Lint::MalformedDocComment {
+            message: message.to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("Matches *.slice files.", false; "glob")]
+    #[test_case("Returns a * b * c.", false; "multiplication")]
+    #[test_case("Pass *args here.", true; "unterminated emphasis")]
+    #[test_case("Use the ` character.", true; "unterminated code span")]
+    fn unmatched_markup_characters_are_plain_text(comment: &str, is_reported: bool) {
+        // Arrange
+        let slice = format!(
+            "
+                module tests
+
+                /// {comment}
+                struct S {{}}
+            "
+        );
+
+        // Act
+        let compilation_state = parse(slice, None);
+
+        // Assert
+        let ast = &compilation_state.ast;
+        let doc_comment = ast.find_element::<Struct>("tests::S").unwrap().comment().unwrap();
+        let message = &doc_comment.overview.as_ref().unwrap().message;
+        let text = message
+            .iter()
+            .map(|component| match component {
+                MessageComponent::Text(text) => text.as_str(),
+                _ => panic!("expected only text, but found {component:?}"),
+            })
+            .collect::<String>();
+        assert_eq!(text, format!("{comment}\n"));
+
+        let diagnostics = diagnostics_from_compilation_state(compilation_state);
+        assert_eq!(diagnostics.len(), usize::from(is_reported));
+    }
+
     #[test_case("/* This is a block comment. */"; "block comment")]
     #[test_case("/*\n* This is a multiline block comment.\n */"; "multi-line block comment")]
     #[test_case("// This is a comment."; "comment")]
@@ -0,0 +1,552 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+mod compatibility {
+
+    use crate::test_helpers::*;
+    use slicec::compatibility::check_compatibility;
+    use slicec::diagnostics::{Diagnostic, DiagnosticLevel, Lint};
+    use slicec::slice_file::{Location, Span};
+    use test_case::test_case;
+
+    /// Compiles the old and new versions of a Slice file, and checks whether they're compatible.
+    fn check(old_slice: &str, new_slice: &str) -> Vec<Diagnostic> {
+        let old_state = parse(old_slice, None);
+        let new_state = parse(new_slice, None);
+        assert!(!old_state.diagnostic_reporter.has_errors());
+        assert!(!new_state.diagnostic_reporter.has_errors());
+        check_compatibility(&old_state, &new_state)
+    }
+
+    fn breaking(message: &str) -> Diagnostic {
+        let message = message.to_owned();
+        Diagnostic::new(Lint::BreakingChange { message })
+    }
+
+    fn compatible(message: &str) -> Diagnostic {
+        let message = message.to_owned();
+        Diagnostic::new(Lint::CompatibleChange { message })
+    }
+
+    fn all_have_level(diagnostics: &[Diagnostic], level: DiagnosticLevel) -> bool {
+        !diagnostics.is_empty() && diagnostics.iter().all(|diagnostic| diagnostic.level() == level)
+    }
+
+    #[test]
+    fn identical_definitions_are_compatible() {
+        // Arrange
+        let slice = "
+            module Test
+            struct Point { x: int32, y: int32, tag(1) label: string? }
+            enum Color : uint8 { Red, Green, Blue }
+            interface Greeter { greet(name: string) -> string }
+        ";
+
+        // Act
+        let diagnostics = check(slice, slice);
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn changes_that_do_not_affect_the_wire_are_ignored() {
+        // Arrange
+        let old_slice = "
+            module Test
+            struct Point { x: int32 }
+            struct Line { start: Point, count: int32 }
+        ";
+        let new_slice = "
+            module Test
+            /// A point.
+            struct Point { [deprecated] x: int32 }
+            typealias Count = int32
+            struct Line { start: ::Test::Point, count: Count }
+            struct Unrelated {}
+        ";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn reordering_fields_of_different_types_is_breaking() {
+        // Arrange
+        let old_slice = "
+            module Test
+            struct Point {
+                x: int32
+                label: string
+            }
+        ";
+        let new_slice = "
+            module Test
+            struct Point {
+                label: string
+                x: int32
+            }
+        ";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        let span = |row, start, end| Span::new(Location { row, col: start }, Location { row, col: end }, "string-0");
+        let expected = [
+            breaking("type of non-tagged field at position 1 changed from 'int32' to 'string'")
+                .set_span(&span(4, 17, 22))
+                .add_note("field 'x' was previously defined here", Some(&span(4, 17, 18))),
+            breaking("type of non-tagged field at position 2 changed from 'string' to 'int32'")
+                .set_span(&span(5, 17, 18))
+                .add_note("field 'label' was previously defined here", Some(&span(5, 17, 22))),
+        ];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn renaming_non_tagged_fields_is_compatible() {
+        // Arrange
+        let old_slice = "
+            module Test
+            struct S { a: int32, b: string }
+            struct Point { x: int32, y: int32 }
+        ";
+        let new_slice = "
+            module Test
+            struct S { first: int32, b: string }
+            struct Point { y: int32, x: int32 }
+        ";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        let expected = [
+            compatible("non-tagged field 'Test::S::a' was renamed to 'first'"),
+            compatible("non-tagged field 'Test::Point::x' was renamed to 'y'"),
+            compatible("non-tagged field 'Test::Point::y' was renamed to 'x'"),
+        ];
+        assert!(all_have_level(&diagnostics, DiagnosticLevel::Allowed));
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test_case("int32", "int64"; "primitive")]
+    #[test_case("int32", "int32?"; "optional")]
+    #[test_case("sequence<int32>", "sequence<uint32>"; "sequence")]
+    #[test_case("dictionary<string, bool>", "dictionary<string, string>"; "dictionary")]
+    #[test_case("Test::Point", "Other::Point"; "user defined type")]
+    fn changing_the_type_of_a_field_is_breaking(old_type: &str, new_type: &str) {
+        // Arrange
+        let definitions = "
+            module Other { struct Point {} }
+            module Test { struct Point {} }
+        ";
+        let old_slice = format!("{definitions}\n module Test {{ struct S {{ f: {old_type} }} }}");
+        let new_slice = format!("{definitions}\n module Test {{ struct S {{ f: {new_type} }} }}");
+
+        // Act
+        let diagnostics = check(&old_slice, &new_slice);
+
+        // Assert
+        let message = format!("type of field 'Test::S::f' changed from '{old_type}' to '{new_type}'");
+        check_diagnostics(diagnostics, [breaking(&message)]);
+    }
+
+    #[test]
+    fn adding_and_removing_non_tagged_fields_is_breaking() {
+        // Arrange
+        let old_slice = "
+            module Test
+            struct S { a: int32, b: int32 }
+            struct T { a: int32 }
+        ";
+        let new_slice = "
+            module Test
+            struct S { a: int32 }
+            struct T { a: int32, c: int32 }
+        ";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        let expected = [
+            breaking("non-tagged field 'Test::S::b' was removed"),
+            breaking("non-tagged field 'Test::T::c' was added"),
+        ];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn adding_and_removing_tagged_fields_is_compatible() {
+        // Arrange
+        let old_slice = "
+            module Test
+            struct S { a: int32, tag(1) b: int32? }
+        ";
+        let new_slice = "
+            module Test
+            struct S { a: int32, tag(2) c: int32? }
+        ";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        let expected = [
+            compatible("tagged field 'Test::S::c' was added"),
+            compatible("tagged field 'Test::S::b' was removed"),
+        ];
+        assert!(all_have_level(&diagnostics, DiagnosticLevel::Allowed));
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn reusing_a_tag_is_breaking() {
+        // Arrange
+        let old_slice = "
+            module Test
+            struct S { tag(1) a: int32? }
+        ";
+        let new_slice = "
+            module Test
+            struct S { tag(1) b: int32? }
+        ";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        let expected = [
+            breaking("tag 1 of field 'Test::S::b' was previously used by field 'Test::S::a'"),
+            compatible("tagged field 'Test::S::a' was removed"),
+        ];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn changing_a_tag_is_breaking() {
+        // Arrange
+        let old_slice = "
+            module Test
+            struct S { tag(1) a: int32?, tag(2) b: string? }
+        ";
+        let new_slice = "
+            module Test
+            struct S { tag(3) a: int32?, tag(2) b: bool? }
+        ";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        let expected = [
+            breaking("tag of field 'Test::S::a' changed from 1 to 3"),
+            breaking("type of field 'Test::S::b' changed from 'string?' to 'bool?'"),
+        ];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn tagging_a_field_is_breaking() {
+        // Arrange
+        let old_slice = "
+            module Test
+            struct S { a: int32, b: int32? }
+            struct T { a: int32, tag(1) b: int32? }
+        ";
+        let new_slice = "
+            module Test
+            struct S { a: int32, tag(1) b: int32? }
+            struct T { a: int32, b: int32? }
+        ";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        let expected = [
+            breaking("field 'Test::S::b' was changed from non-tagged to tagged"),
+            breaking("field 'Test::T::b' was changed from tagged to non-tagged"),
+        ];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn changing_enumerator_values_is_breaking() {
+        // Arrange
+        let old_slice = "
+            module Test
+            enum E : uint8 { A, B, C }
+        ";
+        let new_slice = "
+            module Test
+            enum E : uint8 { A, B = 5, C }
+        ";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        let expected = [
+            breaking("value of enumerator 'Test::E::B' changed from 1 to 5"),
+            breaking("value of enumerator 'Test::E::C' changed from 2 to 6"),
+        ];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn removing_enumerators_is_breaking_for_checked_enums() {
+        // Arrange
+        let old_slice = "
+            module Test
+            enum Checked : uint8 { A, B = 2 }
+            unchecked enum Unchecked : uint8 { A, B = 2 }
+        ";
+        let new_slice = "
+            module Test
+            enum Checked : uint8 { A }
+            unchecked enum Unchecked : uint8 { A }
+        ";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        let expected = [
+            breaking("enumerator 'Test::Checked::B' was removed from checked enum").set_span(&Span::new(
+                Location { row: 3, col: 39 },
+                Location { row: 3, col: 40 },
+                "string-0",
+            )),
+            compatible("enumerator 'Test::Unchecked::B' was removed from unchecked enum"),
+        ];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn changing_the_underlying_type_of_an_enum_is_breaking() {
+        // Arrange
+        let old_slice = "
+            module Test
+            enum E : uint8 { A }
+        ";
+        let new_slice = "
+            module Test
+            enum E : int32 { A }
+        ";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        let expected = breaking("underlying type of enum 'Test::E' changed from 'uint8' to 'int32'");
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn enumerator_fields_are_checked() {
+        // Arrange
+        let old_slice = "
+            module Test
+            enum Shape { Circle(radius: float32) }
+        ";
+        let new_slice = "
+            module Test
+            enum Shape { Circle(radius: float64, tag(1) label: string?) }
+        ";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        let expected = [
+            breaking("type of field 'Test::Shape::Circle::radius' changed from 'float32' to 'float64'"),
+            compatible("tagged field 'Test::Shape::Circle::label' was added"),
+        ];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn removing_operations_is_breaking_and_adding_operations_is_compatible() {
+        // Arrange
+        let old_slice = "
+            module Test
+            interface I { op1() }
+            interface Removed { op() }
+        ";
+        let new_slice = "
+            module Test
+            interface I { op2() }
+            interface Added { op() }
+        ";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        let expected = [
+            breaking("operation 'Test::I::op1' was removed").set_span(&Span::new(
+                Location { row: 3, col: 27 },
+                Location { row: 3, col: 30 },
+                "string-0",
+            )),
+            breaking("operation 'Test::Removed::op' was removed"),
+            compatible("operation 'Test::I::op2' was added"),
+        ];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn parameters_and_return_members_are_checked() {
+        // Arrange
+        let old_slice = "
+            module Test
+            interface I {
+                op(a: int32, b: string) -> (x: bool, y: int32)
+                upload(data: stream uint8)
+            }
+        ";
+        let new_slice = "
+            module Test
+            interface I {
+                op(b: string, a: int32, tag(1) c: int32?) -> (x: bool, y: int64)
+                upload(data: uint8)
+            }
+        ";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        let expected = [
+            breaking("type of non-tagged parameter at position 1 changed from 'int32' to 'string'"),
+            breaking("type of non-tagged parameter at position 2 changed from 'string' to 'int32'"),
+            compatible("tagged parameter 'Test::I::op::c' was added"),
+            breaking("type of return element 'Test::I::op::y' changed from 'int32' to 'int64'"),
+            breaking("type of parameter 'Test::I::upload::data' changed from 'stream uint8' to 'uint8'"),
+        ];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn narrowing_encoding_support_is_breaking() {
+        // Arrange
+        let old_slice = "
+            mode = Slice1
+            module Test
+            compact struct S { a: int32 }
+        ";
+        let new_slice = "
+            module Test
+            compact struct S { a: int32 }
+        ";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        let expected = breaking("struct 'Test::S' no longer supports the Slice1 encoding");
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn changing_the_kind_of_a_type_is_breaking() {
+        // Arrange
+        let old_slice = "
+            module Test
+            struct S {}
+        ";
+        let new_slice = "
+            module Test
+            exception S {}
+        ";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        let expected = breaking("struct 'Test::S' was changed into an exception");
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("class", "class B {}", ": B", "", "from 'Test::B' to none"; "removed class base")]
+    #[test_case("class", "class B {}", "", ": B", "from none to 'Test::B'"; "added class base")]
+    #[test_case("class", "class B {}\n class C {}", ": B", ": C", "from 'Test::B' to 'Test::C'"; "changed class base")]
+    #[test_case("exception", "exception B {}", ": B", "", "from 'Test::B' to none"; "removed exception base")]
+    #[test_case("exception", "exception B {}\n exception C {}", ": B", ": C", "from 'Test::B' to 'Test::C'"; "changed exception base")]
+    fn changing_the_base_of_a_type_is_breaking(kind: &str, bases: &str, old_base: &str, new_base: &str, change: &str) {
+        // Arrange
+        let old_slice = format!("mode = Slice1\n module Test\n {bases}\n {kind} D {old_base} {{}}");
+        let new_slice = format!("mode = Slice1\n module Test\n {bases}\n {kind} D {new_base} {{}}");
+
+        // Act
+        let diagnostics = check(&old_slice, &new_slice);
+
+        // Assert
+        let expected = breaking(&format!("base type of {kind} 'Test::D' changed {change}"));
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("compact struct K { x: int32 }", "struct K { x: int32 }", "struct 'Test::K' was changed from compact to non-compact"; "compact to non-compact")]
+    #[test_case("struct K { x: int32 }", "compact struct K { x: int32 }", "struct 'Test::K' was changed from non-compact to compact"; "non-compact to compact")]
+    #[test_case("enum K : uint8 { A }", "unchecked enum K : uint8 { A }", "enum 'Test::K' was changed from checked to unchecked"; "checked to unchecked")]
+    #[test_case("unchecked enum K : uint8 { A }", "enum K : uint8 { A }", "enum 'Test::K' was changed from unchecked to checked"; "unchecked to checked")]
+    fn changing_the_form_of_a_type_is_breaking(old_definition: &str, new_definition: &str, message: &str) {
+        // Arrange
+        let old_slice = format!("module Test\n {old_definition}");
+        let new_slice = format!("module Test\n {new_definition}");
+
+        // Act
+        let diagnostics = check(&old_slice, &new_slice);
+
+        // Assert
+        check_diagnostics(diagnostics, [breaking(message)]);
+    }
+
+    #[test]
+    fn breaking_changes_can_be_allowed() {
+        // Arrange
+        let old_slice = "
+            module Test
+            struct S { a: int32 }
+            struct T { a: int32 }
+        ";
+        let new_slice = "
+            module Test
+            [allow(BreakingChange)]
+            struct S { a: int64 }
+            struct T { a: int64 }
+        ";
+        let old_state = parse(old_slice, None);
+        let mut new_state = parse(new_slice, None);
+
+        // Act
+        new_state.diagnostic_reporter.diagnostics = check_compatibility(&old_state, &new_state);
+        let diagnostics = diagnostics_from_compilation_state(new_state);
+
+        // Assert
+        let expected = breaking("type of field 'Test::T::a' changed from 'int32' to 'int64'");
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn breaking_changes_are_errors_by_default() {
+        // Arrange
+        let old_slice = "module Test\n struct S { a: int32 }";
+        let new_slice = "module Test\n struct S { a: int64 }";
+
+        // Act
+        let diagnostics = check(old_slice, new_slice);
+
+        // Assert
+        assert!(all_have_level(&diagnostics, DiagnosticLevel::Error));
+    }
+}
@@ -46,7 +46,7 @@ mod compilation_mode {
         let diagnostics = parse_for_diagnostics(slice);
 
         // Assert
-        let expected = Diagnostic::new(Error::Syntax{message: "expected one of '::', '[', 'class', 'compact', 'custom', 'doc comment', 'enum', 'exception', 'interface', 'struct', 'typealias', or 'unchecked', but found 'mode'".to_owned()});
+        let expected = Diagnostic::new(Error::Syntax{message: "expected one of '::', '[', '{', 'class', 'compact', 'const', 'custom', 'doc comment', 'enum', 'exception', 'interface', 'module', 'struct', 'typealias', or 'unchecked', but found 'mode'".to_owned()});
         check_diagnostics(diagnostics, [expected]);
     }
 }
@@ -0,0 +1,238 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+mod constants {
+
+    use crate::test_helpers::*;
+    use slicec::diagnostics::{Diagnostic, Error};
+    use slicec::grammar::*;
+    use slicec::slice_file::Span;
+    use test_case::test_case;
+
+    #[test_case("bool", "true", LiteralValue::Bool(true); "bool")]
+    #[test_case("int8", "-128", LiteralValue::Integer(-128); "int8")]
+    #[test_case("uint16", "0xFFFF", LiteralValue::Integer(65535); "uint16")]
+    #[test_case("varuint62", "1_000_000", LiteralValue::Integer(1_000_000); "varuint62")]
+    #[test_case("float32", "1.5", LiteralValue::Float(1.5); "float32")]
+    #[test_case("float64", "-2.5e-3", LiteralValue::Float(-2.5e-3); "float64 with exponent")]
+    #[test_case("float64", "7", LiteralValue::Integer(7); "float64 from integer")]
+    #[test_case("string", r#""a \"quoted\" \\ string""#, LiteralValue::String(r#"a "quoted" \ string"#.to_owned()); "string")]
+    fn can_have_primitive_type(type_string: &str, value: &str, expected: LiteralValue) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                const C: {type_string} = {value}
+            "
+        );
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let constant = ast.find_element::<Constant>("Test::C").unwrap();
+        assert_eq!(constant.identifier(), "C");
+        assert_eq!(constant.data_type.type_string(), type_string);
+        assert_eq!(constant.value.value, expected);
+    }
+
+    #[test_case("Red"; "unqualified")]
+    #[test_case("Color::Red"; "qualified")]
+    #[test_case("::Test::Color::Red"; "globally qualified")]
+    fn can_have_enum_type(value: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                enum Color : uint8 {{ Red, Green }}
+                const Favorite: Color = {value}
+            "
+        );
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let constant = ast.find_element::<Constant>("Test::Favorite").unwrap();
+        let enumerator = constant.enumerator().unwrap();
+        assert_eq!(enumerator.parser_scoped_identifier(), "Test::Color::Red");
+    }
+
+    #[test]
+    fn can_use_type_alias() {
+        // Arrange
+        let slice = "
+            module Test
+            typealias Port = uint16
+            const DefaultPort: Port = 4062
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let constant = ast.find_element::<Constant>("Test::DefaultPort").unwrap();
+        assert!(matches!(
+            constant.data_type.concrete_type(),
+            Types::Primitive(Primitive::UInt16),
+        ));
+    }
+
+    #[test]
+    fn can_be_documented() {
+        // Arrange
+        let slice = "
+            module Test
+            /// The maximum number of retries.
+            const MaxRetries: int32 = 5
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let constant = ast.find_element::<Constant>("Test::MaxRetries").unwrap();
+        assert!(constant.comment().is_some());
+    }
+
+    #[test_case("uint8", "256", 0, 255; "above max")]
+    #[test_case("uint32", "-1", 0, u32::MAX as i128; "below min")]
+    #[test_case("int16", "40000", i16::MIN as i128, i16::MAX as i128; "int16")]
+    fn values_must_be_within_numeric_bounds(type_string: &str, value: &str, min: i128, max: i128) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                const C: {type_string} = {value}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::LiteralValueOutOfBounds {
+            value: value.parse().unwrap(),
+            kind: type_string.to_owned(),
+            min,
+            max,
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("float32", "1.0e100", 1e100, f32::MAX as f64; "float32 above max")]
+    #[test_case("float32", "-3.5e38", -3.5e38, f32::MAX as f64; "float32 below min")]
+    #[test_case("float64", "1.0e400", f64::INFINITY, f64::MAX; "float64 infinite")]
+    fn float_values_must_be_within_numeric_bounds(type_string: &str, value: &str, parsed: f64, max: f64) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                const C: {type_string} = {value}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::FloatLiteralOutOfBounds {
+            value: parsed,
+            kind: type_string.to_owned(),
+            max,
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("int32", "\"hello\"", "string literal"; "string as integer")]
+    #[test_case("int32", "1.5", "floating point literal"; "float as integer")]
+    #[test_case("bool", "1", "integer literal"; "integer as bool")]
+    #[test_case("string", "false", "boolean literal"; "bool as string")]
+    #[test_case("string", "Foo", "identifier"; "identifier as string")]
+    fn values_must_match_their_type(type_string: &str, value: &str, kind: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                const C: {type_string} = {value}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::LiteralTypeMismatch {
+            expected: type_string.to_owned(),
+            actual: kind.to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn enum_values_must_be_enumerators() {
+        // Arrange
+        let slice = "
+            module Test
+            enum Color : uint8 { Red }
+            const C: Color = Blue
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::DoesNotExist {
+            identifier: "Blue".to_owned(),
+        })
+        .set_span(&Span::new((4, 30).into(), (4, 34).into(), "string-0"))
+        .add_note("'Blue' is not an enumerator of 'Color'", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("struct S {}", "S"; "structs")]
+    #[test_case("custom Foo", "Foo"; "custom types")]
+    #[test_case("", "sequence<int32>"; "sequences")]
+    #[test_case("", "int32?"; "optionals")]
+    fn cannot_have_unsupported_types(definition: &str, type_string: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                {definition}
+                const C: {type_string} = 5
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::ConstantTypeNotSupported {
+            identifier: "C".to_owned(),
+            kind: type_string.to_owned(),
+        })
+        .add_note("constants must have a non-optional primitive or enum type", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn cannot_redefine_constants() {
+        // Arrange
+        let slice = "
+            module Test
+            const C: int32 = 1
+            const C: int32 = 2
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::Redefinition {
+            identifier: "C".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+mod default_values {
+
+    use crate::test_helpers::*;
+    use slicec::diagnostics::{Diagnostic, Error};
+    use slicec::grammar::*;
+    use slicec::slice_file::Span;
+    use test_case::test_case;
+
+    #[test_case("struct", "Slice2"; "structs")]
+    #[test_case("exception", "Slice2"; "exceptions")]
+    #[test_case("class", "Slice1"; "classes")]
+    fn fields_can_have_default_values(kind: &str, mode: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                mode = {mode}
+                module Test
+                {kind} C {{
+                    count: int32 = 5
+                    name: string = \"none\"
+                    flag: bool
+                }}
+            "
+        );
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let count = ast.find_element::<Field>("Test::C::count").unwrap();
+        let name = ast.find_element::<Field>("Test::C::name").unwrap();
+        let flag = ast.find_element::<Field>("Test::C::flag").unwrap();
+        assert_eq!(count.default_value().unwrap().value, LiteralValue::Integer(5));
+        assert_eq!(
+            name.default_value().unwrap().value,
+            LiteralValue::String("none".to_owned()),
+        );
+        assert!(flag.default_value().is_none());
+    }
+
+    #[test]
+    fn enum_fields_can_default_to_enumerators() {
+        // Arrange
+        let slice = "
+            module Test
+            enum Color : uint8 { Red, Green }
+            struct S {
+                color: Color = Color::Green
+            }
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        let field = ast.find_element::<Field>("Test::S::color").unwrap();
+        let Types::Enum(enum_def) = field.data_type().concrete_type() else { panic!() };
+        let enumerator = field.default_value().unwrap().as_enumerator(enum_def).unwrap();
+        assert_eq!(enumerator.identifier(), "Green");
+    }
+
+    #[test]
+    fn parameters_cannot_have_default_values() {
+        // Arrange
+        let slice = "
+            module Test
+            interface I {
+                op(a: int32 = 5)
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::Syntax {
+            message: "expected one of ')', ',', '[', 'doc comment', 'identifier', or 'tag', but found '='".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn default_values_must_be_within_numeric_bounds() {
+        // Arrange
+        let slice = "
+            module Test
+            struct S {
+                a: uint8 = 300
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::LiteralValueOutOfBounds {
+            value: 300,
+            kind: "uint8".to_owned(),
+            min: 0,
+            max: 255,
+        })
+        .set_span(&Span::new((4, 28).into(), (4, 31).into(), "string-0"));
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("int32", "true", "boolean literal"; "bool as integer")]
+    #[test_case("float64", "\"1.0\"", "string literal"; "string as float")]
+    #[test_case("sequence<int32>", "5", "integer literal"; "integer as sequence")]
+    fn default_values_must_match_their_type(type_string: &str, value: &str, kind: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                struct S {{
+                    a: {type_string} = {value}
+                }}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::LiteralTypeMismatch {
+            expected: type_string.to_owned(),
+            actual: kind.to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn enum_default_values_must_be_enumerators() {
+        // Arrange
+        let slice = "
+            module Test
+            enum Color : uint8 { Red }
+            struct S {
+                color: Color = Other::Red
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::DoesNotExist {
+            identifier: "Other::Red".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("class Foo {}", "Foo"; "classes")]
+    #[test_case("", "AnyClass"; "AnyClass")]
+    #[test_case("custom Foo", "Foo"; "custom types")]
+    fn unsupported_types_cannot_have_default_values(definition: &str, type_string: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                mode = Slice1
+                module Test
+                {definition}
+                class C {{
+                    a: {type_string} = 5
+                }}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::DefaultValueNotSupported {
+            identifier: "a".to_owned(),
+            kind: type_string.to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+}
@@ -5,9 +5,10 @@ mod test_helpers;
 mod output {
 
     use crate::test_helpers::parse;
-    use slicec::diagnostics::{Diagnostic, Error};
+    use slicec::diagnostics::{Diagnostic, Error, Lint};
     use slicec::slice_file::Span;
     use slicec::slice_options::{DiagnosticFormat, SliceOptions};
+    use test_case::test_case;
 
     #[test]
     fn output_to_json() {
@@ -38,14 +39,113 @@ mod output {
 
         // Assert
         let expected = concat!(
-            r#"{"message":"doc comment has a param tag for 'x', but there is no parameter by that name","severity":"warning","span":{"start":{"row":5,"col":17},"end":{"row":5,"col":39},"file":"string-0"},"notes":[],"error_code":"IncorrectDocComment"}"#,
+            r#"{"message":"doc comment has a param tag for 'x', but there is no parameter by that name","severity":"warning","span":{"start":{"row":5,"col":17},"end":{"row":5,"col":39},"file":"string-0"},"notes":[],"suggestions":[],"error_code":"IncorrectDocComment"}"#,
             "\n",
-            r#"{"message":"invalid enum 'E': enums must contain at least one enumerator","severity":"error","span":{"start":{"row":9,"col":9},"end":{"row":9,"col":15},"file":"string-0"},"notes":[],"error_code":"E010"}"#,
+            r#"{"message":"invalid enum 'E': enums must contain at least one enumerator","severity":"error","span":{"start":{"row":9,"col":9},"end":{"row":9,"col":15},"file":"string-0"},"notes":[],"suggestions":[],"error_code":"E010"}"#,
             "\n",
         );
         assert_eq!(expected, String::from_utf8(output).unwrap());
     }
 
+    #[test]
+    fn output_to_sarif() {
+        let slice = r#"
+        module Foo
+
+        struct S {
+            tag(1) a: int32?
+            tag(1) b: int32?
+        }
+        "#;
+
+        // Set the output format to SARIF.
+        let options = SliceOptions {
+            diagnostic_format: DiagnosticFormat::Sarif,
+            ..Default::default()
+        };
+
+        // Parse the Slice file.
+        let compilation_state = parse(slice, Some(options));
+
+        let mut output: Vec<u8> = Vec::new();
+
+        // Act
+        compilation_state.update_and_emit_diagnostics(&mut output);
+
+        // Assert
+        let log: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(log["version"], "2.1.0");
+
+        let runs = log["runs"].as_array().unwrap();
+        assert_eq!(runs.len(), 1);
+
+        // Check that there's a rule for every kind of diagnostic.
+        let rules = runs[0]["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), Error::DESCRIPTORS.len() + Lint::DESCRIPTORS.len());
+
+        let results = runs[0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        let result = &results[0];
+        assert_eq!(result["ruleId"], "E014");
+        assert_eq!(result["level"], "error");
+        assert_eq!(rules[result["ruleIndex"].as_u64().unwrap() as usize]["id"], "E014");
+
+        let region = &result["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "string-0"
+        );
+        assert_eq!(region["startLine"], 6);
+        assert_eq!(region["startColumn"], 13);
+
+        let related_location = &result["relatedLocations"][0];
+        let related_region = &related_location["physicalLocation"]["region"];
+        assert_eq!(
+            related_location["message"]["text"],
+            "The tag '1' is already being used by member 'a'"
+        );
+        assert_eq!(related_region["startLine"], 5);
+    }
+
+    #[test]
+    fn sarif_notes_without_spans_are_appended_to_messages() {
+        let slice = "
+        module Foo
+        const C: sequence<int32> = 1
+        ";
+        let options = SliceOptions {
+            diagnostic_format: DiagnosticFormat::Sarif,
+            ..Default::default()
+        };
+        let compilation_state = parse(slice, Some(options));
+        let mut output: Vec<u8> = Vec::new();
+
+        // Act
+        compilation_state.update_and_emit_diagnostics(&mut output);
+
+        // Assert
+        let log: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        let result = &log["runs"][0]["results"][0];
+        assert_eq!(
+            result["message"]["text"],
+            "invalid constant 'C': constants cannot be of type 'sequence<int32>'\nconstants must have a non-optional primitive or enum type",
+        );
+        assert_eq!(result["relatedLocations"], serde_json::json!([]));
+    }
+
+    #[test_case("UnusedImport", "unused import '{0}'"; "lint")]
+    #[test_case("MalformedDocComment", "{0}"; "lint with message field")]
+    #[test_case("E002", "invalid syntax: {0}"; "error")]
+    #[test_case("E022", "type mismatch: expected '{0}' but found '{1}'"; "error with conditional message")]
+    fn message_templates_show_placeholders(code: &str, expected: &str) {
+        // Act
+        let mut descriptors = Error::DESCRIPTORS.iter().chain(Lint::DESCRIPTORS);
+        let descriptor = descriptors.find(|d| d.code == code).unwrap();
+
+        // Assert
+        assert_eq!(descriptor.message_template, expected);
+    }
+
     #[test]
     fn output_to_console() {
         let slice = "
@@ -96,6 +196,12 @@ error [E019]: invalid tag on member 'x': tagged members must be optional
 10 |                     int32, tag(2) y: bool?,
    | -------------------------
    |
+    = help: make the type optional
+ --> string-0:10:26
+   |
+10 -                     int32, tag(2) y: bool?,
+10 +                     int32?, tag(2) y: bool?,
+   |
 error [E010]: invalid enum 'E': enums must contain at least one enumerator
  --> string-0:14:9
    |
@@ -166,7 +272,7 @@ error [E010]: invalid enum 'E': enums must contain at least one enumerator
 
         // Assert: Only one of the two lints should be allowed.
         let expected = concat!(
-            r#"{"message":"doc comment has a param tag for 'x', but there is no parameter by that name","severity":"warning","span":{"start":{"row":6,"col":21},"end":{"row":6,"col":43},"file":"string-0"},"notes":[],"error_code":"IncorrectDocComment"}"#,
+            r#"{"message":"doc comment has a param tag for 'x', but there is no parameter by that name","severity":"warning","span":{"start":{"row":6,"col":21},"end":{"row":6,"col":43},"file":"string-0"},"notes":[],"suggestions":[],"error_code":"IncorrectDocComment"}"#,
             "\n",
         );
         assert_eq!(expected, String::from_utf8(output).unwrap());
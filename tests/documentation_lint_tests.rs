@@ -0,0 +1,174 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+mod documentation_lints {
+
+    use crate::test_helpers::*;
+    use slicec::diagnostics::{Diagnostic, Lint};
+    use slicec::slice_file::Span;
+    use test_case::test_case;
+
+    const UNDOCUMENTED: &str = "
+        module Test
+
+        exception E {}
+
+        interface I {
+            op(a: int32) -> string throws E
+        }
+    ";
+
+    #[test]
+    fn documentation_lints_are_allowed_by_default() {
+        // Act
+        let diagnostics = parse_for_diagnostics(UNDOCUMENTED);
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn missing_doc_comments_can_be_enabled() {
+        // Act
+        let diagnostics = parse_with_warned_lint(UNDOCUMENTED, "MissingDocComment");
+
+        // Assert
+        let expected = [
+            Diagnostic::new(Lint::MissingDocComment {
+                kind: "exception".to_owned(),
+                identifier: "E".to_owned(),
+            })
+            .set_span(&Span::new((4, 9).into(), (4, 20).into(), "string-0")),
+            Diagnostic::new(Lint::MissingDocComment {
+                kind: "interface".to_owned(),
+                identifier: "I".to_owned(),
+            }),
+            Diagnostic::new(Lint::MissingDocComment {
+                kind: "operation".to_owned(),
+                identifier: "op".to_owned(),
+            }),
+        ];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test_case("/// @param a: the value."; "param tag")]
+    #[test_case(""; "no tag")]
+    fn missing_param_docs_can_be_enabled(comment: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                interface I {{
+                    {comment}
+                    op(a: int32, /// The value.\n b: int32)
+                }}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_with_warned_lint(&slice, "MissingParamDoc");
+
+        // Assert
+        if comment.is_empty() {
+            let expected = Diagnostic::new(Lint::MissingParamDoc {
+                identifier: "a".to_owned(),
+                operation: "op".to_owned(),
+            });
+            check_diagnostics(diagnostics, [expected]);
+        } else {
+            let expected: [Diagnostic; 0] = [];
+            check_diagnostics(diagnostics, expected);
+        }
+    }
+
+    #[test_case("/// @returns: the value.", "op() -> string"; "returns tag")]
+    #[test_case("", "op() -> (/// The first value.\n a: string, /// The second value.\n b: string)"; "member comments")]
+    #[test_case("", "op()"; "void operation")]
+    fn documented_return_values_are_accepted(comment: &str, operation: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                interface I {{
+                    {comment}
+                    {operation}
+                }}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_with_warned_lint(&slice, "MissingReturnsDoc");
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn missing_returns_docs_can_be_enabled() {
+        // Act
+        let diagnostics = parse_with_warned_lint(UNDOCUMENTED, "MissingReturnsDoc");
+
+        // Assert
+        let expected = Diagnostic::new(Lint::MissingReturnsDoc {
+            operation: "op".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("/// @throws E: if something fails."; "typed tag")]
+    #[test_case("/// @throws: if something fails."; "untyped tag")]
+    fn documented_exceptions_are_accepted(comment: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                module Test
+                exception E {{}}
+                interface I {{
+                    {comment}
+                    op() throws E
+                }}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_with_warned_lint(&slice, "UndocumentedThrows");
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn undocumented_throws_can_be_enabled() {
+        // Act
+        let diagnostics = parse_with_warned_lint(UNDOCUMENTED, "UndocumentedThrows");
+
+        // Assert
+        let expected = Diagnostic::new(Lint::UndocumentedThrows {
+            operation: "op".to_owned(),
+            exception: "E".to_owned(),
+        })
+        .set_span(&Span::new((7, 43).into(), (7, 44).into(), "string-0"));
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn warned_lints_can_still_be_allowed_by_attributes() {
+        // Arrange
+        let slice = "
+            [[allow(MissingDocComment, Unused)]]
+            module Test
+            struct S {}
+        ";
+
+        // Act
+        let diagnostics = parse_with_warned_lint(slice, "All");
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+}
@@ -0,0 +1,198 @@
+// Copyright (c) ZeroC, Inc.
+
+use crate::test_helpers::*;
+use slicec::diagnostics::{Diagnostic, Error};
+use slicec::grammar::*;
+
+#[test]
+fn enumerators_can_have_fields() {
+    // Arrange
+    let slice = "
+        module Test
+        enum Shape {
+            Circle(radius: float64)
+            Rectangle(width: float64, height: float64)
+            Point
+        }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let enumerators = ast.find_element::<Enum>("Test::Shape").unwrap().enumerators();
+    assert_eq!(enumerators[0].fields().len(), 1);
+    assert_eq!(enumerators[1].fields().len(), 2);
+    assert!(!enumerators[2].has_fields());
+
+    let radius = ast.find_element::<Field>("Test::Shape::Circle::radius").unwrap();
+    assert_eq!(radius.parent().identifier(), "Circle");
+    assert_eq!(radius.data_type.type_string(), "float64");
+}
+
+#[test]
+fn enumerators_with_fields_can_have_explicit_values() {
+    // Arrange
+    let slice = "
+        module Test
+        enum E {
+            A(a: int32) = 5
+            B
+        }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let enumerators = ast.find_element::<Enum>("Test::E").unwrap().enumerators();
+    assert_eq!(enumerators[0].value(), 5);
+    assert_eq!(enumerators[1].value(), 6);
+}
+
+#[test]
+fn enumerator_fields_can_be_tagged() {
+    // Arrange
+    let slice = "
+        module Test
+        enum E {
+            A(a: int32, tag(1) b: string?)
+        }
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    let field = ast.find_element::<Field>("Test::E::A::b").unwrap();
+    assert_eq!(field.tag(), Some(1));
+}
+
+#[test]
+fn enumerator_tags_must_be_unique() {
+    // Arrange
+    let slice = "
+        module Test
+        enum E {
+            A(tag(1) a: int32?, tag(1) b: int32?)
+        }
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::CannotHaveDuplicateTag {
+        identifier: "b".to_owned(),
+    })
+    .add_note("The tag '1' is already being used by member 'a'", None);
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn enumerator_fields_cannot_be_redefined() {
+    // Arrange
+    let slice = "
+        module Test
+        enum E {
+            A(a: int32, a: string)
+        }
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::Redefinition {
+        identifier: "a".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn enums_with_underlying_types_cannot_have_fields() {
+    // Arrange
+    let slice = "
+        module Test
+        enum E : uint8 {
+            A(a: int32)
+        }
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::EnumeratorCannotContainFields {
+        enumerator_identifier: "A".to_owned(),
+    })
+    .add_note("the underlying type of 'E' is specified here:", None);
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn enumerator_fields_cannot_be_cyclic() {
+    // Arrange
+    let slice = "
+        module Test
+        enum List {
+            Nil
+            Cons(head: int32, tail: List)
+        }
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::InfiniteSizeCycle {
+        type_id: "Test::List".to_owned(),
+        cycle: "Test::List -> Test::List".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn enums_with_fields_cannot_be_dictionary_keys() {
+    // Arrange
+    let slice = "
+        module Test
+        enum E {
+            A(a: int32)
+        }
+        typealias D = dictionary<E, int32>
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::KeyTypeNotSupported {
+        kind: "enum".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn enumerators_with_fields_are_disallowed_in_slice1() {
+    // Arrange
+    let slice = "
+        mode = Slice1
+        module Test
+        enum E {
+            A(a: int32)
+        }
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::NotSupportedInCompilationMode {
+        kind: "enum".to_owned(),
+        identifier: "E".to_owned(),
+        mode: CompilationMode::Slice1,
+    })
+    .add_note("enumerators with fields can only be used in Slice2 mode", None);
+    check_diagnostics(diagnostics, [expected]);
+}
@@ -1,4 +1,5 @@
 // Copyright (c) ZeroC, Inc.
 
+mod associated_fields;
 mod container;
 mod mode_compatibility;
@@ -0,0 +1,460 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+mod formatter {
+
+    use crate::test_helpers::*;
+    use slicec::ast::Ast;
+    use slicec::formatter::format_slice;
+    use slicec::grammar::*;
+    use slicec::slice_file::SliceFile;
+    use slicec::visitor::Visitor;
+    use std::fs;
+    use std::process::Command;
+    use test_case::test_case;
+
+    fn format(slice: &str) -> String {
+        match format_slice("string-0", slice) {
+            Ok(formatted) => formatted,
+            Err(diagnostics) => panic!("{diagnostics:?}"),
+        }
+    }
+
+    /// Describes every element in an AST with a string, ignoring spans and doc comments.
+    /// Two ASTs have the same definitions if their descriptions are equal.
+    fn describe_ast(ast: &Ast, files: &[&SliceFile]) -> Vec<String> {
+        struct Describer(Vec<String>);
+
+        impl Describer {
+            fn describe(&mut self, entity: &dyn Entity, details: String) {
+                let attributes = entity.attributes().into_iter().map(|a| a.kind.directive().to_owned());
+                let attributes = attributes.collect::<Vec<_>>();
+                let identifier = entity.parser_scoped_identifier();
+                self.0
+                    .push(format!("{} {identifier} {attributes:?} {details}", entity.kind()));
+            }
+        }
+
+        impl Visitor for Describer {
+            fn visit_file(&mut self, slice_file: &SliceFile) {
+                self.0.push(format!("file {:?}", slice_file.compilation_mode()));
+            }
+
+            fn visit_module(&mut self, module_def: &Module) {
+                self.0.push(format!("module {}", module_def.nested_module_identifier()));
+            }
+
+            fn visit_struct(&mut self, struct_def: &Struct) {
+                self.describe(struct_def, format!("compact={}", struct_def.is_compact));
+            }
+
+            fn visit_class(&mut self, class_def: &Class) {
+                let base = class_def.base.as_ref().map(|base| base.type_string());
+                self.describe(class_def, format!("base={base:?}"));
+            }
+
+            fn visit_exception(&mut self, exception_def: &Exception) {
+                let base = exception_def.base.as_ref().map(|base| base.type_string());
+                self.describe(exception_def, format!("base={base:?}"));
+            }
+
+            fn visit_interface(&mut self, interface_def: &Interface) {
+                let bases = interface_def
+                    .bases
+                    .iter()
+                    .map(|base| base.type_string())
+                    .collect::<Vec<_>>();
+                self.describe(interface_def, format!("bases={bases:?}"));
+            }
+
+            fn visit_enum(&mut self, enum_def: &Enum) {
+                let underlying = enum_def.underlying.as_ref().map(|underlying| underlying.type_string());
+                self.describe(enum_def, format!("underlying={underlying:?}"));
+            }
+
+            fn visit_operation(&mut self, operation: &Operation) {
+                let throws = match &operation.throws {
+                    Throws::None => "nothing".to_owned(),
+                    Throws::Specific(exception) => exception.type_string(),
+                    Throws::AnyException => "any".to_owned(),
+                };
+                let details = format!("idempotent={} throws={throws}", operation.is_idempotent);
+                self.describe(operation, details);
+            }
+
+            fn visit_custom_type(&mut self, custom_type: &CustomType) {
+                self.describe(custom_type, String::new());
+            }
+
+            fn visit_type_alias(&mut self, type_alias: &TypeAlias) {
+                self.describe(type_alias, type_alias.underlying.type_string());
+            }
+
+            fn visit_constant(&mut self, constant: &Constant) {
+                let details = format!("{} = {:?}", constant.data_type.type_string(), constant.value.value);
+                self.describe(constant, details);
+            }
+
+            fn visit_field(&mut self, field: &Field) {
+                let tag = field.tag.as_ref().map(|tag| tag.value);
+                let default_value = field.default_value.as_ref().map(|literal| &literal.value);
+                let details = format!(
+                    "{} tag={tag:?} default={default_value:?}",
+                    field.data_type.type_string()
+                );
+                self.describe(field, details);
+            }
+
+            fn visit_parameter(&mut self, parameter: &Parameter) {
+                let tag = parameter.tag.as_ref().map(|tag| tag.value);
+                let details = format!(
+                    "{} tag={tag:?} streamed={} returned={}",
+                    parameter.data_type.type_string(),
+                    parameter.is_streamed,
+                    parameter.is_returned,
+                );
+                self.describe(parameter, details);
+            }
+
+            fn visit_enumerator(&mut self, enumerator: &Enumerator) {
+                self.describe(enumerator, format!("value={}", enumerator.value()));
+            }
+
+            fn visit_type_ref(&mut self, type_ref: &TypeRef) {
+                self.0.push(format!("type {}", type_ref.type_string()));
+            }
+        }
+
+        let mut describer = Describer(Vec::new());
+        for slice_file in files {
+            slice_file.visit_with(&mut describer);
+        }
+        describer.0.push(format!("{} nodes", ast.as_slice().len()));
+        describer.0
+    }
+
+    #[test]
+    fn definitions_are_formatted_canonically() {
+        // Arrange
+        let slice = "
+mode=Slice1
+module   Test{
+  compact struct Point{x:int32, y : int32,
+     tag(1)   z:string?=\"hello\"}
+interface Shapes:Base1,Base2{
+  getOrigin ( ) ->Point
+      idempotent move(p:Point,dx:int32)->(result:Point,moved:bool) throws Oops
+}
+enum E:uint8{A=1,B,C=-3}
+exception Oops{}
+class C(5):Base{f:AnyClass?}
+typealias Alias=sequence<::Test::Point>
+const MAX:int32=5
+}
+";
+
+        // Act
+        let formatted = format(slice);
+
+        // Assert
+        let expected = "\
+mode = Slice1
+module Test {
+    compact struct Point {
+        x: int32
+        y: int32
+        tag(1) z: string? = \"hello\"
+    }
+    interface Shapes : Base1, Base2 {
+        getOrigin() -> Point
+        idempotent move(p: Point, dx: int32) -> (result: Point, moved: bool) throws Oops
+    }
+    enum E : uint8 {
+        A = 1
+        B
+        C = -3
+    }
+    exception Oops {}
+    class C(5) : Base {
+        f: AnyClass?
+    }
+    typealias Alias = sequence<::Test::Point>
+    const MAX: int32 = 5
+}
+";
+        assert_eq!(formatted, expected);
+    }
+
+    #[test]
+    fn doc_comments_and_attributes_are_placed_on_their_own_lines() {
+        // Arrange
+        let slice = "
+[[allow(All)]] module Test
+/// A struct.
+  ///
+[deprecated] [cs::attribute(\"a\", [1, 2],)] struct S {
+    /// A field.
+    [cs::identifier(\"F\")] f: [cs::type(\"int\")] int32
+}
+";
+
+        // Act
+        let formatted = format(slice);
+
+        // Assert
+        let expected = "\
+[[allow(All)]]
+module Test
+/// A struct.
+///
+[deprecated]
+[cs::attribute(\"a\", [1, 2])]
+struct S {
+    /// A field.
+    [cs::identifier(\"F\")]
+    f: [cs::type(\"int\")] int32
+}
+";
+        assert_eq!(formatted, expected);
+    }
+
+    #[test]
+    fn comments_and_preprocessor_directives_are_kept() {
+        // Arrange
+        let slice = "
+// Copyright header
+
+
+module Test { // Trailing comment.
+
+    /* A block
+       comment. */
+      #if FOO
+    struct S {}
+        #else
+    struct S { f: int32 }   // Another trailing comment.
+#endif
+
+
+    // The end.
+}
+";
+
+        // Act
+        let formatted = format(slice);
+
+        // Assert
+        let expected = "\
+// Copyright header
+
+module Test { // Trailing comment.
+    /* A block
+       comment. */
+#if FOO
+    struct S {}
+#else
+    struct S {
+        f: int32
+    } // Another trailing comment.
+#endif
+
+    // The end.
+}
+";
+        assert_eq!(formatted, expected);
+    }
+
+    #[test]
+    fn long_parameter_lists_are_wrapped() {
+        // Arrange
+        let slice = "
+module Test
+interface I {
+    shortOperation(a: int32) -> bool
+    aVeryLongOperationName(firstParameter: sequence<string>, secondParameter: dictionary<string, int32>, third: int64) -> bool
+    commentedOperation(a: int32, // The first parameter.
+        b: int32)
+}
+";
+
+        // Act
+        let formatted = format(slice);
+
+        // Assert
+        let expected = "\
+module Test
+interface I {
+    shortOperation(a: int32) -> bool
+    aVeryLongOperationName(
+        firstParameter: sequence<string>,
+        secondParameter: dictionary<string, int32>,
+        third: int64,
+    ) -> bool
+    commentedOperation(
+        a: int32, // The first parameter.
+        b: int32,
+    )
+}
+";
+        assert_eq!(formatted, expected);
+    }
+
+    #[test]
+    fn long_return_tuples_are_wrapped() {
+        // Arrange
+        let slice = "
+module Test
+interface I {
+    op(a: int32) -> (firstReturnMember: sequence<string>, secondReturnMember: dictionary<string, sequence<int32>>, thirdMember: bool)
+}
+";
+
+        // Act
+        let formatted = format(slice);
+
+        // Assert
+        let expected = "\
+module Test
+interface I {
+    op(
+        a: int32,
+    ) -> (
+        firstReturnMember: sequence<string>,
+        secondReturnMember: dictionary<string, sequence<int32>>,
+        thirdMember: bool,
+    )
+}
+";
+        assert_eq!(formatted, expected);
+    }
+
+    #[test]
+    fn syntax_errors_are_reported() {
+        // Arrange
+        let slice = "module Test\nstruct {}";
+
+        // Act
+        let diagnostics = format_slice("string-0", slice).unwrap_err();
+
+        // Assert
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code(), "E002");
+    }
+
+    #[test]
+    fn directives_which_split_definitions_are_reported() {
+        // Arrange
+        let slice = "
+module Test
+#if FOO
+interface I : Base {
+#else
+interface I {
+#endif
+}
+";
+
+        // Act
+        let diagnostics = format_slice("string-0", slice).unwrap_err();
+
+        // Assert
+        let message = diagnostics[0].message();
+        assert!(
+            message.contains("preprocessor directives splitting a definition"),
+            "{message}"
+        );
+    }
+
+    #[test_case(
+        "
+        module Test
+        /// A point.
+        compact struct Point { x: int32, y: int32 }
+        struct Line { start: Point end: Point, tag(1) label: string? }
+        const Origin: int32 = -1
+        custom Custom
+        typealias Points = sequence<Point>
+        "; "structs"
+    )]
+    #[test_case(
+        "
+        mode = Slice1
+        module Test {
+            class Base {}
+            class Derived(7) : Base { [cs::identifier(\"Value\")] value: AnyClass? }
+            exception BaseException { message: string }
+            exception DerivedException : BaseException {}
+        }
+        "; "classes and exceptions"
+    )]
+    #[test_case(
+        "
+        module Test
+        enum Color : uint8 { Red = 1, Green, Blue = 10 }
+        unchecked enum Flags : int32 { A = -1 }
+        enum Shape { Circle(radius: float64), Rectangle(width: float64, height: float64) }
+        "; "enums"
+    )]
+    #[test_case(
+        "
+        module Test
+        exception Oops {}
+        interface Base {}
+        interface Greeter : Base {
+            greet(name: string, tag(1) nickname: string?) -> string throws Oops
+            idempotent streamGreetings(count: int32) -> stream string
+            describe(verbose: bool) -> (description: string, tag(2) details: sequence<string>?)
+            anExtremelyLongOperationNameToForceWrapping(firstParameter: dictionary<string, sequence<int32>>, second: int32) -> bool
+        }
+        "; "interfaces"
+    )]
+    fn formatting_is_stable_and_preserves_the_ast(slice: &str) {
+        // Arrange
+        let original_ast = parse_for_ast(slice);
+
+        // Act
+        let formatted = format(slice);
+        let reformatted = format(&formatted);
+
+        // Assert
+        assert_eq!(formatted, reformatted);
+
+        let original_state = parse(slice, None);
+        let formatted_state = parse(formatted.as_str(), None);
+        assert!(!formatted_state.diagnostic_reporter.has_errors());
+        let original_files = original_state.files.values().collect::<Vec<_>>();
+        let formatted_files = formatted_state.files.values().collect::<Vec<_>>();
+        assert_eq!(
+            describe_ast(&original_state.ast, &original_files),
+            describe_ast(&formatted_state.ast, &formatted_files),
+        );
+        assert_eq!(original_ast.as_slice().len(), formatted_state.ast.as_slice().len());
+    }
+
+    #[test_case("module Test\nstruct S {\n    a: int32\n}\n", 0; "formatted")]
+    #[test_case("module Test\nstruct   S{a:int32}\n", 1; "unformatted")]
+    fn check_mode_reports_unformatted_files_without_modifying_them(slice: &str, expected_exit_code: i32) {
+        // Arrange
+        let directory =
+            std::env::temp_dir().join(format!("slicec-fmt-check-{}-{expected_exit_code}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("test.slice");
+        fs::write(&path, slice).unwrap();
+
+        // Act
+        let output = Command::new(env!("CARGO_BIN_EXE_slicec-fmt"))
+            .arg("--check")
+            .arg(&path)
+            .output()
+            .unwrap();
+
+        // Assert
+        assert_eq!(output.status.code(), Some(expected_exit_code));
+        assert_eq!(fs::read_to_string(&path).unwrap(), slice);
+        let is_reported = String::from_utf8_lossy(&output.stderr).contains("is not formatted");
+        assert_eq!(is_reported, expected_exit_code == 1);
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+mod imports {
+
+    use crate::test_helpers::*;
+    use slicec::compile_from_strings;
+    use slicec::diagnostics::{Diagnostic, Error, Lint};
+    use slicec::grammar::*;
+    use slicec::slice_file::Span;
+    use slicec::slice_options::SliceOptions;
+    use test_case::test_case;
+
+    fn parse_strict_for_diagnostics(slices: &[&str]) -> Vec<Diagnostic> {
+        let options = SliceOptions {
+            strict_imports: true,
+            ..Default::default()
+        };
+        diagnostics_from_compilation_state(compile_from_strings(slices, Some(options), |_| {}, |_| {}))
+    }
+
+    const DEFINITIONS: &str = "
+        module Other
+        struct Point {}
+        struct Line {}
+    ";
+
+    #[test]
+    fn imports_are_stored_on_files() {
+        // Arrange
+        let slice = "
+            import \"string-1\"
+            import Other::Point
+            import ::Other
+            module Test
+        ";
+
+        // Act
+        let compilation_state = compile_from_strings(&[slice, DEFINITIONS], None, |_| {}, |_| {});
+
+        // Assert
+        let file = compilation_state.files.get("string-0").unwrap();
+        assert_eq!(file.imports.len(), 3);
+        assert!(matches!(&file.imports[0].kind, ImportKind::File(path) if path == "string-1"));
+        assert!(matches!(&file.imports[1].kind, ImportKind::Definition(i) if i.value == "Other::Point"));
+        assert!(matches!(&file.imports[2].kind, ImportKind::Definition(i) if i.value == "::Other"));
+    }
+
+    #[test]
+    fn imports_are_optional_by_default() {
+        // Arrange
+        let slice = "
+            module Test
+            struct S {
+                p: Other::Point
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_multiple_for_diagnostics(&[slice, DEFINITIONS]);
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test_case("import \"string-1\""; "file import")]
+    #[test_case("import \"./string-1\""; "relative file import")]
+    #[test_case("import Other::Point"; "definition import")]
+    #[test_case("import Other"; "module import")]
+    fn strict_mode_allows_imported_definitions(import: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                {import}
+                module Test
+                struct S {{
+                    p: Other::Point
+                }}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_strict_for_diagnostics(&[&slice, DEFINITIONS]);
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn strict_mode_rejects_definitions_that_are_not_imported() {
+        // Arrange
+        let slice = "
+            import Other::Point
+            module Test
+            struct S {
+                p: Other::Point
+                l: Other::Line
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_strict_for_diagnostics(&[slice, DEFINITIONS]);
+
+        // Assert
+        let expected = Diagnostic::new(Error::DefinitionNotImported {
+            identifier: "Other::Line".to_owned(),
+            file: "string-1".to_owned(),
+        })
+        .add_note("consider adding 'import Other::Line' to this file", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn strict_mode_allows_definitions_from_the_same_file() {
+        // Arrange
+        let slice = "
+            module Test
+            struct Point {}
+            struct S {
+                p: Point
+                i: int32
+                s: sequence<Point>
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_strict_for_diagnostics(&[slice]);
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn unused_imports_are_reported() {
+        // Arrange
+        let slice = "
+            import Other::Point
+            import Other::Line
+            module Test
+            struct S {
+                p: Other::Point
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_multiple_for_diagnostics(&[slice, DEFINITIONS]);
+
+        // Assert
+        let span = Span::new((3, 13).into(), (3, 31).into(), "string-0");
+        let expected = Diagnostic::new(Lint::UnusedImport {
+            import: "Other::Line".to_owned(),
+        })
+        .add_suggestion("remove the unused import", &span, "");
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("Test::Point", "/// See {@link Point}."; "link")]
+    #[test_case("Test::Point", "/// @see Point"; "see tag")]
+    #[test_case("Test::Failure", "/// @throws Failure: sometimes."; "throws tag")]
+    fn imports_used_by_doc_comment_links_are_not_reported(import: &str, comment: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                import {import}
+                module Test
+                interface I {{
+                    {comment}
+                    [allow(IncorrectDocComment)]
+                    op()
+                }}
+            "
+        );
+        let definitions = "
+            module Test
+            struct Point {}
+            exception Failure {}
+        ";
+
+        // Act
+        let diagnostics = parse_strict_for_diagnostics(&[&slice, definitions]);
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test_case("import \"missing.slice\"", "missing.slice", "no Slice file with this path was passed to the compiler"; "file")]
+    #[test_case("import Other::Missing", "Other::Missing", "no definition with this identifier exists"; "definition")]
+    fn unresolved_imports_are_rejected(import: &str, import_string: &str, note: &str) {
+        // Arrange
+        let slice = format!(
+            "
+                {import}
+                module Test
+            "
+        );
+
+        // Act
+        let diagnostics = parse_multiple_for_diagnostics(&[&slice, DEFINITIONS]);
+
+        // Assert
+        let expected = Diagnostic::new(Error::UnresolvedImport {
+            import: import_string.to_owned(),
+        })
+        .add_note(note, None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+}
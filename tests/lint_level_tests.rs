@@ -0,0 +1,174 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+mod lint_levels {
+
+    use crate::test_helpers::*;
+    use slicec::compile_from_strings;
+    use slicec::diagnostics::{Diagnostic, DiagnosticLevel, Error};
+    use slicec::slice_options::SliceOptions;
+    use test_case::test_case;
+
+    /// Compiles the provided Slice and returns the levels of all the (non-allowed) diagnostics it emitted.
+    fn compile_for_levels(slice: &str, options: SliceOptions) -> Vec<DiagnosticLevel> {
+        let compilation_state = compile_from_strings(&[slice], Some(options), |_| {}, |_| {});
+        let diagnostics = diagnostics_from_compilation_state(compilation_state);
+        diagnostics.iter().map(Diagnostic::level).collect()
+    }
+
+    fn options(allowed: &[&str], warned: &[&str], denied: &[&str]) -> SliceOptions {
+        let to_vec = |lints: &[&str]| lints.iter().map(|lint| lint.to_string()).collect();
+        SliceOptions {
+            allowed_lints: to_vec(allowed),
+            warned_lints: to_vec(warned),
+            denied_lints: to_vec(denied),
+            ..Default::default()
+        }
+    }
+
+    /// Slice that emits a single `Deprecated` lint, with placeholders for attributes on its file and its scope.
+    /// Everything is documented, so that configuring `All` lints doesn't also enable the documentation lints.
+    fn deprecated_usage(file_attribute: &str, struct_attribute: &str) -> String {
+        format!(
+            "
+            {file_attribute}
+            module Test
+
+            /// A deprecated struct.
+            /// @deprecated: use something else.
+            [deprecated]
+            struct S {{}}
+
+            /// A struct that uses a deprecated struct.
+            {struct_attribute}
+            struct UseS {{
+                /// A deprecated field.
+                s: S
+            }}
+            "
+        )
+    }
+
+    #[test]
+    fn lints_use_their_default_level() {
+        // Act
+        let levels = compile_for_levels(&deprecated_usage("", ""), SliceOptions::default());
+
+        // Assert
+        assert_eq!(levels, [DiagnosticLevel::Warning]);
+    }
+
+    #[test_case(&[], &[], &["Deprecated"], Some(DiagnosticLevel::Error); "deny")]
+    #[test_case(&[], &[], &["All"], Some(DiagnosticLevel::Error); "deny all")]
+    #[test_case(&["Deprecated"], &[], &[], None; "allow")]
+    #[test_case(&["All"], &[], &["Deprecated"], Some(DiagnosticLevel::Error); "specific deny beats allow all")]
+    #[test_case(&["Deprecated"], &[], &["All"], None; "specific allow beats deny all")]
+    #[test_case(&[], &["Deprecated"], &["All"], Some(DiagnosticLevel::Warning); "specific warn beats deny all")]
+    #[test_case(&["Deprecated"], &[], &["Deprecated"], None; "allow beats deny")]
+    fn command_line_options_configure_lints(
+        allowed: &[&str],
+        warned: &[&str],
+        denied: &[&str],
+        expected: Option<DiagnosticLevel>,
+    ) {
+        // Act
+        let levels = compile_for_levels(&deprecated_usage("", ""), options(allowed, warned, denied));
+
+        // Assert
+        assert_eq!(levels, Vec::from_iter(expected));
+    }
+
+    #[test_case("[[deny(Deprecated)]]", "", Some(DiagnosticLevel::Error); "file deny")]
+    #[test_case("", "[deny(All)]", Some(DiagnosticLevel::Error); "local deny")]
+    #[test_case("[[allow(Deprecated)]]", "[deny(Deprecated)]", Some(DiagnosticLevel::Error); "local deny beats file allow")]
+    #[test_case("[[deny(Deprecated)]]", "[allow(Deprecated)]", None; "local allow beats file deny")]
+    #[test_case("[[deny(Deprecated)]]", "[warn(Deprecated)]", Some(DiagnosticLevel::Warning); "local warn beats file deny")]
+    #[test_case("", "[allow(Deprecated)] [deny(Deprecated)]", Some(DiagnosticLevel::Error); "last attribute wins")]
+    fn attributes_configure_lints(file_attribute: &str, struct_attribute: &str, expected: Option<DiagnosticLevel>) {
+        // Act
+        let levels = compile_for_levels(
+            &deprecated_usage(file_attribute, struct_attribute),
+            SliceOptions::default(),
+        );
+
+        // Assert
+        assert_eq!(levels, Vec::from_iter(expected));
+    }
+
+    #[test_case("[[allow(Deprecated)]]", &[], &["Deprecated"], None; "file allow beats deny option")]
+    #[test_case("[[warn(Deprecated)]]", &["All"], &[], Some(DiagnosticLevel::Warning); "file warn beats allow option")]
+    fn attributes_take_precedence_over_command_line_options(
+        file_attribute: &str,
+        allowed: &[&str],
+        denied: &[&str],
+        expected: Option<DiagnosticLevel>,
+    ) {
+        // Act
+        let levels = compile_for_levels(&deprecated_usage(file_attribute, ""), options(allowed, &[], denied));
+
+        // Assert
+        assert_eq!(levels, Vec::from_iter(expected));
+    }
+
+    #[test_case(""; "default level")]
+    #[test_case("[[warn(Deprecated)]]"; "warn attribute")]
+    fn warnings_as_errors_raises_warnings(file_attribute: &str) {
+        // Arrange
+        let options = SliceOptions {
+            warnings_as_errors: true,
+            ..Default::default()
+        };
+
+        // Act
+        let levels = compile_for_levels(&deprecated_usage(file_attribute, ""), options);
+
+        // Assert
+        assert_eq!(levels, [DiagnosticLevel::Error]);
+    }
+
+    #[test]
+    fn warnings_as_errors_does_not_raise_allowed_lints() {
+        // Arrange
+        let options = SliceOptions {
+            warnings_as_errors: true,
+            ..Default::default()
+        };
+
+        // Act
+        let levels = compile_for_levels(&deprecated_usage("[[allow(Deprecated)]]", ""), options);
+
+        // Assert
+        assert!(levels.is_empty());
+    }
+
+    #[test]
+    fn denied_lints_fail_compilation() {
+        // Arrange
+        let slice = deprecated_usage("[[deny(Deprecated)]]", "");
+
+        // Act
+        let compilation_state = compile_from_strings(&[&slice], None, |_| {}, |_| {});
+        let (total_warnings, total_errors) = compilation_state.update_and_emit_diagnostics(&mut Vec::new());
+
+        // Assert
+        assert_eq!((total_warnings, total_errors), (0, 1));
+    }
+
+    #[test_case("warn")]
+    #[test_case("deny")]
+    fn lint_attributes_reject_unknown_lints(directive: &str) {
+        // Arrange
+        let slice = format!("[[{directive}(Fake)]]");
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::ArgumentNotSupported {
+            argument: "Fake".to_owned(),
+            directive: directive.to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+}
@@ -0,0 +1,466 @@
+// Copyright (c) ZeroC, Inc.
+
+mod lsp {
+
+    use serde_json::{json, Value};
+    use std::fs;
+    use std::io::{Read, Write};
+    use std::process::{Command, Stdio};
+    use test_case::test_case;
+
+    const POINT_URI: &str = "file:///point.slice";
+    const POINT_SLICE: &str = "module Test
+
+/// A point in space.
+struct Point {
+    x: int32
+    y: int32
+}
+";
+
+    const SHAPES_URI: &str = "file:///shapes.slice";
+    const SHAPES_SLICE: &str = "module Test
+
+interface Shapes {
+    getOrigin() -> Point
+}
+";
+
+    fn request(id: i64, method: &str, params: Value) -> Value {
+        json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+    }
+
+    fn notification(method: &str, params: Value) -> Value {
+        json!({ "jsonrpc": "2.0", "method": method, "params": params })
+    }
+
+    fn did_open(uri: &str, text: &str) -> Value {
+        let text_document = json!({ "uri": uri, "languageId": "slice", "version": 1, "text": text });
+        notification("textDocument/didOpen", json!({ "textDocument": text_document }))
+    }
+
+    fn position_params(uri: &str, line: usize, character: usize) -> Value {
+        json!({ "textDocument": { "uri": uri }, "position": { "line": line, "character": character } })
+    }
+
+    /// Runs the language server with a scripted session: an `initialize` request, followed by the provided messages,
+    /// followed by a `shutdown` request and an `exit` notification.
+    /// Returns every message written by the server, along with the server's exit code.
+    fn run_session(messages: &[Value]) -> (Vec<Value>, i32) {
+        run_session_with(json!({ "capabilities": {} }), messages)
+    }
+
+    /// Runs the language server with a scripted session, like [run_session], but with the provided parameters for the
+    /// `initialize` request.
+    fn run_session_with(initialize_params: Value, messages: &[Value]) -> (Vec<Value>, i32) {
+        let mut script = vec![request(0, "initialize", initialize_params)];
+        script.extend_from_slice(messages);
+        script.push(request(1000, "shutdown", Value::Null));
+        script.push(notification("exit", Value::Null));
+        run_script(&script)
+    }
+
+    fn run_script(script: &[Value]) -> (Vec<Value>, i32) {
+        let mut server = Command::new(env!("CARGO_BIN_EXE_slicec-lsp"))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut stdin = server.stdin.take().unwrap();
+        for message in script {
+            let body = message.to_string();
+            write!(stdin, "Content-Length: {}\r\n\r\n{body}", body.len()).unwrap();
+        }
+        drop(stdin);
+
+        let mut output = String::new();
+        server.stdout.take().unwrap().read_to_string(&mut output).unwrap();
+        let exit_code = server.wait().unwrap().code().unwrap();
+
+        let mut messages = Vec::new();
+        let mut remaining = output.as_str();
+        while let Some((header, rest)) = remaining.split_once("\r\n\r\n") {
+            let length = header
+                .strip_prefix("Content-Length: ")
+                .unwrap()
+                .parse::<usize>()
+                .unwrap();
+            messages.push(serde_json::from_str(&rest[..length]).unwrap());
+            remaining = &rest[length..];
+        }
+        (messages, exit_code)
+    }
+
+    fn response(messages: &[Value], id: i64) -> &Value {
+        messages.iter().find(|message| message["id"] == id).unwrap()
+    }
+
+    /// Returns the diagnostics from every `publishDiagnostics` notification for the provided URI, in order.
+    fn published_diagnostics<'a>(messages: &'a [Value], uri: &str) -> Vec<&'a Value> {
+        messages
+            .iter()
+            .filter(|message| message["method"] == "textDocument/publishDiagnostics")
+            .filter(|message| message["params"]["uri"] == uri)
+            .map(|message| &message["params"]["diagnostics"])
+            .collect()
+    }
+
+    #[test]
+    fn initialize_reports_capabilities() {
+        // Act
+        let (messages, exit_code) = run_session(&[]);
+
+        // Assert
+        let capabilities = &response(&messages, 0)["result"]["capabilities"];
+        assert_eq!(capabilities["textDocumentSync"], 1);
+        assert_eq!(capabilities["hoverProvider"], true);
+        assert_eq!(capabilities["definitionProvider"], true);
+        assert_eq!(capabilities["documentSymbolProvider"], true);
+        assert_eq!(response(&messages, 1000)["result"], Value::Null);
+        assert_eq!(exit_code, 0);
+    }
+
+    #[test]
+    fn exiting_without_shutdown_fails() {
+        // Act
+        let (_, exit_code) = run_script(&[notification("exit", Value::Null)]);
+
+        // Assert
+        assert_eq!(exit_code, 1);
+    }
+
+    #[test]
+    fn unknown_requests_are_rejected() {
+        // Act
+        let (messages, _) = run_session(&[request(1, "textDocument/rename", json!({}))]);
+
+        // Assert
+        assert_eq!(response(&messages, 1)["error"]["code"], -32601);
+    }
+
+    #[test]
+    fn diagnostics_are_published_for_open_documents() {
+        // Arrange
+        let slice = "module Test\nstruct S {\n    p: Pont\n}\n";
+
+        // Act
+        let (messages, _) = run_session(&[did_open(POINT_URI, POINT_SLICE), did_open(SHAPES_URI, slice)]);
+
+        // Assert
+        let diagnostics = published_diagnostics(&messages, SHAPES_URI);
+        let diagnostic = &diagnostics[0][0];
+        assert_eq!(diagnostic["severity"], 1);
+        assert_eq!(diagnostic["code"], "E049");
+        assert_eq!(diagnostic["source"], "slicec");
+        assert_eq!(diagnostic["message"], "no element with identifier 'Pont' exists");
+        assert_eq!(
+            diagnostic["range"],
+            json!({ "start": { "line": 2, "character": 7 }, "end": { "line": 2, "character": 11 } }),
+        );
+
+        // The 'did you mean' note points into the other document.
+        let related_information = &diagnostic["relatedInformation"][0];
+        assert_eq!(related_information["message"], "did you mean 'Point'?");
+        assert_eq!(related_information["location"]["uri"], POINT_URI);
+    }
+
+    #[test]
+    fn diagnostics_are_cleared_when_fixed_or_closed() {
+        // Arrange
+        let broken = "module Test\nstruct S { p: Piont }\n";
+        let fixed = "module Test\nstruct S { p: int32 }\n";
+        let did_change = notification(
+            "textDocument/didChange",
+            json!({ "textDocument": { "uri": SHAPES_URI, "version": 2 }, "contentChanges": [{ "text": fixed }] }),
+        );
+        let did_close = notification(
+            "textDocument/didClose",
+            json!({ "textDocument": { "uri": SHAPES_URI } }),
+        );
+
+        // Act
+        let (messages, _) = run_session(&[
+            did_open(SHAPES_URI, broken),
+            did_change,
+            did_open(SHAPES_URI, broken),
+            did_close,
+        ]);
+
+        // Assert
+        let diagnostics = published_diagnostics(&messages, SHAPES_URI);
+        let counts = diagnostics
+            .iter()
+            .map(|d| d.as_array().unwrap().len())
+            .collect::<Vec<_>>();
+        assert_eq!(counts, [1, 0, 1, 0]);
+    }
+
+    #[test]
+    fn hover_shows_type_and_doc_comment() {
+        // Act
+        let (messages, _) = run_session(&[
+            did_open(POINT_URI, POINT_SLICE),
+            did_open(SHAPES_URI, SHAPES_SLICE),
+            request(1, "textDocument/hover", position_params(SHAPES_URI, 3, 21)),
+            request(2, "textDocument/hover", position_params(POINT_URI, 4, 4)),
+            request(3, "textDocument/hover", position_params(POINT_URI, 1, 0)),
+        ]);
+
+        // Assert
+        let type_ref_hover = &response(&messages, 1)["result"];
+        assert_eq!(
+            type_ref_hover["contents"]["value"],
+            "```slice\nPoint\n```\n\nA point in space.",
+        );
+        assert_eq!(
+            type_ref_hover["range"],
+            json!({ "start": { "line": 3, "character": 19 }, "end": { "line": 3, "character": 24 } }),
+        );
+
+        let field_hover = &response(&messages, 2)["result"];
+        assert_eq!(field_hover["contents"]["value"], "```slice\nfield x: int32\n```");
+
+        assert_eq!(response(&messages, 3)["result"], Value::Null);
+    }
+
+    #[test]
+    fn definition_resolves_across_documents() {
+        // Act
+        let (messages, _) = run_session(&[
+            did_open(POINT_URI, POINT_SLICE),
+            did_open(SHAPES_URI, SHAPES_SLICE),
+            request(1, "textDocument/definition", position_params(SHAPES_URI, 3, 21)),
+            request(2, "textDocument/definition", position_params(POINT_URI, 4, 8)),
+        ]);
+
+        // Assert
+        assert_eq!(
+            response(&messages, 1)["result"],
+            json!({
+                "uri": POINT_URI,
+                "range": { "start": { "line": 3, "character": 7 }, "end": { "line": 3, "character": 12 } },
+            }),
+        );
+
+        // Primitives have no definition.
+        assert_eq!(response(&messages, 2)["result"], Value::Null);
+    }
+
+    #[test]
+    fn positions_count_utf16_code_units() {
+        // Arrange
+        // 'é' is a single UTF-16 code unit, but '😀' is two, so positions after it are offset by one character.
+        let slice = "module Test\nstruct S {\n    /* é😀 */ p: Pont\n    /* é😀 */ q: Point\n}\n";
+
+        // Act
+        let (messages, _) = run_session(&[
+            did_open(POINT_URI, POINT_SLICE),
+            did_open(SHAPES_URI, slice),
+            request(1, "textDocument/hover", position_params(SHAPES_URI, 3, 17)),
+            request(2, "textDocument/hover", position_params(SHAPES_URI, 3, 16)),
+        ]);
+
+        // Assert
+        let diagnostic = &published_diagnostics(&messages, SHAPES_URI)[0][0];
+        assert_eq!(
+            diagnostic["range"],
+            json!({ "start": { "line": 2, "character": 17 }, "end": { "line": 2, "character": 21 } }),
+        );
+
+        let hover = &response(&messages, 1)["result"];
+        assert_eq!(hover["contents"]["value"], "```slice\nPoint\n```\n\nA point in space.");
+        assert_eq!(
+            hover["range"],
+            json!({ "start": { "line": 3, "character": 17 }, "end": { "line": 3, "character": 22 } }),
+        );
+
+        // The character before 'Point' is a space, so there's nothing to hover over.
+        assert_eq!(response(&messages, 2)["result"], Value::Null);
+    }
+
+    #[test]
+    fn document_symbols_list_every_definition() {
+        // Act
+        let (messages, _) = run_session(&[
+            did_open(SHAPES_URI, SHAPES_SLICE),
+            did_open(POINT_URI, POINT_SLICE),
+            request(
+                1,
+                "textDocument/documentSymbol",
+                json!({ "textDocument": { "uri": POINT_URI } }),
+            ),
+        ]);
+
+        // Assert
+        let symbols = response(&messages, 1)["result"].as_array().unwrap();
+        let summary = symbols
+            .iter()
+            .map(|s| {
+                (
+                    s["name"].as_str().unwrap(),
+                    s["kind"].as_u64().unwrap(),
+                    s["containerName"].clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                ("Test", 2, Value::Null),
+                ("Point", 23, json!("Test")),
+                ("x", 8, json!("Test::Point")),
+                ("y", 8, json!("Test::Point")),
+            ],
+        );
+        assert!(symbols.iter().all(|s| s["location"]["uri"] == POINT_URI));
+    }
+
+    #[test]
+    fn requests_are_answered_for_documents_with_errors() {
+        // Arrange
+        let slice = "module Test\n\nstruct S {\n    p: Pont\n    q: sequence<Pont>?\n}\n";
+
+        // Act
+        let (messages, _) = run_session(&[
+            did_open(SHAPES_URI, slice),
+            request(1, "textDocument/hover", position_params(SHAPES_URI, 3, 4)),
+            request(2, "textDocument/hover", position_params(SHAPES_URI, 4, 4)),
+            request(3, "textDocument/hover", position_params(SHAPES_URI, 3, 8)),
+            request(4, "textDocument/definition", position_params(SHAPES_URI, 2, 7)),
+            request(
+                5,
+                "textDocument/documentSymbol",
+                json!({ "textDocument": { "uri": SHAPES_URI } }),
+            ),
+        ]);
+
+        // Assert
+        assert_eq!(published_diagnostics(&messages, SHAPES_URI)[0][0]["code"], "E049");
+
+        // Unresolved types are described by the identifiers they were written with.
+        let field_hover = &response(&messages, 1)["result"];
+        assert_eq!(field_hover["contents"]["value"], "```slice\nfield p: Pont\n```");
+        let sequence_hover = &response(&messages, 2)["result"];
+        assert_eq!(
+            sequence_hover["contents"]["value"],
+            "```slice\nfield q: sequence<Pont>?\n```",
+        );
+
+        // Unresolved type references have nothing to show.
+        assert_eq!(response(&messages, 3)["result"], Value::Null);
+
+        assert_eq!(
+            response(&messages, 4)["result"]["range"],
+            json!({ "start": { "line": 2, "character": 7 }, "end": { "line": 2, "character": 8 } }),
+        );
+        let symbols = response(&messages, 5)["result"].as_array().unwrap();
+        let names = symbols.iter().map(|s| s["name"].as_str().unwrap()).collect::<Vec<_>>();
+        assert_eq!(names, ["Test", "S", "p", "q"]);
+    }
+
+    #[test]
+    fn definition_resolves_into_unopened_workspace_files() {
+        // Arrange
+        let workspace = std::env::temp_dir().join(format!("slicec-lsp-workspace-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&workspace);
+        fs::create_dir_all(workspace.join("slice")).unwrap();
+        fs::write(workspace.join("slice").join("point.slice"), POINT_SLICE).unwrap();
+        let root_uri = format!("file://{}", workspace.display());
+
+        // Act
+        let (messages, _) = run_session_with(
+            json!({ "capabilities": {}, "rootUri": root_uri }),
+            &[
+                did_open(SHAPES_URI, SHAPES_SLICE),
+                request(1, "textDocument/definition", position_params(SHAPES_URI, 3, 21)),
+            ],
+        );
+
+        // Assert
+        assert_eq!(
+            response(&messages, 1)["result"],
+            json!({
+                "uri": format!("{root_uri}/slice/point.slice"),
+                "range": { "start": { "line": 3, "character": 7 }, "end": { "line": 3, "character": 12 } },
+            }),
+        );
+
+        // Diagnostics are only published for open documents.
+        let diagnostics = published_diagnostics(&messages, SHAPES_URI);
+        assert_eq!(diagnostics, [&json!([])]);
+        let published_uris = messages
+            .iter()
+            .filter(|message| message["method"] == "textDocument/publishDiagnostics")
+            .map(|message| message["params"]["uri"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(published_uris, [SHAPES_URI]);
+
+        fs::remove_dir_all(&workspace).unwrap();
+    }
+
+    #[test]
+    fn workspace_files_are_only_reread_when_reported_as_changed() {
+        // Arrange
+        let workspace = std::env::temp_dir().join(format!("slicec-lsp-watched-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&workspace);
+        fs::create_dir_all(&workspace).unwrap();
+        let point_path = workspace.join("point.slice");
+        fs::write(&point_path, POINT_SLICE).unwrap();
+        let point_uri = format!("file://{}", point_path.display());
+        let did_change_watched_file = |change_type: u8| {
+            let changes = json!([{ "uri": point_uri, "type": change_type }]);
+            notification("workspace/didChangeWatchedFiles", json!({ "changes": changes }))
+        };
+
+        // Act
+        // The file is never actually deleted, so the server can only drop its definitions if it uses the reported
+        // changes instead of re-reading the workspace from disk.
+        let (messages, _) = run_session_with(
+            json!({ "capabilities": {}, "rootUri": format!("file://{}", workspace.display()) }),
+            &[
+                did_open(SHAPES_URI, SHAPES_SLICE),
+                did_change_watched_file(3),
+                did_change_watched_file(1),
+            ],
+        );
+
+        // Assert
+        let counts = published_diagnostics(&messages, SHAPES_URI)
+            .iter()
+            .map(|d| d.as_array().unwrap().len())
+            .collect::<Vec<_>>();
+        assert_eq!(counts, [0, 1, 0]);
+
+        fs::remove_dir_all(&workspace).unwrap();
+    }
+
+    #[test_case(true, 1; "supported")]
+    #[test_case(false, 0; "unsupported")]
+    fn file_watchers_are_registered_if_supported(dynamic_registration: bool, expected: usize) {
+        // Arrange
+        let watched_files = json!({ "didChangeWatchedFiles": { "dynamicRegistration": dynamic_registration } });
+        let capabilities = json!({ "workspace": watched_files });
+
+        // Act
+        let (messages, _) = run_session_with(
+            json!({ "capabilities": capabilities }),
+            &[notification("initialized", json!({}))],
+        );
+
+        // Assert
+        let registrations = messages
+            .iter()
+            .filter(|message| message["method"] == "client/registerCapability")
+            .collect::<Vec<_>>();
+        assert_eq!(registrations.len(), expected);
+        if let Some(registration) = registrations.first() {
+            let registration = &registration["params"]["registrations"][0];
+            assert_eq!(registration["method"], "workspace/didChangeWatchedFiles");
+            assert_eq!(
+                registration["registerOptions"]["watchers"][0]["globPattern"],
+                "**/*.slice"
+            );
+        }
+    }
+}
@@ -6,6 +6,7 @@ mod module {
     use crate::test_helpers::*;
     use slicec::diagnostics::{Diagnostic, Error};
     use slicec::grammar::*;
+    use slicec::slice_file::Span;
 
     #[test]
     fn can_be_defined() {
@@ -50,6 +51,124 @@ mod module {
         check_diagnostics(diagnostics, [expected]);
     }
 
+    #[test]
+    fn definitions_must_be_inside_a_module() {
+        // Arrange
+        let slice = "
+            custom C
+            module Test
+            custom D
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::Syntax {
+            message: "module declaration is required".to_owned(),
+        })
+        .set_span(&Span::new((2, 13).into(), (2, 21).into(), "string-0"));
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn files_can_contain_multiple_modules() {
+        // Arrange
+        let slice = "
+            module A
+            struct S {}
+
+            module B
+            struct S {}
+            struct T {
+                s: A::S
+            }
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        assert!(ast.find_element::<Struct>("A::S").is_ok());
+        assert!(ast.find_element::<Struct>("B::S").is_ok());
+        assert!(ast.find_element::<Struct>("A::T").is_err());
+        let field = ast.find_element::<Field>("B::T::s").unwrap();
+        let Types::Struct(struct_def) = field.data_type().concrete_type() else { panic!() };
+        assert_eq!(struct_def.parser_scoped_identifier(), "A::S");
+    }
+
+    #[test]
+    fn can_use_block_syntax() {
+        // Arrange
+        let slice = "
+            module A {
+                struct S {}
+
+                module B::C {
+                    struct S {}
+                }
+
+                struct T {}
+            }
+
+            module D {}
+        ";
+
+        // Act
+        let compilation_state = parse(slice, None);
+
+        // Assert
+        let ast = &compilation_state.ast;
+        assert!(ast.find_element::<Module>("A").is_ok());
+        assert!(ast.find_element::<Module>("A::B::C").is_ok());
+        assert!(ast.find_element::<Module>("D").is_ok());
+        assert!(ast.find_element::<Struct>("A::S").is_ok());
+        assert!(ast.find_element::<Struct>("A::B::C::S").is_ok());
+        assert!(ast.find_element::<Struct>("A::T").is_ok());
+
+        let file = compilation_state.files.get("string-0").unwrap();
+        let modules = file.modules.iter().map(|m| m.borrow().nested_module_identifier());
+        assert_eq!(modules.collect::<Vec<_>>(), ["A", "A::B::C", "D"]);
+    }
+
+    #[test]
+    fn block_modules_can_be_nested_in_file_level_modules() {
+        // Arrange
+        let slice = "
+            module A
+            module B {
+                struct S {}
+            }
+            struct T {}
+        ";
+
+        // Act
+        let ast = parse_for_ast(slice);
+
+        // Assert
+        assert!(ast.find_element::<Struct>("A::B::S").is_ok());
+        assert!(ast.find_element::<Struct>("A::T").is_ok());
+    }
+
+    #[test]
+    fn file_level_modules_cannot_be_nested_in_block_modules() {
+        // Arrange
+        let slice = "
+            module A {
+                module B
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::Syntax {
+            message: "expected one of '{', but found '}'".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
     #[test]
     fn multiple_files_can_use_the_same_module() {
         // Arrange
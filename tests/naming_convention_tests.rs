@@ -0,0 +1,138 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+mod naming_convention {
+
+    use crate::test_helpers::*;
+    use slicec::diagnostics::{Diagnostic, Lint};
+    use slicec::slice_file::Span;
+    use test_case::test_case;
+
+    #[test]
+    fn naming_convention_is_allowed_by_default() {
+        // Arrange
+        let slice = "
+            module my_module
+            struct my_struct {}
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn conventional_identifiers_are_accepted() {
+        // Arrange
+        let slice = "
+            module Outer::Inner
+            struct MyStruct {
+                myField: int32
+            }
+            enum MyEnum : uint8 { FirstValue, SecondValue }
+            interface MyInterface {
+                getUser(userId: int32) -> (firstName: string, lastName: string)
+            }
+            exception MyException {}
+            custom MyCustom
+            typealias MyAlias = int32
+        ";
+
+        // Act
+        let diagnostics = parse_with_warned_lint(slice, "NamingConvention");
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test_case("struct UUID {}"; "all caps acronym")]
+    #[test_case("exception IOError {}"; "leading acronym")]
+    #[test_case("interface HTTPServer {}"; "acronym followed by word")]
+    #[test_case("struct Vector3d {}"; "digit followed by lowercase")]
+    #[test_case("interface I { getURL() }"; "camelCase with trailing acronym")]
+    #[test_case("struct S { userID: int32 }"; "field with trailing acronym")]
+    fn identifiers_with_acronyms_are_accepted(definition: &str) {
+        // Arrange
+        let slice = format!("module Test\n{definition}");
+
+        // Act
+        let diagnostics = parse_with_warned_lint(&slice, "NamingConvention");
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test_case("struct my_struct {}", "struct", "my_struct", "PascalCase", "MyStruct"; "struct")]
+    #[test_case("interface myInterface {}", "interface", "myInterface", "PascalCase", "MyInterface"; "interface")]
+    #[test_case("enum E : uint8 { first_value }", "enumerator", "first_value", "PascalCase", "FirstValue"; "enumerator")]
+    #[test_case("interface I { GetUser() }", "operation", "GetUser", "camelCase", "getUser"; "operation")]
+    #[test_case("interface I { op(user_id: int32) }", "parameter", "user_id", "camelCase", "userId"; "parameter")]
+    #[test_case("struct S { MyField: int32 }", "field", "MyField", "camelCase", "myField"; "field")]
+    #[test_case("typealias my_alias = int32", "type alias", "my_alias", "PascalCase", "MyAlias"; "type alias")]
+    fn unconventional_identifiers_are_reported(definition: &str, kind: &str, identifier: &str, case: &str, fix: &str) {
+        // Arrange
+        let slice = format!(
+            "
+            module Test
+            {definition}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_with_warned_lint(&slice, "NamingConvention");
+
+        // Assert
+        assert_eq!(diagnostics[0].suggestions()[0].replacement, fix);
+        let expected = Diagnostic::new(Lint::NamingConvention {
+            kind: kind.to_owned(),
+            identifier: identifier.to_owned(),
+            case: case.to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn nested_modules_are_checked_segment_by_segment() {
+        // Arrange
+        let slice = "module Outer::inner_module";
+
+        // Act
+        let diagnostics = parse_with_warned_lint(slice, "NamingConvention");
+
+        // Assert
+        let span = Span::new((1, 8).into(), (1, 27).into(), "string-0");
+        let expected = Diagnostic::new(Lint::NamingConvention {
+            kind: "module".to_owned(),
+            identifier: "Outer::inner_module".to_owned(),
+            case: "PascalCase".to_owned(),
+        })
+        .set_span(&span)
+        .add_suggestion("rename to 'Outer::InnerModule'", &span, "Outer::InnerModule");
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn naming_convention_can_be_allowed_by_attributes() {
+        // Arrange
+        let slice = "
+            module Test
+            interface I {
+                [allow(NamingConvention)]
+                GetUser()
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_with_warned_lint(slice, "NamingConvention");
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+}
@@ -0,0 +1,243 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+mod parse_cache {
+
+    use slicec::ast_json::ast_to_json;
+    use slicec::compilation_state::CompilationState;
+    use slicec::compile_from_options;
+    use slicec::slice_options::SliceOptions;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::time::Instant;
+    use test_case::test_case;
+
+    /// Creates an empty directory for a test to write Slice files and the parse cache into.
+    fn create_test_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("slicec-parse-cache-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(directory.join("slice")).unwrap();
+        directory
+    }
+
+    /// Writes a Slice file which uses most of the language, so that rehydrating it exercises every kind of element.
+    fn write_slice_file(directory: &Path, index: usize) -> String {
+        let slice = format!(
+            "
+            [[allow(Unused)]]
+            module Bench{index}
+            /// A point with a {{@link Shape}}, and an `inline` **emphasis**.
+            /// - first item
+            /// - second item
+            /// @see Shapes
+            [cs::attribute(\"Serializable\", [1, 2], level = 3)]
+            struct Point {{
+                x: int32
+                y: int32 = -{index}
+                tag(1) label: string?
+            }}
+            [flags]
+            enum Flags : uint8 {{ A, B, C = 8, D }}
+            enum Shape {{
+                Circle(radius: float64)
+                Rectangle(width: float64, tag(2) height: float64?)
+            }}
+            exception Oops {{ message: string }}
+            typealias Points = sequence<Point>
+            const Limit: float64 = 2.5
+            custom Timestamp
+            /// Manages shapes.
+            interface Shapes {{
+                /// Adds some shapes.
+                /// @param shapes: The shapes to add.
+                /// @returns: How many shapes were added.
+                /// @throws Oops: If the shapes are invalid.
+                idempotent add(shapes: Points, [cs::type(\"Dictionary\")] names: dictionary<string, Shape?>) -> int32 throws Oops
+                streamPoints() -> (first: Point, rest: stream Point)
+            }}
+            module Nested {{
+                compact struct Holder {{ points: ::Bench{index}::Points }}
+            }}
+            "
+        );
+
+        let path = directory.join("slice").join(format!("bench{index}.slice"));
+        fs::write(&path, slice).unwrap();
+        path.display().to_string()
+    }
+
+    fn compile(sources: &[String], cache: &Path, defined_symbols: &[&str]) -> CompilationState {
+        let options = SliceOptions {
+            sources: sources.to_vec(),
+            defined_symbols: defined_symbols.iter().map(|s| s.to_string()).collect(),
+            parse_cache: Some(cache.display().to_string()),
+            ..Default::default()
+        };
+        compile_from_options(&options, |_| {}, |_| {})
+    }
+
+    #[test]
+    fn second_compilation_loads_unchanged_files_from_the_cache() {
+        // Arrange
+        let directory = create_test_directory("benchmark");
+        let cache = directory.join("cache");
+        let mut sources = (0..100).map(|i| write_slice_file(&directory, i)).collect::<Vec<_>>();
+        let slice1_path = directory.join("slice").join("slice1.slice");
+        let slice1 = "
+            mode = Slice1
+            module Legacy {
+                class Base {}
+                class Derived(3) : Base { next: Base?, tag(1) count: int32? }
+                exception Error {}
+                interface Legacy { op(b: Base?) throws AnyException }
+            }
+        ";
+        fs::write(&slice1_path, slice1).unwrap();
+        sources.push(slice1_path.display().to_string());
+
+        // Act
+        let start = Instant::now();
+        let first_state = compile(&sources, &cache, &[]);
+        let first_duration = start.elapsed();
+
+        let start = Instant::now();
+        let second_state = compile(&sources, &cache, &[]);
+        let second_duration = start.elapsed();
+
+        // Assert
+        println!("first compilation (cold cache): {first_duration:?}");
+        println!("second compilation (warm cache): {second_duration:?}");
+        assert!(!first_state.diagnostic_reporter.has_errors());
+        assert!(!second_state.diagnostic_reporter.has_errors());
+        assert_eq!(first_state.parsed_file_count, 101);
+        assert_eq!(second_state.parsed_file_count, 0);
+
+        // The rehydrated AST must be identical to the parsed one.
+        assert_eq!(ast_to_json(&first_state), ast_to_json(&second_state));
+        assert_eq!(first_state.ast.as_slice().len(), second_state.ast.as_slice().len());
+        assert_eq!(first_state.referenced_definitions, second_state.referenced_definitions);
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn changed_files_are_parsed_again() {
+        // Arrange
+        let directory = create_test_directory("changed");
+        let cache = directory.join("cache");
+        let sources = (0..3).map(|i| write_slice_file(&directory, i)).collect::<Vec<_>>();
+        let _ = compile(&sources, &cache, &[]);
+
+        let mut contents = fs::read_to_string(&sources[1]).unwrap();
+        contents.push_str("struct Added {}\n");
+        fs::write(&sources[1], contents).unwrap();
+
+        // Act
+        let state = compile(&sources, &cache, &[]);
+
+        // Assert
+        assert!(!state.diagnostic_reporter.has_errors());
+        assert_eq!(state.parsed_file_count, 1);
+        assert!(state
+            .ast
+            .find_element::<slicec::grammar::Struct>("Bench1::Added")
+            .is_ok());
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn files_are_parsed_again_when_defined_symbols_change() {
+        // Arrange
+        let directory = create_test_directory("symbols");
+        let cache = directory.join("cache");
+        let sources = (0..3).map(|i| write_slice_file(&directory, i)).collect::<Vec<_>>();
+        let _ = compile(&sources, &cache, &["FOO"]);
+
+        // Act
+        let same_symbols = compile(&sources, &cache, &["FOO"]).parsed_file_count;
+        let different_symbols = compile(&sources, &cache, &["FOO=1"]).parsed_file_count;
+
+        // Assert
+        assert_eq!(same_symbols, 0);
+        assert_eq!(different_symbols, 3);
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn cache_is_invalidated_when_the_slicec_version_changes() {
+        // Arrange
+        let directory = create_test_directory("version");
+        let cache = directory.join("cache");
+        let sources = (0..3).map(|i| write_slice_file(&directory, i)).collect::<Vec<_>>();
+        let _ = compile(&sources, &cache, &[]);
+        fs::write(cache.join("slicec-version"), "0.0.0-old").unwrap();
+
+        // Act
+        let state = compile(&sources, &cache, &[]);
+
+        // Assert
+        assert_eq!(state.parsed_file_count, 3);
+        let version = fs::read_to_string(cache.join("slicec-version")).unwrap();
+        assert!(version.starts_with(concat!(env!("CARGO_PKG_VERSION"), "+format")));
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test_case("module", "\"module\":0", "\"module\":7"; "module index out of bounds")]
+    #[test_case("primitive", "{\"Primitive\":\"int32\"}", "{\"Primitive\":\"Bench0::Point\"}"; "unknown primitive")]
+    #[test_case("base", "\"base\":null", "\"base\":{\"definition\":{\"Primitive\":\"int32\"},\"is_optional\":false,\"scope\":{\"parser_scope\":\"\",\"module\":null},\"attributes\":[],\"span\":{\"start\":{\"row\":1,\"col\":1},\"end\":{\"row\":1,\"col\":1},\"file\":\"\"}}"; "primitive base type")]
+    #[test_case("key", "\"source_length\":", "\"source_length\":1"; "mismatched key")]
+    fn inconsistent_entries_are_parsed_again(name: &str, original: &str, replacement: &str) {
+        // Arrange
+        let directory = create_test_directory(&format!("inconsistent-{name}"));
+        let cache = directory.join("cache");
+        let sources = vec![write_slice_file(&directory, 0)];
+        let expected = ast_to_json(&compile(&sources, &cache, &[]));
+
+        let entry_paths = fs::read_dir(&cache).unwrap().map(|entry| entry.unwrap().path());
+        let entry_path = entry_paths
+            .into_iter()
+            .find(|path| path.extension().is_some_and(|extension| extension == "json"))
+            .unwrap();
+        let entry = fs::read_to_string(&entry_path).unwrap();
+        assert!(entry.contains(original));
+        fs::write(&entry_path, entry.replacen(original, replacement, 1)).unwrap();
+
+        // Act
+        let state = compile(&sources, &cache, &[]);
+
+        // Assert
+        assert!(!state.diagnostic_reporter.has_errors());
+        assert_eq!(state.parsed_file_count, 1);
+        assert_eq!(ast_to_json(&state), expected);
+
+        // The inconsistent entry was replaced, so the next compilation can use it.
+        assert_eq!(compile(&sources, &cache, &[]).parsed_file_count, 0);
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn files_with_diagnostics_are_not_cached() {
+        // Arrange
+        let directory = create_test_directory("diagnostics");
+        let cache = directory.join("cache");
+        let path = directory.join("slice").join("invalid.slice");
+        fs::write(&path, "module Test\nstruct S { tag(-1) x: int32? }").unwrap();
+        let sources = vec![path.display().to_string()];
+
+        // Act
+        let first_state = compile(&sources, &cache, &[]);
+        let second_state = compile(&sources, &cache, &[]);
+
+        // Assert
+        assert!(first_state.diagnostic_reporter.has_errors());
+        assert!(second_state.diagnostic_reporter.has_errors());
+        assert_eq!(second_state.parsed_file_count, 1);
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -3,8 +3,10 @@
 pub mod test_helpers;
 
 use crate::test_helpers::*;
-use slicec::diagnostics::{Diagnostic, Error};
+use slicec::compile_from_options_with_symbols;
+use slicec::diagnostics::{Diagnostic, DiagnosticKind, Error, Lint};
 use slicec::grammar::*;
+use slicec::slice_file::Span;
 use slicec::slice_options::SliceOptions;
 use test_case::test_case;
 
@@ -366,7 +368,7 @@ fn preprocessor_recovers_at_end_of_line() {
     // Assert
     let expected = [
         Diagnostic::new(Error::Syntax {
-            message: "expected one of directive_end, but found 'Identifier(\"Bar\")'".to_owned(),
+            message: "expected one of directive_end or integer_literal, but found 'Identifier(\"Bar\")'".to_owned(),
         }),
         Diagnostic::new(Error::Syntax {
             message: r#"expected one of "&&", ")", or "||", but found 'DirectiveEnd'"#.to_owned(),
@@ -374,3 +376,380 @@ fn preprocessor_recovers_at_end_of_line() {
     ];
     check_diagnostics(diagnostics, expected);
 }
+
+#[test]
+fn error_directives_report_errors() {
+    // Arrange
+    let slice = "
+        #error \"this is an error\"
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::ErrorDirective {
+        message: "this is an error".to_owned(),
+    })
+    .set_span(&Span::new((2, 9).into(), (2, 34).into(), "string-0"));
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn warning_directives_report_lints() {
+    // Arrange
+    let slice = "
+        #warning \"this is a \\\"warning\\\"\"
+        module Test
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Lint::WarningDirective {
+        message: "this is a \"warning\"".to_owned(),
+    })
+    .set_span(&Span::new((2, 9).into(), (2, 41).into(), "string-0"));
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn diagnostic_directives_are_only_evaluated_in_active_branches() {
+    // Arrange
+    let slice = "
+        #define Foo
+        #if Foo
+            #warning \"active\"
+        #elif Bar
+            #error \"inactive elif\"
+        #else
+            #if Foo
+                #error \"nested inactive\"
+            #endif
+        #endif
+        module Test
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Lint::WarningDirective {
+        message: "active".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test_case("#error"; "missing message")]
+#[test_case("#warning foo"; "identifier message")]
+fn diagnostic_directives_require_string_literals(directive: &str) {
+    // Arrange
+    let slice = format!("{directive}\nmodule Test");
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    assert_eq!(diagnostics.len(), 1);
+    assert!(matches!(
+        &diagnostics[0].kind,
+        DiagnosticKind::Error(Error::Syntax { .. })
+    ));
+}
+
+#[test]
+fn unterminated_string_literals_are_rejected() {
+    // Arrange
+    let slice = "
+        #error \"missing quote
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::Syntax {
+        message: "unterminated string literal".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test_case("VERSION == 3", true; "equal")]
+#[test_case("VERSION != 3", false; "not equal")]
+#[test_case("VERSION < 3", false; "less than")]
+#[test_case("VERSION <= 3", true; "less than or equal")]
+#[test_case("VERSION > -1", true; "greater than")]
+#[test_case("VERSION >= 4", false; "greater than or equal")]
+#[test_case("VERSION == 3 && !Foo", true; "combined with boolean operators")]
+#[test_case("(2 < VERSION) == (VERSION < 4)", false; "comparing booleans is a type error")]
+fn preprocessor_comparison_expressions(expression: &str, expected: bool) {
+    // Arrange
+    let slice = format!(
+        "
+        #define VERSION 3
+        #if {expression}
+        module Test
+        interface I {{}}
+        #endif
+        "
+    );
+
+    // Act
+    let compilation_state = parse(slice, None);
+
+    // Assert
+    let ast = &compilation_state.ast;
+    assert_eq!(ast.find_element::<Interface>("Test::I").is_ok(), expected);
+}
+
+#[test]
+fn command_line_defined_symbols_can_have_values() {
+    // Arrange
+    let slice = "
+        #if VERSION >= 2 && Foo
+        module Test
+        interface I {}
+        #endif
+    ";
+
+    let options = SliceOptions {
+        defined_symbols: vec!["VERSION=2".to_owned(), "Foo".to_owned()],
+        ..Default::default()
+    };
+
+    // Act
+    let compilation_state = parse(slice, Some(options));
+
+    // Assert
+    assert!(compilation_state.ast.find_element::<Interface>("Test::I").is_ok());
+}
+
+#[test]
+fn command_line_defined_symbols_must_have_integer_values() {
+    // Arrange
+    let options = SliceOptions {
+        defined_symbols: vec!["VERSION=two".to_owned()],
+        ..Default::default()
+    };
+
+    // Act
+    let diagnostics = diagnostics_from_compilation_state(parse("module Test", Some(options)));
+
+    // Assert
+    let expected = Diagnostic::new(Error::InvalidSymbolDefinition {
+        definition: "VERSION=two".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn bare_symbols_with_values_evaluate_to_true() {
+    // Arrange
+    let slice = "
+        #define VERSION 0
+        #if VERSION
+        module Test
+        interface I {}
+        #endif
+    ";
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    assert!(ast.find_element::<Interface>("Test::I").is_ok());
+}
+
+#[test_case("#if 1 && Foo", "boolean", "integer", (2, 13), (2, 14); "integer as boolean")]
+#[test_case("#if !5", "boolean", "integer", (2, 14), (2, 15); "negated integer")]
+#[test_case("#if (Foo || Bar) < 5", "integer", "boolean", (2, 13), (2, 25); "boolean as integer")]
+fn preprocessor_type_mismatches_are_reported(
+    directive: &str,
+    expected: &str,
+    actual: &str,
+    start: (usize, usize),
+    end: (usize, usize),
+) {
+    // Arrange
+    let slice = format!(
+        "
+        {directive}
+        #endif
+        "
+    );
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::PreprocessorTypeMismatch {
+        expected: expected.to_owned(),
+        actual: actual.to_owned(),
+    })
+    .set_span(&Span::new(start.into(), end.into(), "string-0"));
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn symbols_without_values_cannot_be_compared() {
+    // Arrange
+    let slice = "
+        #define Foo
+        #if Foo > 1
+        #endif
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::SymbolHasNoValue {
+        symbol: "Foo".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test_case("API_LEVEL >= 3", false; "greater than or equal")]
+#[test_case("API_LEVEL < 3", true; "less than")]
+#[test_case("API_LEVEL == 0", true; "equal to zero")]
+#[test_case("API_LEVEL && API_LEVEL >= 3", false; "guarded by defined check")]
+#[test_case("!API_LEVEL || API_LEVEL >= 3", true; "guarded by undefined check")]
+fn undefined_symbols_compare_as_zero(expression: &str, expected: bool) {
+    // Arrange
+    let slice = format!(
+        "
+        #if {expression}
+        module Test
+        interface I {{}}
+        #endif
+        "
+    );
+
+    // Act
+    let compilation_state = parse(slice, None);
+
+    // Assert
+    assert!(!compilation_state.diagnostic_reporter.has_errors());
+    let ast = &compilation_state.ast;
+    assert_eq!(ast.find_element::<Interface>("Test::I").is_ok(), expected);
+}
+
+#[test_case("#if Foo && Bar > 1"; "and")]
+#[test_case("#if !Foo || Bar > 1"; "or")]
+#[test_case("#if Foo && (1 || Bar > 1)"; "nested")]
+fn right_hand_sides_are_only_evaluated_when_needed(directive: &str) {
+    // Arrange
+    let slice = format!(
+        "
+        #define Bar
+        {directive}
+        #endif
+        "
+    );
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected: [Diagnostic; 0] = [];
+    check_diagnostics(diagnostics, expected);
+}
+
+#[test_case("#if Foo\n#if Bar > 1\n#endif\n#endif"; "nested in inactive if")]
+#[test_case("#if Bar\n#else\n#if 1\n#endif\n#endif"; "nested in inactive else")]
+#[test_case("#if Bar\n#elif Bar > 1\n#endif"; "elif after active if")]
+#[test_case("#if Foo\n#elif Bar\n#elif Bar < 1\n#endif"; "elif after active elif")]
+fn expressions_in_inactive_branches_do_not_report_diagnostics(directives: &str) {
+    // Arrange
+    let slice = format!(
+        "
+        #define Bar
+        {directives}
+        "
+    );
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected: [Diagnostic; 0] = [];
+    check_diagnostics(diagnostics, expected);
+}
+
+#[test]
+fn out_of_range_integer_literals_are_rejected() {
+    // Arrange
+    let slice = "
+        #define Foo 99999999999999999999
+    ";
+
+    // Act
+    let diagnostics = parse_for_diagnostics(slice);
+
+    // Assert
+    let expected = Diagnostic::new(Error::Syntax {
+        message: "integer literal '99999999999999999999' is out of range".to_owned(),
+    });
+    check_diagnostics(diagnostics, [expected]);
+}
+
+#[test]
+fn compiler_version_symbols_are_predefined() {
+    // Arrange
+    let slice = format!(
+        "
+        #if SLICEC_VERSION_MAJOR == {} && SLICEC_VERSION_MINOR == {} && SLICEC_VERSION_PATCH == {}
+        module Test
+        interface I {{}}
+        #endif
+        ",
+        env!("CARGO_PKG_VERSION_MAJOR"),
+        env!("CARGO_PKG_VERSION_MINOR"),
+        env!("CARGO_PKG_VERSION_PATCH"),
+    );
+
+    // Act
+    let ast = parse_for_ast(slice);
+
+    // Assert
+    assert!(ast.find_element::<Interface>("Test::I").is_ok());
+}
+
+#[test]
+fn language_compilers_can_predefine_symbols() {
+    // Arrange
+    let options = SliceOptions {
+        sources: vec!["tests/files/predefined_symbols.slice".to_owned()],
+        ..Default::default()
+    };
+
+    // Act
+    let state = compile_from_options_with_symbols(&options, &[("SLICEC_CS", None)], |_| {}, |_| {});
+
+    // Assert
+    assert!(state.ast.find_element::<Interface>("Test::CSharpOnly").is_ok());
+    assert!(state.ast.find_element::<Interface>("Test::RustOnly").is_err());
+}
+
+#[test]
+fn user_defined_symbols_take_precedence_over_predefined_symbols() {
+    // Arrange
+    let slice = "
+        #if SLICEC_VERSION_MAJOR == 99
+        module Test
+        interface I {}
+        #endif
+    ";
+
+    let options = SliceOptions {
+        defined_symbols: vec!["SLICEC_VERSION_MAJOR=99".to_owned()],
+        ..Default::default()
+    };
+
+    // Act
+    let compilation_state = parse(slice, Some(options));
+
+    // Assert
+    assert!(compilation_state.ast.find_element::<Interface>("Test::I").is_ok());
+}
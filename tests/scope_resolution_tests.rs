@@ -7,6 +7,7 @@ mod scope_resolution {
     use crate::test_helpers::*;
     use slicec::diagnostics::{Diagnostic, Error};
     use slicec::grammar::*;
+    use slicec::slice_file::Span;
 
     #[test]
     fn identifier_exists_in_module_and_submodule() {
@@ -206,4 +207,94 @@ mod scope_resolution {
         });
         check_diagnostics(diagnostics, [expected]);
     }
+
+    #[test]
+    fn missing_types_suggest_similarly_named_types() {
+        // Arrange
+        let slice = "
+            module A
+            struct Point {}
+            struct C {
+                p: Pont
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let span = Span::new((3, 13).into(), (3, 25).into(), "string-0");
+        let expected = Diagnostic::new(Error::DoesNotExist {
+            identifier: "Pont".to_string(),
+        })
+        .add_note("did you mean 'Point'?", Some(&span));
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn missing_types_suggest_types_from_other_modules() {
+        // Arrange
+        let slice1 = "
+            module A
+            struct C {
+                p: Point
+            }
+        ";
+        let slice2 = "
+            module B
+            struct Point {}
+        ";
+
+        // Act
+        let diagnostics = parse_multiple_for_diagnostics(&[slice1, slice2]);
+
+        // Assert
+        let expected = Diagnostic::new(Error::DoesNotExist {
+            identifier: "Point".to_string(),
+        })
+        .add_note("did you mean 'B::Point'?", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn missing_types_only_suggest_compatible_elements() {
+        // Arrange
+        let slice = "
+            module A
+            interface I {
+                send()
+            }
+            struct Sends {}
+            struct C {
+                s: Send
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected = Diagnostic::new(Error::DoesNotExist {
+            identifier: "Send".to_string(),
+        })
+        .add_note("did you mean 'Sends'?", None);
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn missing_types_without_similar_types_have_no_notes() {
+        // Arrange
+        let slice = "
+            module A
+            struct C {
+                b: Unrelated
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        assert!(diagnostics[0].notes().is_empty());
+    }
 }
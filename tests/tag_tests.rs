@@ -7,6 +7,7 @@ mod tags {
     use crate::test_helpers::*;
     use slicec::diagnostics::{Diagnostic, Error};
     use slicec::grammar::*;
+    use slicec::slice_file::Span;
     use test_case::test_case;
 
     #[test]
@@ -26,9 +27,11 @@ mod tags {
         let diagnostics = parse_for_diagnostics(slice);
 
         // Assert
+        let span = Span::new((7, 32).into(), (7, 32).into(), "string-0");
         let expected = Diagnostic::new(Error::TaggedMemberMustBeOptional {
             identifier: "b".to_owned(),
-        });
+        })
+        .add_suggestion("make the type optional", &span, "?");
         check_diagnostics(diagnostics, [expected]);
     }
 
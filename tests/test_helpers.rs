@@ -58,6 +58,36 @@ pub fn parse_multiple_for_diagnostics(slice: &[&str]) -> Vec<Diagnostic> {
     diagnostics_from_compilation_state(compile_from_strings(slice, None, |_| {}, |_| {}))
 }
 
+/// This function parses the provided Slice file with the provided lint set to the warning level, and returns any
+/// Diagnostics that were emitted during parsing.
+#[must_use]
+pub fn parse_with_warned_lint(slice: impl Into<String>, lint: &str) -> Vec<Diagnostic> {
+    parse_multiple_with_warned_lint(&[&slice.into()], lint)
+}
+
+/// This function parses the provided Slice files with the provided lint set to the warning level, and returns any
+/// Diagnostics that were emitted during parsing.
+/// Like in a real compilation, the first file is treated as a source file, and any others are treated as reference files.
+#[must_use]
+pub fn parse_multiple_with_warned_lint(slice: &[&str], lint: &str) -> Vec<Diagnostic> {
+    // `compile_from_strings` treats every file as a reference file, so we mark the first file as a source file
+    // before validation runs.
+    unsafe fn mark_first_file_as_source(compilation_state: &mut CompilationState) {
+        compilation_state.files.get_mut("string-0").unwrap().is_source = true;
+    }
+
+    let options = SliceOptions {
+        warned_lints: vec![lint.to_owned()],
+        ..Default::default()
+    };
+    diagnostics_from_compilation_state(compile_from_strings(
+        slice,
+        Some(options),
+        mark_first_file_as_source,
+        |_| {},
+    ))
+}
+
 /// Asserts that the provided slice parses okay, producing no errors.
 pub fn assert_parses(slice: impl Into<String>) {
     let diagnostics = parse_for_diagnostics(slice);
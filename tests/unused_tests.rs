@@ -0,0 +1,204 @@
+// Copyright (c) ZeroC, Inc.
+
+mod test_helpers;
+
+mod unused {
+
+    use crate::test_helpers::*;
+    use slicec::diagnostics::{Diagnostic, Lint};
+    use slicec::slice_file::Span;
+    use test_case::test_case;
+
+    #[test]
+    fn unused_is_allowed_by_default() {
+        // Arrange
+        let slice = "
+            module Test
+            struct S {}
+        ";
+
+        // Act
+        let diagnostics = parse_for_diagnostics(slice);
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test_case("Slice2", "struct S {}", "struct"; "struct")]
+    #[test_case("Slice1", "class S {}", "class"; "class")]
+    #[test_case("Slice2", "exception S {}", "exception"; "exception")]
+    #[test_case("Slice2", "enum S : uint8 { A }", "enum"; "enum")]
+    #[test_case("Slice2", "custom S", "custom type"; "custom type")]
+    #[test_case("Slice2", "typealias S = int32", "type alias"; "type alias")]
+    fn unreferenced_definitions_are_reported(mode: &str, definition: &str, kind: &str) {
+        // Arrange
+        let slice = format!(
+            "
+            mode = {mode}
+            module Test
+            {definition}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_with_warned_lint(slice, "Unused");
+
+        // Assert
+        let expected = Diagnostic::new(Lint::Unused {
+            kind: kind.to_owned(),
+            identifier: "S".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test_case("struct S { f: Used }"; "field type")]
+    #[test_case("struct S { f: sequence<Used> }"; "sequence element type")]
+    #[test_case("struct S { f: dictionary<int32, Used> }"; "dictionary value type")]
+    #[test_case("typealias Alias = Used\n struct S { f: Alias }"; "type alias")]
+    #[test_case("interface I { op(p: Used) }"; "parameter type")]
+    #[test_case("interface I { op() -> Used }"; "return type")]
+    #[test_case("exception Used {}\n interface I { op() throws Used }"; "thrown exception")]
+    #[test_case("/// See {@link Used}.\n interface I {}"; "doc comment link")]
+    #[test_case("interface I {\n /// @see Used\n op()\n }"; "doc comment see tag")]
+    fn referenced_definitions_are_not_reported(usage: &str) {
+        // Arrange
+        let used = if usage.contains("Used {}") {
+            ""
+        } else {
+            "struct Used {}"
+        };
+        let slice = format!(
+            "
+            module Test
+            {used}
+            {usage}
+            "
+        );
+
+        // Act
+        let diagnostics = parse_with_warned_lint(slice, "Unused");
+
+        // Assert
+        let messages = diagnostics.iter().map(Diagnostic::message).collect::<Vec<_>>();
+        assert!(diagnostics.iter().all(|d| d.code() == "Unused"), "{messages:?}");
+        assert!(
+            !messages.iter().any(|message| message.contains("'Used'")),
+            "{messages:?}"
+        );
+    }
+
+    #[test]
+    fn base_types_count_as_uses() {
+        // Arrange
+        let slice = "
+            mode = Slice1
+            module Test
+            class Base {}
+            exception BaseException {}
+            class Derived : Base {}
+            exception DerivedException : BaseException {}
+        ";
+
+        // Act
+        let diagnostics = parse_with_warned_lint(slice, "Unused");
+
+        // Assert
+        let expected = [
+            Diagnostic::new(Lint::Unused {
+                kind: "class".to_owned(),
+                identifier: "Derived".to_owned(),
+            }),
+            Diagnostic::new(Lint::Unused {
+                kind: "exception".to_owned(),
+                identifier: "DerivedException".to_owned(),
+            }),
+        ];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn self_references_do_not_count_as_uses() {
+        // Arrange
+        let slice = "
+            mode = Slice1
+            module Test
+
+            /// A linked list, see {@link List}.
+            class List {
+                next: List?
+            }
+        ";
+
+        // Act
+        let diagnostics = parse_with_warned_lint(slice, "Unused");
+
+        // Assert
+        let expected = Diagnostic::new(Lint::Unused {
+            kind: "class".to_owned(),
+            identifier: "List".to_owned(),
+        })
+        .set_span(&Span::new((6, 19).into(), (6, 23).into(), "string-0"));
+        check_diagnostics(diagnostics, [expected]);
+    }
+
+    #[test]
+    fn interfaces_are_never_reported() {
+        // Arrange
+        let slice = "
+            module Test
+            interface I {}
+        ";
+
+        // Act
+        let diagnostics = parse_with_warned_lint(slice, "Unused");
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn unused_can_be_allowed_by_attributes() {
+        // Arrange
+        let slice = "
+            module Test
+
+            [allow(Unused)]
+            struct S {}
+        ";
+
+        // Act
+        let diagnostics = parse_with_warned_lint(slice, "Unused");
+
+        // Assert
+        let expected: [Diagnostic; 0] = [];
+        check_diagnostics(diagnostics, expected);
+    }
+
+    #[test]
+    fn reference_files_are_ignored() {
+        // Arrange
+        let source = "
+            module Test
+            struct S {
+                f: Other::Used
+            }
+        ";
+        let reference = "
+            module Other
+            struct Used {}
+            struct Unused {}
+        ";
+
+        // Act
+        let diagnostics = parse_multiple_with_warned_lint(&[source, reference], "Unused");
+
+        // Assert
+        let expected = Diagnostic::new(Lint::Unused {
+            kind: "struct".to_owned(),
+            identifier: "S".to_owned(),
+        });
+        check_diagnostics(diagnostics, [expected]);
+    }
+}